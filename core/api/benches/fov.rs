@@ -0,0 +1,53 @@
+use api::{
+    fov::{OcclusionMap, WindowFov},
+    gridmap::FOV_MAP_WIDTH,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const PLAYERS: usize = 100;
+
+// Rooms of 10x10 cells with a doorway in every wall, similar to a ship layout.
+fn ship_like_map() -> OcclusionMap {
+    let mut map = OcclusionMap::new(FOV_MAP_WIDTH, FOV_MAP_WIDTH);
+
+    for x in 0..FOV_MAP_WIDTH {
+        for y in 0..FOV_MAP_WIDTH {
+            let is_wall = (x % 10 == 0 && y % 10 != 5) || (y % 10 == 0 && x % 10 != 5);
+            map.set_transparent(x, y, !is_wall);
+        }
+    }
+
+    map
+}
+
+fn player_positions() -> Vec<(usize, usize)> {
+    (0..PLAYERS)
+        .map(|i| (100 + (i * 37) % 300, 100 + (i * 53) % 300))
+        .collect()
+}
+
+fn bench_fov(c: &mut Criterion) {
+    let map = ship_like_map();
+    let open_map = OcclusionMap::new(FOV_MAP_WIDTH, FOV_MAP_WIDTH);
+    let positions = player_positions();
+    let mut fovs: Vec<WindowFov> = (0..PLAYERS).map(|_| WindowFov::default()).collect();
+
+    c.bench_function("fov single senser ship", |b| {
+        b.iter(|| fovs[0].compute_fov(black_box(&map), 250, 250))
+    });
+
+    c.bench_function("fov single senser open space", |b| {
+        b.iter(|| fovs[0].compute_fov(black_box(&open_map), 250, 250))
+    });
+
+    c.bench_function("fov 100 sensers ship", |b| {
+        b.iter(|| {
+            for (fov, (x, y)) in fovs.iter_mut().zip(positions.iter()) {
+                fov.compute_fov(black_box(&map), *x, *y);
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_fov);
+criterion_main!(benches);
@@ -0,0 +1,6 @@
+// Send to have ASANA announce a message over the radio, any plugin can do so without depending on the asana crate.
+pub struct AsanaAnnouncement {
+    pub message: String,
+    // Id of the radio channel to announce on, None uses the configured default channel.
+    pub channel_option: Option<String>,
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use bevy::prelude::Entity;
 
+use crate::data::{Vec2Int, Vec3Int};
+
 // This struct gets repeated FOV_MAP_WIDTH*FOV_MAP_WIDTH (250k) times in our atmospherics dictionary.
 #[derive(Clone)]
 pub struct Atmospherics {
@@ -10,9 +12,16 @@ pub struct Atmospherics {
     pub temperature: f32,
     //Mol
     pub amount: f32,
+    //Mol of oxygen, part of amount.
+    pub oxygen: f32,
+    //Mol of combustible gas, part of amount.
+    pub fuel: f32,
+    pub burning: bool,
     pub flags: Vec<String>,
     pub effects: HashMap<EffectType, AtmosEffect>,
     pub forces_push_up: bool,
+    // Open to the cell on the deck above through a hatch.
+    pub vertical_passage: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -30,6 +39,8 @@ pub struct AtmosEffect {
     pub target_amount: f32,
     pub amount_speed: f32,
     pub remover: bool,
+    // Share of the added gas that is fuel, the rest is air.
+    pub fuel_ratio: f32,
 }
 
 impl Default for Atmospherics {
@@ -40,15 +51,37 @@ impl Default for Atmospherics {
             blocked: false,
             temperature: -270.45 + CELCIUS_KELVIN_OFFSET,
             amount: 0.,
+            oxygen: 0.,
+            fuel: 0.,
+            burning: false,
             effects: effects,
             flags: vec![],
             forces_push_up: false,
+            vertical_passage: false,
         }
     }
 }
 
+// Sent by hot things like laser impacts, sets the cell on fire if it has something to burn.
+pub struct AtmosIgnition {
+    pub cell_id: Vec3Int,
+}
+
+// Sent when a removed cell opens up a pressurized area to space.
+pub struct HullBreach {
+    pub cell_id: Vec2Int,
+    pub deck: usize,
+    // Kpa of the pressurized side of the breach.
+    pub pressure: f32,
+}
+
 pub const CELCIUS_KELVIN_OFFSET: f32 = 273.15;
 pub const DEFAULT_INTERNAL_AMOUNT: f32 = 84.58;
+// Share of oxygen in air added by atmos effects.
+pub const OXYGEN_RATIO: f32 = 0.21;
+pub const IGNITION_TEMPERATURE: f32 = 150. + CELCIUS_KELVIN_OFFSET;
+// Below this many mol of fuel or oxygen a cell won't burn.
+pub const MIN_COMBUSTION_AMOUNT: f32 = 0.5;
 
 impl Atmospherics {
     pub fn new_internal(blocked: bool, forces_push_up: bool) -> Self {
@@ -56,15 +89,35 @@ impl Atmospherics {
             blocked,
             temperature: 20. + CELCIUS_KELVIN_OFFSET,
             amount: DEFAULT_INTERNAL_AMOUNT,
+            oxygen: DEFAULT_INTERNAL_AMOUNT * OXYGEN_RATIO,
+            fuel: 0.,
+            burning: false,
             effects: HashMap::new(),
             flags: vec![],
             forces_push_up,
+            vertical_passage: false,
         }
     }
     pub fn get_pressure(&self) -> f32 {
         // Return kpa
         (((self.amount * 0.08206 * self.temperature) / 2000.) * 101325.) / 1000.
     }
+    pub fn is_combustible(&self) -> bool {
+        !self.blocked && self.fuel > MIN_COMBUSTION_AMOUNT && self.oxygen > MIN_COMBUSTION_AMOUNT
+    }
+    // Heats the cell up to its ignition temperature, atmos_combustion takes it from there.
+    pub fn ignite(&mut self) -> bool {
+        if !self.is_combustible() {
+            return false;
+        }
+        self.temperature = self.temperature.max(IGNITION_TEMPERATURE);
+        true
+    }
+}
+
+// Inverse of Atmospherics::get_pressure, the amount of mol a cell holds at the given kpa.
+pub fn get_amount_for_pressure(pressure: f32, temperature: f32) -> f32 {
+    ((pressure * 1000.) / 101325.) * 2000. / (0.08206 * temperature)
 }
 
 pub const VACUUM_ATMOSEFFECT: AtmosEffect = AtmosEffect {
@@ -75,4 +128,5 @@ pub const VACUUM_ATMOSEFFECT: AtmosEffect = AtmosEffect {
     target_amount: 0.,
     amount_speed: 500.,
     remover: true,
+    fuel_ratio: 0.,
 };
@@ -1,6 +1,12 @@
+use bevy::prelude::SystemLabel;
 use const_format::concatcp;
 use rand::Rng;
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum ChatLabels {
+    Filter,
+}
+
 pub const BILLBOARD_SHOUT_FONT: &str =
     "res://assets/fonts/RobotoFamily/RobotoCondensed/RobotoCondensed-BoldShoutDyna.tres";
 pub const BILLBOARD_SHOUT_ITALIC_FONT: &str =
@@ -173,6 +179,16 @@ pub const _TALK_TYPE_MACHINE_FAR_END: &str = "[/i]";
 
 pub const TALK_SPACE_GLOBAL_CHATPREFIX: &str = "/global";
 
+pub const PRIVATE_MESSAGE_CHATPREFIX: &str = "/pm";
+pub const ADMIN_HELP_CHATPREFIX: &str = "/ahelp";
+pub const ADMIN_REPLY_CHATPREFIX: &str = "/areply";
+pub const ADMIN_CLOSE_CHATPREFIX: &str = "/aclose";
+pub const STAFF_CHATPREFIX: &str = "/staff";
+pub const EMOTE_CHATPREFIX: &str = "/emote";
+pub const PRIVATE_MESSAGE_COLOR: &str = "#e07bff";
+pub const ADMIN_HELP_COLOR: &str = "#ff5c8a";
+pub const STAFF_COLOR: &str = "#5cb8ff";
+
 pub const TALK_SPACE_PROXIMITY_EMOTE_CHATPREFIX: &str = "/me";
 pub const TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBSTART: &str = "[color=#dbdbdb]";
 pub const TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBEND: &str = "[/color]";
@@ -185,34 +201,12 @@ pub const TALK_SPACE_PROXIMITY_PREFIXBBEND: &str = "[/color]";
 pub const TALK_SPACE_PROXIMITY_MESSAGEBBSTART: &str = "[color=#e6e6e6]";
 pub const TALK_SPACE_PROXIMITY_MESSAGEBBEND: &str = "[/color]";
 
-pub const TALK_SPACE_COMMON_CHATPREFIX: &str = ";";
-pub const TALK_SPACE_COMMON_PREFIXBBSTART: &str = "[color=#6ce07a]";
-pub const TALK_SPACE_COMMON_PREFIXBBEND: &str = "[/color]";
-pub const TALK_SPACE_COMMON_MESSAGEBBSTART: &str = "[color=#68de77]";
-pub const TALK_SPACE_COMMON_MESSAGEBBEND: &str = "[/color]";
-
-pub const TALK_SPACE_SECURITY_CHATPREFIX: &str = ":s";
-pub const TALK_SPACE_SECURITY_PREFIXBBSTART: &str = "[color=#f24141]";
-pub const TALK_SPACE_SECURITY_PREFIXBBEND: &str = "[/color]";
-pub const TALK_SPACE_SECURITY_MESSAGEBBSTART: &str = "[color=#fc3d3d]";
-pub const TALK_SPACE_SECURITY_MESSAGEBBEND: &str = "[/color]";
-
-pub const TALK_SPACE_SPECIALOPS_CHATPREFIX: &str = ".";
-pub const TALK_SPACE_SPECIALOPS_PREFIXBBSTART: &str = "[color=#f24141]";
-pub const TALK_SPACE_SPECIALOPS_PREFIXBBEND: &str = "[/color]";
-pub const TALK_SPACE_SPECIALOPS_MESSAGEBBSTART: &str = "[color=#fc3d3d]";
-pub const TALK_SPACE_SPECIALOPS_MESSAGEBBEND: &str = "[/color]";
-
 pub const BILLBOARD_DATA_SECURITY_START: &str = "[center][color=#ff7070]";
 pub const BILLBOARD_DATA_SECURITY_END: &str = "[/color][/center]";
 
 pub const _BILLBOARD_DATA_SPECIALOPS_START: &str = "[center][color=#ff7070]";
 pub const _BILLBOARD_DATA_SPECIALOPS_END: &str = "[/color][/center]";
 
-pub const TALK_SPACE_COMMON_WORD: &str = "Common";
-pub const TALK_SPACE_SECURITY_WORD: &str = "Security";
-pub const TALK_SPACE_SPECIALOPS_WORD: &str = "Spec-op";
-
 pub const JOB_SECURITY_WORD: &str = "Security";
 pub const JOB_CONTROL_WORD: &str = "Control";
 
@@ -247,26 +241,24 @@ pub fn get_space_message() -> String {
 
     msg.to_string()
 }
-pub fn get_talk_spaces() -> Vec<(String, String)> {
-    vec![
+// Radio talk spaces come from the radio channels the player has equipped.
+pub fn get_talk_spaces(radio_talk_spaces: Vec<(String, String)>) -> Vec<(String, String)> {
+    let mut talk_spaces = vec![
         ("Local".to_string(), "".to_string()),
         (
             "Me".to_string(),
             TALK_SPACE_PROXIMITY_EMOTE_CHATPREFIX.to_string(),
         ),
-        (
-            "Common".to_string(),
-            TALK_SPACE_COMMON_CHATPREFIX.to_string(),
-        ),
-        (
-            "Security".to_string(),
-            TALK_SPACE_SECURITY_CHATPREFIX.to_string(),
-        ),
-        (
-            "Global".to_string(),
-            TALK_SPACE_GLOBAL_CHATPREFIX.to_string(),
-        ),
-    ]
+    ];
+
+    talk_spaces.extend(radio_talk_spaces);
+
+    talk_spaces.push((
+        "Global".to_string(),
+        TALK_SPACE_GLOBAL_CHATPREFIX.to_string(),
+    ));
+
+    talk_spaces
 }
 
 pub fn escape_bb(string: String, partially: bool, escape_special_chars: bool) -> String {
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use bevy::math::Vec3;
+use bevy::{math::Vec3, prelude::Component};
 use serde::{Deserialize, Serialize};
 
 pub struct ProjectileFOV {
@@ -11,7 +11,9 @@ pub struct ProjectileFOV {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum NetProjectileType {
     Laser((f32, f32, f32, f32), f32, f32, Vec3, Vec3),
-    Ballistic,
+    // Speed, start and end position of a straight stretch of a ballistic projectile's path,
+    // sent when it is fired and again after every impact it continues from.
+    Ballistic(f32, Vec3, Vec3),
 }
 
 #[allow(dead_code)]
@@ -72,5 +74,58 @@ pub enum CombatType {
 #[derive(Clone, Debug)]
 pub enum ProjectileType {
     Laser((f32, f32, f32, f32), f32, f32, f32),
-    Ballistic,
+    // Muzzle velocity, range and penetration power.
+    Ballistic(f32, f32, f32),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AmmunitionType {
+    Rounds,
+    Charge,
+}
+
+// A magazine or power cell, rounds count shots worth of charge for power cells.
+#[derive(Component)]
+pub struct Magazine {
+    pub ammunition_type: AmmunitionType,
+    pub rounds: u16,
+    pub capacity: u16,
+}
+
+impl Magazine {
+    pub fn new(ammunition_type: AmmunitionType, capacity: u16) -> Self {
+        Self {
+            ammunition_type,
+            rounds: capacity,
+            capacity,
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self.ammunition_type {
+            AmmunitionType::Rounds => {
+                self.rounds.to_string() + " of " + &self.capacity.to_string() + " rounds"
+            }
+            AmmunitionType::Charge => {
+                // Power cells without capacity read as drained rather than NaN.
+                let percentage = match self.capacity {
+                    0 => 0.,
+                    capacity => (self.rounds as f32 / capacity as f32 * 100.).floor(),
+                };
+                percentage.to_string() + "% charge"
+            }
+        }
+    }
+}
+
+// The magazine or power cell loaded into a projectile weapon.
+// Projectile weapons without it never run dry.
+#[derive(Component)]
+pub struct Ammunition {
+    pub loaded: Magazine,
+}
+
+// Where an attack got tested against a rewound hitbox, sent while lag compensation debugging is on.
+pub struct DebugHitbox {
+    pub translation: Vec3,
 }
@@ -2,3 +2,33 @@ use bevy::prelude::Component;
 
 #[derive(Component)]
 pub struct SoftPlayer;
+
+// Per connection, renet stats plus the round trip of ServerTime messages answered with a HeartBeat.
+// Boarding moves players to a new entity, these start over there.
+#[derive(Component, Default, Clone)]
+pub struct NetworkStats {
+    // Milliseconds, smoothed by renet.
+    pub rtt: f32,
+    // Milliseconds, latest application level round trip.
+    pub ping: Option<f32>,
+    // Milliseconds, smoothed difference between consecutive pings.
+    pub jitter: f32,
+    // Fraction of the packets that got lost.
+    pub packet_loss: f32,
+    pub sent_kbps: f32,
+    pub received_kbps: f32,
+    // Seconds the rtt has been over the auto kick limit.
+    pub excessive_ping_duration: f32,
+}
+
+impl NetworkStats {
+    pub fn add_ping(&mut self, ping: f32) {
+        match self.ping {
+            Some(previous_ping) => {
+                self.jitter += ((ping - previous_ping).abs() - self.jitter) / 16.;
+            }
+            None => {}
+        }
+        self.ping = Some(ping);
+    }
+}
@@ -167,7 +167,12 @@ pub struct Showcase {
 }
 
 pub const PISTOL_L1_ENTITY_NAME: &str = "pistolL1";
+pub const PISTOL_B1_ENTITY_NAME: &str = "pistolB1";
+pub const MAGAZINE_B1_ENTITY_NAME: &str = "magazineB1";
+pub const POWER_CELL_L1_ENTITY_NAME: &str = "powerCellL1";
 pub const JUMPSUIT_SECURITY_ENTITY_NAME: &str = "jumpsuitSecurity";
+pub const ID_CARD_ENTITY_NAME: &str = "idCard";
+pub const HEADSET_ENTITY_NAME: &str = "headset";
 
 pub const HUMAN_DUMMY_ENTITY_NAME: &str = "humanDummy";
 pub const HUMAN_MALE_ENTITY_NAME: &str = "humanMale";
@@ -0,0 +1,180 @@
+pub const FOV_DISTANCE: usize = 23;
+
+// Sensers only keep the square of cells within FOV_DISTANCE of them.
+pub const FOV_WINDOW_WIDTH: usize = FOV_DISTANCE * 2 + 1;
+
+// Octant transforms for shadowcasting, xx, xy, yx, yy.
+const OCTANTS: [(i32, i32, i32, i32); 8] = [
+    (1, 0, 0, 1),
+    (0, 1, 1, 0),
+    (0, -1, 1, 0),
+    (-1, 0, 0, 1),
+    (-1, 0, 0, -1),
+    (0, -1, -1, 0),
+    (0, 1, -1, 0),
+    (1, 0, 0, -1),
+];
+
+// Which cells block sight, shared by all sensers on a deck.
+pub struct OcclusionMap {
+    pub width: usize,
+    pub height: usize,
+    transparent: Vec<bool>,
+}
+
+impl OcclusionMap {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            transparent: vec![true; width * height],
+        }
+    }
+
+    pub fn set_transparent(&mut self, x: usize, y: usize, is_transparent: bool) {
+        if x < self.width && y < self.height {
+            self.transparent[x + y * self.width] = is_transparent;
+        }
+    }
+
+    // Everything outside of the map blocks sight.
+    pub fn is_transparent(&self, x: i32, y: i32) -> bool {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return false;
+        }
+        self.transparent[x as usize + y as usize * self.width]
+    }
+}
+
+// Recursive shadowcasting bounded to a window around the senser.
+pub struct WindowFov {
+    origin_x: i32,
+    origin_y: i32,
+    visible: Vec<bool>,
+    // Set when a cell inside the window changed, recomputed once per frame.
+    pub invalidated: bool,
+}
+
+impl Default for WindowFov {
+    fn default() -> Self {
+        Self {
+            origin_x: 0,
+            origin_y: 0,
+            visible: vec![false; FOV_WINDOW_WIDTH * FOV_WINDOW_WIDTH],
+            invalidated: false,
+        }
+    }
+}
+
+impl WindowFov {
+    fn window_index(&self, x: i32, y: i32) -> Option<usize> {
+        let window_x = x - self.origin_x + FOV_DISTANCE as i32;
+        let window_y = y - self.origin_y + FOV_DISTANCE as i32;
+
+        if window_x < 0
+            || window_y < 0
+            || window_x as usize >= FOV_WINDOW_WIDTH
+            || window_y as usize >= FOV_WINDOW_WIDTH
+        {
+            return None;
+        }
+
+        Some(window_x as usize + window_y as usize * FOV_WINDOW_WIDTH)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.window_index(x as i32, y as i32).is_some()
+    }
+
+    pub fn is_in_fov(&self, x: usize, y: usize) -> bool {
+        match self.window_index(x as i32, y as i32) {
+            Some(i) => self.visible[i],
+            None => false,
+        }
+    }
+
+    pub fn clear_fov(&mut self) {
+        for visible in self.visible.iter_mut() {
+            *visible = false;
+        }
+    }
+
+    pub fn compute_fov(&mut self, map: &OcclusionMap, x: usize, y: usize) {
+        self.clear_fov();
+        self.origin_x = x as i32;
+        self.origin_y = y as i32;
+        self.invalidated = false;
+
+        let origin_i = self.window_index(self.origin_x, self.origin_y).unwrap();
+        self.visible[origin_i] = true;
+
+        for octant in OCTANTS.iter() {
+            self.cast_light(map, 1, 1., 0., *octant);
+        }
+    }
+
+    fn cast_light(
+        &mut self,
+        map: &OcclusionMap,
+        row: i32,
+        mut start: f32,
+        end: f32,
+        (xx, xy, yx, yy): (i32, i32, i32, i32),
+    ) {
+        if start < end {
+            return;
+        }
+
+        let radius = FOV_DISTANCE as i32;
+        let radius_squared = radius * radius;
+        let mut new_start = 0.;
+
+        for j in row..radius + 1 {
+            let dy = -j;
+            let mut blocked = false;
+
+            for dx in -j..1 {
+                let left_slope = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+                let right_slope = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+
+                if start < right_slope {
+                    continue;
+                } else if end > left_slope {
+                    break;
+                }
+
+                let x = self.origin_x + dx * xx + dy * xy;
+                let y = self.origin_y + dx * yx + dy * yy;
+
+                // Walls that bound the view are lit too.
+                if dx * dx + dy * dy < radius_squared {
+                    match self.window_index(x, y) {
+                        Some(i) => {
+                            self.visible[i] = true;
+                        }
+                        None => {}
+                    }
+                }
+
+                let is_blocking = !map.is_transparent(x, y);
+
+                if blocked {
+                    if is_blocking {
+                        new_start = right_slope;
+                    } else {
+                        blocked = false;
+                        start = new_start;
+                    }
+                } else if is_blocking && j < radius {
+                    blocked = true;
+                    self.cast_light(map, j + 1, start, left_slope, (xx, xy, yx, yy));
+                    new_start = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
@@ -42,6 +42,34 @@ pub fn to_doryen_coordinates(x: i16, y: i16) -> (usize, usize) {
 pub fn doryen_coordinates_out_of_range(x: usize, y: usize) -> bool {
     x > FOV_MAP_WIDTH || y > FOV_MAP_WIDTH
 }
+
+// Every deck is a floor layer with a wall layer on top of it, deck 0 has its floor at y -1.
+pub const DECK_CELL_HEIGHT: i16 = 2;
+// Each deck gets its own fov map and atmospherics grid.
+pub const MAX_DECKS: usize = 4;
+
+pub fn get_deck(cell_y: i16) -> usize {
+    (cell_y + 1)
+        .div_euclid(DECK_CELL_HEIGHT)
+        .clamp(0, MAX_DECKS as i16 - 1) as usize
+}
+
+pub fn is_wall_layer(cell_y: i16) -> bool {
+    cell_y.rem_euclid(DECK_CELL_HEIGHT) == 0
+}
+
+pub fn get_wall_layer(deck: usize) -> i16 {
+    deck as i16 * DECK_CELL_HEIGHT
+}
+
+pub fn get_floor_layer(deck: usize) -> i16 {
+    get_wall_layer(deck) - 1
+}
+
+// World height of the floor surface pawns stand on.
+pub fn get_deck_floor_height(deck: usize) -> f32 {
+    get_wall_layer(deck) as f32 * CELL_SIZE
+}
 pub const CELL_SIZE: f32 = 2.;
 
 pub fn world_to_cell_id(position: Vec3) -> Vec3Int {
@@ -60,6 +88,8 @@ pub struct GridmapMain {
     pub grid_data: HashMap<Vec3Int, CellData>,
     pub entity_data: HashMap<Vec3Int, EntityGridData>,
     pub updates: HashMap<Vec3Int, CellUpdate>,
+    // Amount of decks the map has cells on.
+    pub decks: usize,
 }
 
 pub struct EntityGridData {
@@ -198,7 +228,17 @@ pub fn get_atmos_index(id: Vec2Int) -> usize {
     (idx + (idy * FOV_MAP_WIDTH as u32)) as usize
 }
 
+// Index into the atmospherics grid of the given deck.
+pub fn get_deck_atmos_index(id: Vec2Int, deck: usize) -> usize {
+    deck * FOV_MAP_WIDTH * FOV_MAP_WIDTH + get_atmos_index(id)
+}
+
+pub fn get_atmos_deck(i: usize) -> usize {
+    i / (FOV_MAP_WIDTH * FOV_MAP_WIDTH)
+}
+
 pub fn get_atmos_id(i: usize) -> Vec2Int {
+    let i = i % (FOV_MAP_WIDTH * FOV_MAP_WIDTH);
     let y = (i as f32 / FOV_MAP_WIDTH as f32).floor() as usize;
     let x = i - (y * FOV_MAP_WIDTH);
 
@@ -148,7 +148,7 @@ use rand::prelude::SliceRandom;
 use crate::{
     combat::{DamageFlag, DamageModel, DamageType, HitResult},
     data::{HandleToEntity, Vec3Int},
-    gridmap::{to_doryen_coordinates, CellData},
+    gridmap::CellData,
     network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage},
     senser::Senser,
 };
@@ -178,7 +178,25 @@ impl PendingMessage for NetHealth {
     }
 }
 
+// There is no death state yet, a pawn counts as eliminated once its head or torso
+// took this much damage.
+pub const ELIMINATION_DAMAGE: f32 = 100.;
+
 impl Health {
+    pub fn is_eliminated(&self) -> bool {
+        match &self.health_container {
+            HealthContainer::Humanoid(humanoid_health) => {
+                humanoid_health.head_brute + humanoid_health.head_burn + humanoid_health.head_toxin
+                    >= ELIMINATION_DAMAGE
+                    || humanoid_health.torso_brute
+                        + humanoid_health.torso_burn
+                        + humanoid_health.torso_toxin
+                        >= ELIMINATION_DAMAGE
+            }
+            HealthContainer::Entity(_) => false,
+        }
+    }
+
     pub fn apply_damage(
         &mut self,
         body_part: &str,
@@ -204,9 +222,6 @@ impl Health {
             &damage_model.toxin,
         );
 
-        let attacker_cell_id_doryen = to_doryen_coordinates(attacker_cell_id.x, attacker_cell_id.z);
-        let attacked_cell_id_doryen = to_doryen_coordinates(attacked_cell_id.x, attacked_cell_id.z);
-
         match &mut self.health_container {
             HealthContainer::Humanoid(humanoid_health) => {
                 for (entity, senser) in sensers.iter() {
@@ -216,10 +231,7 @@ impl Health {
 
                     let attacker_is_visible;
 
-                    if senser.fov.is_in_fov(
-                        attacker_cell_id_doryen.0 as usize,
-                        attacker_cell_id_doryen.1 as usize,
-                    ) {
+                    if senser.is_cell_in_fov(attacker_cell_id) {
                         attacker_is_visible = true;
                     } else {
                         attacker_is_visible = false;
@@ -227,10 +239,7 @@ impl Health {
 
                     let attacked_is_visible;
 
-                    if senser.fov.is_in_fov(
-                        attacked_cell_id_doryen.0 as usize,
-                        attacked_cell_id_doryen.1 as usize,
-                    ) {
+                    if senser.is_cell_in_fov(attacked_cell_id) {
                         attacked_is_visible = true;
                     } else {
                         attacked_is_visible = false;
@@ -437,10 +446,7 @@ impl Health {
 
                     let attacker_is_visible;
 
-                    if senser.fov.is_in_fov(
-                        attacker_cell_id_doryen.0 as usize,
-                        attacker_cell_id_doryen.1 as usize,
-                    ) {
+                    if senser.is_cell_in_fov(attacker_cell_id) {
                         attacker_is_visible = true;
                     } else {
                         attacker_is_visible = false;
@@ -448,10 +454,7 @@ impl Health {
 
                     let attacked_is_visible;
 
-                    if senser.fov.is_in_fov(
-                        attacked_cell_id_doryen.0 as usize,
-                        attacked_cell_id_doryen.1 as usize,
-                    ) {
+                    if senser.is_cell_in_fov(attacked_cell_id) {
                         attacked_is_visible = true;
                     } else {
                         attacked_is_visible = false;
@@ -536,9 +539,6 @@ impl StructureHealth {
             &damage_model.toxin,
         );
 
-        let attacker_cell_id_doryen = to_doryen_coordinates(attacker_cell_id.x, attacker_cell_id.z);
-        let attacked_cell_id_doryen = to_doryen_coordinates(attacked_cell_id.x, attacked_cell_id.z);
-
         self.brute += brute_damage;
         self.burn += burn_damage;
         self.toxin += toxin_damage;
@@ -550,10 +550,7 @@ impl StructureHealth {
 
             let attacker_is_visible;
 
-            if senser.fov.is_in_fov(
-                attacker_cell_id_doryen.0 as usize,
-                attacker_cell_id_doryen.1 as usize,
-            ) {
+            if senser.is_cell_in_fov(attacker_cell_id) {
                 attacker_is_visible = true;
             } else {
                 attacker_is_visible = false;
@@ -561,10 +558,7 @@ impl StructureHealth {
 
             let attacked_is_visible;
 
-            if senser.fov.is_in_fov(
-                attacked_cell_id_doryen.0 as usize,
-                attacked_cell_id_doryen.1 as usize,
-            ) {
+            if senser.is_cell_in_fov(attacked_cell_id) {
                 attacked_is_visible = true;
             } else {
                 attacked_is_visible = false;
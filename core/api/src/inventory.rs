@@ -6,6 +6,8 @@ pub enum SlotType {
     Helmet,
     Jumpsuit,
     Holster,
+    Id,
+    Headset,
 }
 
 pub struct Slot {
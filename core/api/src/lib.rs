@@ -1,3 +1,4 @@
+pub mod asana;
 pub mod atmospherics;
 pub mod chat;
 pub mod combat;
@@ -8,14 +9,17 @@ pub mod data;
 pub mod data_link;
 pub mod entity_updates;
 pub mod examinable;
+pub mod fov;
 pub mod get_spawn_position;
 pub mod gridmap;
 pub mod health;
 pub mod inventory;
 pub mod load_entity;
+pub mod metrics;
 pub mod network;
 pub mod pawn;
 pub mod rigid_body;
+pub mod rounds;
 pub mod sensable;
 pub mod senser;
 pub mod sfx;
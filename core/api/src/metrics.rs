@@ -0,0 +1,55 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+// Behind a mutex so systems can record with Res<Metrics> and keep running in parallel.
+#[derive(Default)]
+pub struct Metrics {
+    data: Mutex<MetricsData>,
+}
+
+#[derive(Default)]
+pub struct MetricsData {
+    // Seconds, of the latest tick.
+    pub tick_duration: f64,
+    pub stage_durations: HashMap<&'static str, f64>,
+    pub system_durations: HashMap<&'static str, f64>,
+    pub stage_started: HashMap<&'static str, Instant>,
+    // Percentage per fixed timestep label.
+    pub oversteps: HashMap<&'static str, f64>,
+    pub entity_groups: HashMap<&'static str, usize>,
+    pub connected_players: usize,
+    // Total since startup per message type.
+    pub bytes_sent: HashMap<&'static str, u64>,
+    pub messages_sent: HashMap<&'static str, u64>,
+    // Events sent during the latest tick.
+    pub event_queue_lengths: HashMap<&'static str, usize>,
+}
+
+impl Metrics {
+    pub fn lock(&self) -> MutexGuard<MetricsData> {
+        match self.data.lock() {
+            Ok(data) => data,
+            // A panicking system can't leave the plain data behind in a broken state.
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    pub fn record_system(&self, system: &'static str, duration: Duration) {
+        self.lock()
+            .system_durations
+            .insert(system, duration.as_secs_f64());
+    }
+
+    pub fn record_overstep(&self, label: &'static str, overstep_percentage: f64) {
+        self.lock().oversteps.insert(label, overstep_percentage);
+    }
+
+    pub fn add_sent(&self, message_type: &'static str, bytes: usize) {
+        let mut data = self.lock();
+        *data.bytes_sent.entry(message_type).or_insert(0) += bytes as u64;
+        *data.messages_sent.entry(message_type).or_insert(0) += 1;
+    }
+}
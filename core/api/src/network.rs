@@ -129,6 +129,7 @@ pub enum ReliableClientMessage {
     MapChangeDisplayMode(String),
     MapRequestDisplayModes,
     MapCameraPosition(Vec2),
+    MapSelectDeck(u8),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -176,6 +177,46 @@ pub enum ReliableServerMessage {
     UIAddNotice(String),
     UIRemoveNotice(String),
     MapDefaultAddition(i16, i16, i16),
+    // Selected deck and the amount of decks, clears the default additions of the previous deck.
+    MapSelectedDeck(u8, u8),
+    // All entity updates of a tick, entity bits with node path ids and parameter ids.
+    EntityUpdateBatch(Vec<(u64, Vec<(u16, Vec<(u16, EntityUpdateData)>)>)>),
+    // A bincode serialized EntityUpdateBatch compressed with lz4, size prepended.
+    CompressedEntityUpdateBatch(Vec<u8>),
+}
+
+impl ReliableServerMessage {
+    // Variant name, used to label network metrics.
+    pub fn get_type_name(&self) -> &'static str {
+        match self {
+            ReliableServerMessage::EntityUpdate(..) => "EntityUpdate",
+            ReliableServerMessage::ConfigMessage(..) => "ConfigMessage",
+            ReliableServerMessage::UIRequestInput(..) => "UIRequestInput",
+            ReliableServerMessage::LoadEntity(..) => "LoadEntity",
+            ReliableServerMessage::UnloadEntity(..) => "UnloadEntity",
+            ReliableServerMessage::ChatMessage(..) => "ChatMessage",
+            ReliableServerMessage::PickedUpItem(..) => "PickedUpItem",
+            ReliableServerMessage::DropItem(..) => "DropItem",
+            ReliableServerMessage::SwitchHands => "SwitchHands",
+            ReliableServerMessage::EquippedWornItem(..) => "EquippedWornItem",
+            ReliableServerMessage::ConsoleWriteLine(..) => "ConsoleWriteLine",
+            ReliableServerMessage::PlaySound(..) => "PlaySound",
+            ReliableServerMessage::FireProjectile(..) => "FireProjectile",
+            ReliableServerMessage::TabData(..) => "TabData",
+            ReliableServerMessage::TextTreeSelection(..) => "TextTreeSelection",
+            ReliableServerMessage::RemoveCell(..) => "RemoveCell",
+            ReliableServerMessage::AddCell(..) => "AddCell",
+            ReliableServerMessage::MapSendDisplayModes(..) => "MapSendDisplayModes",
+            ReliableServerMessage::MapOverlayUpdate(..) => "MapOverlayUpdate",
+            ReliableServerMessage::MapOverlayHoverData(..) => "MapOverlayHoverData",
+            ReliableServerMessage::UIAddNotice(..) => "UIAddNotice",
+            ReliableServerMessage::UIRemoveNotice(..) => "UIRemoveNotice",
+            ReliableServerMessage::MapDefaultAddition(..) => "MapDefaultAddition",
+            ReliableServerMessage::MapSelectedDeck(..) => "MapSelectedDeck",
+            ReliableServerMessage::EntityUpdateBatch(..) => "EntityUpdateBatch",
+            ReliableServerMessage::CompressedEntityUpdateBatch(..) => "CompressedEntityUpdateBatch",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -197,12 +238,16 @@ pub enum ServerConfigMessage {
     TalkSpaces(Vec<(String, String)>),
     PlaceableItemsSurfaces(Vec<i64>),
     NonBlockingCells(Vec<i64>),
+    // Node path and parameter names for the ids in entity update batches, from the given id on.
+    EntityUpdateIds(u16, Vec<String>),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum UnreliableServerMessage {
     TransformUpdate(u64, Vec3, Quat, Option<Vec3>, u64, u8),
     PositionUpdate(u64, Vec3, u64),
+    // Translation in 1/64th meters, rotation packed as the smallest three quaternion components.
+    QuantizedTransformUpdate(u64, [i16; 3], u32, u64, u8),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1,6 +1,6 @@
 use bevy::{
     math::Quat,
-    prelude::{Component, Transform},
+    prelude::{Component, Entity, Transform},
 };
 use bevy_rapier3d::na::Quaternion;
 use serde::Deserialize;
@@ -84,4 +84,100 @@ pub struct SpawnPoints {
     pub list: Vec<SpawnPoint>,
     pub i: usize,
 }
+
+impl SpawnPoints {
+    // Cycles through the spawn points of the given type, falls back to any spawn point.
+    pub fn get_next(&mut self, point_type: &str) -> Option<Transform> {
+        let list_len = self.list.len();
+
+        if list_len == 0 {
+            return None;
+        }
+
+        for j in 0..list_len {
+            let index = (self.i + j) % list_len;
+
+            if self.list[index].point_type == point_type {
+                self.i = (index + 1) % list_len;
+                return Some(self.list[index].transform);
+            }
+        }
+
+        // The list may have shrunk since the last call.
+        let index = self.i % list_len;
+        self.i = (index + 1) % list_len;
+
+        Some(self.list[index].transform)
+    }
+}
 pub const REACH_DISTANCE: f32 = 3.;
+
+// Knocks a pawn down, it can't move until the duration in seconds has passed.
+pub struct KnockDown {
+    pub entity: Entity,
+    pub duration: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Transform;
+
+    use super::{SpawnPoint, SpawnPoints};
+
+    fn spawn_point(point_type: &str, x: f32) -> SpawnPoint {
+        SpawnPoint {
+            point_type: point_type.to_string(),
+            transform: Transform::from_xyz(x, 0., 0.),
+        }
+    }
+
+    #[test]
+    fn empty_list_has_no_spawn_point() {
+        let mut spawn_points = SpawnPoints::default();
+
+        assert!(spawn_points.get_next("generic").is_none());
+    }
+
+    #[test]
+    fn cycles_through_spawn_points_of_type() {
+        let mut spawn_points = SpawnPoints {
+            list: vec![
+                spawn_point("generic", 0.),
+                spawn_point("security", 1.),
+                spawn_point("generic", 2.),
+            ],
+            i: 0,
+        };
+
+        let xs: Vec<f32> = (0..4)
+            .map(|_| spawn_points.get_next("generic").unwrap().translation.x)
+            .collect();
+
+        assert_eq!(xs, vec![0., 2., 0., 2.]);
+    }
+
+    #[test]
+    fn falls_back_to_any_spawn_point() {
+        let mut spawn_points = SpawnPoints {
+            list: vec![spawn_point("generic", 0.), spawn_point("generic", 1.)],
+            i: 0,
+        };
+
+        let xs: Vec<f32> = (0..3)
+            .map(|_| spawn_points.get_next("unknown").unwrap().translation.x)
+            .collect();
+
+        assert_eq!(xs, vec![0., 1., 0.]);
+    }
+
+    #[test]
+    fn survives_a_shrunk_list() {
+        let mut spawn_points = SpawnPoints {
+            list: vec![spawn_point("generic", 0.)],
+            i: 5,
+        };
+
+        assert_eq!(spawn_points.get_next("unknown").unwrap().translation.x, 0.);
+        assert_eq!(spawn_points.i, 0);
+    }
+}
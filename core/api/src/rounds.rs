@@ -0,0 +1,81 @@
+use bevy::{
+    ecs::schedule::ShouldRun,
+    prelude::{Res, SystemLabel},
+};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum RoundsLabels {
+    Controller,
+    // Systems adding RoundEndSummary lines when the round enters RoundState::Ending.
+    Summary,
+    RebuildMap,
+    RebuildAtmospherics,
+    ResetWorld,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RoundState {
+    Lobby,
+    Starting,
+    InProgress,
+    Ending,
+}
+
+// Shared so that any plugin can check the round phase without depending on the rounds crate.
+pub struct RoundStatus {
+    pub state: RoundState,
+    pub round_number: u32,
+}
+
+impl Default for RoundStatus {
+    fn default() -> Self {
+        Self {
+            state: RoundState::Lobby,
+            round_number: 1,
+        }
+    }
+}
+
+impl RoundStatus {
+    pub fn boarding_allowed(&self) -> bool {
+        self.state == RoundState::InProgress
+    }
+
+    // Players can finish boarding ahead of the round, they board once it starts.
+    pub fn boarding_queued(&self) -> bool {
+        matches!(self.state, RoundState::Lobby | RoundState::Starting)
+    }
+}
+
+// Sent once the round-end summary has been shown. Plugins reset their round state on it in
+// systems labelled RoundsLabels::ResetWorld, the map gets rebuilt the frame after.
+pub struct ResetWorld;
+
+// Send to end the current round, the reason is shown in the round-end summary.
+pub struct EndRound {
+    pub reason: String,
+}
+
+pub struct RoundStateChanged {
+    pub previous: RoundState,
+    pub new: RoundState,
+}
+
+// Lines pushed here by RoundsLabels::Summary systems get broadcast with the round-end summary.
+#[derive(Default)]
+pub struct RoundEndSummary {
+    pub lines: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct MapRebuild {
+    pub requested: bool,
+}
+
+pub fn map_rebuild_requested(map_rebuild: Res<MapRebuild>) -> ShouldRun {
+    if map_rebuild.requested {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
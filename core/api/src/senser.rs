@@ -1,7 +1,10 @@
 use bevy::prelude::{Component, Entity};
-use doryen_fov::FovRecursiveShadowCasting;
 
-use crate::{data::Vec2Int, gridmap::FOV_MAP_WIDTH};
+use crate::{
+    data::{Vec2Int, Vec3Int},
+    fov::{OcclusionMap, WindowFov},
+    gridmap::{get_deck, to_doryen_coordinates},
+};
 
 #[derive(PartialEq)]
 pub enum SensingAbility {
@@ -12,8 +15,11 @@ pub enum SensingAbility {
 #[derive(Component)]
 pub struct Senser {
     pub cell_id: Vec2Int,
-    pub fov: FovRecursiveShadowCasting,
+    pub deck: usize,
+    pub fov: WindowFov,
     pub sensing: Vec<Entity>,
+    // Entities this senser has cached but does not currently sense.
+    pub sensing_cached: Vec<Entity>,
     pub sfx: Vec<Entity>,
     pub sensing_abilities: Vec<SensingAbility>,
 }
@@ -22,10 +28,37 @@ impl Default for Senser {
     fn default() -> Self {
         Self {
             cell_id: Vec2Int { x: 0, y: 0 },
-            fov: FovRecursiveShadowCasting::new(FOV_MAP_WIDTH, FOV_MAP_WIDTH),
+            deck: 0,
+            fov: WindowFov::default(),
             sensing: vec![],
+            sensing_cached: vec![],
             sfx: vec![],
             sensing_abilities: vec![],
         }
     }
 }
+
+impl Senser {
+    // The fov only covers the deck the senser is on.
+    pub fn is_cell_in_fov(&self, cell_id: &Vec3Int) -> bool {
+        if get_deck(cell_id.y) != self.deck {
+            return false;
+        }
+        let coords = to_doryen_coordinates(cell_id.x, cell_id.z);
+        self.fov.is_in_fov(coords.0, coords.1)
+    }
+
+    // Changes to cells inside the window can change what the senser sees.
+    pub fn is_cell_in_fov_window(&self, cell_id: &Vec3Int) -> bool {
+        if get_deck(cell_id.y) != self.deck {
+            return false;
+        }
+        let coords = to_doryen_coordinates(cell_id.x, cell_id.z);
+        self.fov.contains(coords.0, coords.1)
+    }
+
+    pub fn update_fov(&mut self, map: &OcclusionMap) {
+        let coords = to_doryen_coordinates(self.cell_id.x, self.cell_id.y);
+        self.fov.compute_fov(map, coords.0, coords.1);
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::{EventReader, ResMut};
+use api::{
+    atmospherics::{AtmosIgnition, Atmospherics, CELCIUS_KELVIN_OFFSET, IGNITION_TEMPERATURE},
+    data::Vec2Int,
+};
+
+use crate::diffusion::AtmosphericsResource;
+
+// Mol of fuel burnt per diffusion step in a single cell.
+const BURN_RATE: f32 = 0.2;
+const OXYGEN_PER_FUEL: f32 = 2.;
+// Kelvin gained per mol burnt, divided over the mol in the cell.
+const COMBUSTION_HEAT: f32 = 2000.;
+const MAX_FIRE_TEMPERATURE: f32 = 1500. + CELCIUS_KELVIN_OFFSET;
+
+// Burns a single diffusion step worth of fuel, returns whether the cell is on fire.
+fn combust(atmospherics: &mut Atmospherics) -> bool {
+    if atmospherics.temperature < IGNITION_TEMPERATURE || !atmospherics.is_combustible() {
+        atmospherics.burning = false;
+        return false;
+    }
+
+    let burnt_fuel = BURN_RATE
+        .min(atmospherics.fuel)
+        .min(atmospherics.oxygen / OXYGEN_PER_FUEL);
+    let burnt_oxygen = burnt_fuel * OXYGEN_PER_FUEL;
+
+    atmospherics.fuel -= burnt_fuel;
+    atmospherics.oxygen -= burnt_oxygen;
+    atmospherics.amount = (atmospherics.amount - burnt_fuel - burnt_oxygen).max(0.);
+
+    atmospherics.temperature = (atmospherics.temperature
+        + burnt_fuel * COMBUSTION_HEAT / atmospherics.amount.max(1.))
+    .min(MAX_FIRE_TEMPERATURE);
+
+    atmospherics.burning = true;
+    true
+}
+
+pub fn atmos_combustion(mut atmospherics_resource: ResMut<AtmosphericsResource>) {
+    let mut burning = vec![];
+
+    for (i, atmospherics) in atmospherics_resource.atmospherics.iter_mut().enumerate() {
+        if combust(atmospherics) {
+            burning.push(i);
+        }
+    }
+
+    atmospherics_resource.burning = burning;
+}
+
+pub fn atmos_ignition(
+    mut atmos_ignitions: EventReader<AtmosIgnition>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+) {
+    for atmos_ignition in atmos_ignitions.iter() {
+        if AtmosphericsResource::is_id_out_of_range(Vec2Int {
+            x: atmos_ignition.cell_id.x,
+            y: atmos_ignition.cell_id.z,
+        }) {
+            continue;
+        }
+
+        let atmos_index = atmospherics_resource.get_cell_atmos_index(atmos_ignition.cell_id);
+
+        match atmospherics_resource.atmospherics.get_mut(atmos_index) {
+            Some(atmospherics) => {
+                atmospherics.ignite();
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use api::atmospherics::{Atmospherics, IGNITION_TEMPERATURE};
+
+    use super::combust;
+
+    fn fuelled_cell() -> Atmospherics {
+        let mut atmospherics = Atmospherics::new_internal(false, false);
+        atmospherics.fuel = 10.;
+        atmospherics.amount += 10.;
+        atmospherics
+    }
+
+    #[test]
+    fn fuelled_cell_ignites_and_burns() {
+        let mut atmospherics = fuelled_cell();
+
+        assert!(!combust(&mut atmospherics));
+        assert!(atmospherics.ignite());
+        assert!(combust(&mut atmospherics));
+        assert!(atmospherics.burning);
+        assert!(atmospherics.fuel < 10.);
+        assert!(atmospherics.temperature > IGNITION_TEMPERATURE);
+    }
+
+    #[test]
+    fn air_does_not_ignite() {
+        let mut atmospherics = Atmospherics::new_internal(false, false);
+
+        assert!(!atmospherics.ignite());
+        assert!(!combust(&mut atmospherics));
+        assert!(!atmospherics.burning);
+    }
+
+    #[test]
+    fn fire_burns_out() {
+        let mut atmospherics = fuelled_cell();
+        atmospherics.ignite();
+
+        let mut steps = 0;
+        while combust(&mut atmospherics) {
+            steps += 1;
+            assert!(steps < 1000);
+        }
+
+        assert!(!atmospherics.burning);
+        assert!(!atmospherics.is_combustible());
+    }
+}
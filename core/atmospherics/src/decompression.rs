@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use bevy::{
+    math::Vec3,
+    prelude::{Entity, EventReader, EventWriter, Query, Res, Transform, Without},
+};
+use bevy_rapier3d::{
+    plugin::RapierContext,
+    prelude::{ExternalImpulse, RapierRigidBodyHandle},
+};
+use pawn::pawn::Pawn;
+use physics::physics::RigidBodyDisabled;
+use api::{
+    atmospherics::HullBreach,
+    data::Vec2Int,
+    gridmap::{get_deck, get_deck_atmos_index, get_deck_floor_height, world_to_cell_id, CELL_SIZE},
+    pawn::KnockDown,
+};
+
+use crate::diffusion::AtmosphericsResource;
+
+// World units around the breach that get pulled towards it.
+const DECOMPRESSION_RADIUS: f32 = 12.;
+// Velocity change per kpa on the pressurized side of the breach, right next to it.
+const DECOMPRESSION_SENSITIVITY: f32 = 0.1;
+const DECOMPRESSION_MAX_VELOCITY: f32 = 15.;
+// Pawns pulled harder than this fall over.
+const KNOCK_DOWN_MIN_VELOCITY: f32 = 4.;
+const KNOCK_DOWN_DURATION: f32 = 3.;
+
+// Cells within reach of the breach that air can flow through to it, blocked cells like walls
+// and closed doors shield whatever is behind them.
+fn connected_cells(
+    atmospherics_resource: &AtmosphericsResource,
+    breach: Vec2Int,
+    deck: usize,
+) -> HashSet<Vec2Int> {
+    let max_cells = (DECOMPRESSION_RADIUS / CELL_SIZE).ceil() as i16;
+
+    let mut connected = HashSet::new();
+    connected.insert(breach);
+    let mut open = vec![breach];
+
+    loop {
+        let cell;
+        match open.pop() {
+            Some(c) => {
+                cell = c;
+            }
+            None => {
+                break;
+            }
+        }
+
+        for neighbour in [
+            Vec2Int {
+                x: cell.x + 1,
+                y: cell.y,
+            },
+            Vec2Int {
+                x: cell.x - 1,
+                y: cell.y,
+            },
+            Vec2Int {
+                x: cell.x,
+                y: cell.y + 1,
+            },
+            Vec2Int {
+                x: cell.x,
+                y: cell.y - 1,
+            },
+        ] {
+            if (neighbour.x - breach.x).abs() > max_cells
+                || (neighbour.y - breach.y).abs() > max_cells
+                || connected.contains(&neighbour)
+                || AtmosphericsResource::is_id_out_of_range(neighbour)
+            {
+                continue;
+            }
+
+            match atmospherics_resource
+                .atmospherics
+                .get(get_deck_atmos_index(neighbour, deck))
+            {
+                Some(atmospherics) => {
+                    if atmospherics.blocked {
+                        continue;
+                    }
+                }
+                None => {
+                    continue;
+                }
+            }
+
+            connected.insert(neighbour);
+            open.push(neighbour);
+        }
+    }
+
+    connected
+}
+
+pub fn explosive_decompression(
+    mut hull_breaches: EventReader<HullBreach>,
+    mut rigid_bodies: Query<
+        (
+            Entity,
+            &Transform,
+            &mut ExternalImpulse,
+            &RapierRigidBodyHandle,
+            Option<&Pawn>,
+        ),
+        Without<RigidBodyDisabled>,
+    >,
+    rapier_context: Res<RapierContext>,
+    atmospherics_resource: Res<AtmosphericsResource>,
+    mut knock_down: EventWriter<KnockDown>,
+) {
+    for hull_breach in hull_breaches.iter() {
+        let connected = connected_cells(
+            &atmospherics_resource,
+            hull_breach.cell_id,
+            hull_breach.deck,
+        );
+
+        let breach_position = Vec3::new(
+            (hull_breach.cell_id.x as f32 + 0.5) * CELL_SIZE,
+            get_deck_floor_height(hull_breach.deck),
+            (hull_breach.cell_id.y as f32 + 0.5) * CELL_SIZE,
+        );
+
+        for (entity, transform, mut external_impulse, rigid_body_handle, pawn_component_option) in
+            rigid_bodies.iter_mut()
+        {
+            let cell_id = world_to_cell_id(transform.translation);
+
+            // Decks are sealed off from each other.
+            if get_deck(cell_id.y) != hull_breach.deck
+                || !connected.contains(&Vec2Int {
+                    x: cell_id.x,
+                    y: cell_id.z,
+                })
+            {
+                continue;
+            }
+
+            let mut to_breach = breach_position - transform.translation;
+            to_breach.y = 0.;
+
+            let distance = to_breach.length();
+
+            if distance > DECOMPRESSION_RADIUS {
+                continue;
+            }
+
+            let mass;
+
+            match rapier_context.bodies.get(rigid_body_handle.0) {
+                Some(rigid_body) => {
+                    mass = rigid_body.mass();
+                }
+                None => {
+                    continue;
+                }
+            }
+
+            let velocity = (hull_breach.pressure
+                * DECOMPRESSION_SENSITIVITY
+                * (1. - distance / DECOMPRESSION_RADIUS))
+                .min(DECOMPRESSION_MAX_VELOCITY);
+
+            let direction = to_breach.normalize_or_zero();
+
+            external_impulse.impulse += direction * velocity * mass;
+
+            if pawn_component_option.is_none() {
+                continue;
+            }
+
+            if velocity > KNOCK_DOWN_MIN_VELOCITY {
+                knock_down.send(KnockDown {
+                    entity,
+                    duration: KNOCK_DOWN_DURATION,
+                });
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
 use bevy::{
     core::{FixedTimesteps, Time},
@@ -6,9 +6,10 @@ use bevy::{
     prelude::{warn, Entity, Res, ResMut},
 };
 use api::{
-    atmospherics::Atmospherics,
-    data::Vec2Int,
-    gridmap::{get_atmos_index, FOV_MAP_WIDTH},
+    atmospherics::{Atmospherics, IGNITION_TEMPERATURE},
+    data::{Vec2Int, Vec3Int},
+    gridmap::{get_deck, get_deck_atmos_index, FOV_MAP_WIDTH},
+    metrics::Metrics,
 };
 
 use super::plugin::ATMOS_DIFFUSION_LABEL;
@@ -29,13 +30,16 @@ pub fn atmos_diffusion(
     time: Res<Time>,
     fixed_timesteps: Res<FixedTimesteps>,
     mut atmospherics: ResMut<AtmosphericsResource>,
+    metrics: Res<Metrics>,
 ) {
+    let started = Instant::now();
     let current_time_stamp = time.time_since_startup().as_millis();
 
     let overstep_percentage = fixed_timesteps
         .get(ATMOS_DIFFUSION_LABEL)
         .unwrap()
         .overstep_percentage();
+    metrics.record_overstep(ATMOS_DIFFUSION_LABEL, overstep_percentage);
     if overstep_percentage > 5. {
         if current_time_stamp > 60000 {
             warn!("overstep_percentage: {}", overstep_percentage);
@@ -49,111 +53,159 @@ pub fn atmos_diffusion(
 
     let default_x = FOV_MAP_WIDTH as i16 / 2;
 
-    let mut current_cell_id = Vec2Int {
-        x: -default_x - 1,
-        y: -default_x,
-    };
-
     let vacuum_atmos = Atmospherics::default();
 
-    // Takes about 1ms.
-    for _i in 0..FOV_MAP_WIDTH * FOV_MAP_WIDTH {
-        current_cell_id.x += 1;
-
-        if current_cell_id.x > default_x {
-            current_cell_id.x = -default_x;
-            current_cell_id.y += 1;
-        }
-
-        let current_cell_atmos = atmospherics
-            .atmospherics
-            .get(get_atmos_index(current_cell_id))
-            .unwrap();
-
-        if current_cell_atmos.blocked {
-            continue;
-        }
+    for deck in 0..atmospherics.decks {
+        let mut current_cell_id = Vec2Int {
+            x: -default_x - 1,
+            y: -default_x,
+        };
 
-        let mut total_temperature = 0.;
-        let mut total_amount = 0.;
+        // Takes about 1ms per deck.
+        for _i in 0..FOV_MAP_WIDTH * FOV_MAP_WIDTH {
+            current_cell_id.x += 1;
 
-        let mut non_blocking_adjacents: u8 = 0;
+            if current_cell_id.x > default_x {
+                current_cell_id.x = -default_x;
+                current_cell_id.y += 1;
+            }
 
-        for j in 0..4 {
-            let mut adjacent_cell_id = current_cell_id.clone();
+            let current_cell_atmos = atmospherics
+                .atmospherics
+                .get(get_deck_atmos_index(current_cell_id, deck))
+                .unwrap();
 
-            if j == 0 {
-                adjacent_cell_id.x += 1
-            } else if j == 1 {
-                adjacent_cell_id.x -= 1
-            } else if j == 2 {
-                adjacent_cell_id.y += 1
-            } else {
-                adjacent_cell_id.y -= 1
+            if current_cell_atmos.blocked {
+                continue;
             }
 
-            let out_of_range;
-
-            if AtmosphericsResource::is_id_out_of_range(adjacent_cell_id) {
-                out_of_range = true;
-            } else {
-                match atmospherics
-                    .atmospherics
-                    .get(get_atmos_index(adjacent_cell_id))
-                {
-                    Some(a) => {
-                        if !a.blocked {
-                            non_blocking_adjacents += 1;
-                            total_temperature += a.temperature;
-                            total_amount += a.amount;
-                        }
-                        out_of_range = false;
+            let mut total_temperature = 0.;
+            let mut total_amount = 0.;
+            let mut total_oxygen = 0.;
+            let mut total_fuel = 0.;
+            let mut burning_adjacent = false;
+
+            let mut non_blocking_adjacents: u8 = 0;
+
+            for j in 0..6 {
+                let mut adjacent_cell_id = current_cell_id.clone();
+                let mut adjacent_deck = deck;
+
+                if j == 0 {
+                    adjacent_cell_id.x += 1
+                } else if j == 1 {
+                    adjacent_cell_id.x -= 1
+                } else if j == 2 {
+                    adjacent_cell_id.y += 1
+                } else if j == 3 {
+                    adjacent_cell_id.y -= 1
+                } else if j == 4 {
+                    // Hatches connect a cell with the one right above it.
+                    if !current_cell_atmos.vertical_passage || deck + 1 >= atmospherics.decks {
+                        continue;
                     }
-                    None => {
-                        out_of_range = true;
+                    adjacent_deck += 1;
+                } else {
+                    if deck == 0
+                        || !atmospherics.atmospherics
+                            [get_deck_atmos_index(current_cell_id, deck - 1)]
+                        .vertical_passage
+                    {
+                        continue;
                     }
+                    adjacent_deck -= 1;
                 }
-            }
 
-            if out_of_range {
-                // Tile is outside of map range, permanent vacuum.
-                total_temperature += vacuum_atmos.temperature;
-                total_amount += vacuum_atmos.amount;
-            }
-        }
+                let out_of_range;
+
+                if AtmosphericsResource::is_id_out_of_range(adjacent_cell_id) {
+                    out_of_range = true;
+                } else {
+                    match atmospherics
+                        .atmospherics
+                        .get(get_deck_atmos_index(adjacent_cell_id, adjacent_deck))
+                    {
+                        Some(a) => {
+                            if !a.blocked {
+                                non_blocking_adjacents += 1;
+                                total_temperature += a.temperature;
+                                total_amount += a.amount;
+                                total_oxygen += a.oxygen;
+                                total_fuel += a.fuel;
+                                burning_adjacent = burning_adjacent || a.burning;
+                            }
+                            out_of_range = false;
+                        }
+                        None => {
+                            out_of_range = true;
+                        }
+                    }
+                }
 
-        if non_blocking_adjacents == 0 {
-            continue;
-        }
+                if out_of_range {
+                    // Tile is outside of map range, permanent vacuum.
+                    total_temperature += vacuum_atmos.temperature;
+                    total_amount += vacuum_atmos.amount;
+                }
+            }
 
-        //let new_temperature = total_temperature / non_blocking_adjacents as f32;
-        //let new_amount = total_amount / non_blocking_adjacents as f32;
+            if non_blocking_adjacents == 0 {
+                continue;
+            }
 
-        let new_temperature = (current_cell_atmos.temperature
-            + TEMPERATURE_DIFFUSIVITY * (total_temperature / non_blocking_adjacents as f32))
-            / (1. + TEMPERATURE_DIFFUSIVITY);
-        let new_amount = (current_cell_atmos.amount
-            + AMOUNT_DIFFUSIVITY * (total_amount / non_blocking_adjacents as f32))
-            / (1. + AMOUNT_DIFFUSIVITY);
+            //let new_temperature = total_temperature / non_blocking_adjacents as f32;
+            //let new_amount = total_amount / non_blocking_adjacents as f32;
+
+            let mut new_temperature = (current_cell_atmos.temperature
+                + TEMPERATURE_DIFFUSIVITY * (total_temperature / non_blocking_adjacents as f32))
+                / (1. + TEMPERATURE_DIFFUSIVITY);
+            let new_amount = (current_cell_atmos.amount
+                + AMOUNT_DIFFUSIVITY * (total_amount / non_blocking_adjacents as f32))
+                / (1. + AMOUNT_DIFFUSIVITY);
+            let new_oxygen = (current_cell_atmos.oxygen
+                + AMOUNT_DIFFUSIVITY * (total_oxygen / non_blocking_adjacents as f32))
+                / (1. + AMOUNT_DIFFUSIVITY);
+            let new_fuel = (current_cell_atmos.fuel
+                + AMOUNT_DIFFUSIVITY * (total_fuel / non_blocking_adjacents as f32))
+                / (1. + AMOUNT_DIFFUSIVITY);
+
+            // Fire spreads into adjacent cells that have something to burn.
+            if burning_adjacent
+                && current_cell_atmos.is_combustible()
+                && new_temperature < IGNITION_TEMPERATURE
+            {
+                new_temperature = IGNITION_TEMPERATURE;
+            }
 
-        let current_cell_atmos = atmospherics
-            .atmospherics
-            .get_mut(get_atmos_index(current_cell_id))
-            .unwrap();
+            let current_cell_atmos = atmospherics
+                .atmospherics
+                .get_mut(get_deck_atmos_index(current_cell_id, deck))
+                .unwrap();
 
-        current_cell_atmos.temperature = new_temperature;
-        current_cell_atmos.amount = new_amount;
+            current_cell_atmos.temperature = new_temperature;
+            current_cell_atmos.amount = new_amount;
+            current_cell_atmos.oxygen = new_oxygen;
+            current_cell_atmos.fuel = new_fuel;
+        }
     }
+
+    metrics.record_system("atmos_diffusion", started.elapsed());
 }
 
+// One grid per deck, one after another.
 pub struct AtmosphericsResource {
     pub atmospherics: Vec<Atmospherics>,
+    pub decks: usize,
+    // Indexes of the cells on fire, kept by atmos_combustion.
+    pub burning: Vec<usize>,
 }
 
 impl Default for AtmosphericsResource {
     fn default() -> Self {
         AtmosphericsResource {
             atmospherics: vec![Atmospherics::default(); FOV_MAP_WIDTH * FOV_MAP_WIDTH],
+            decks: 1,
+            burning: vec![],
         }
     }
 }
@@ -165,4 +217,15 @@ impl AtmosphericsResource {
         let in_range = range.contains(&id.x) && range.contains(&id.y);
         !in_range
     }
+
+    // Positions above the highest deck of the map use its top deck.
+    pub fn get_cell_atmos_index(&self, cell_id: Vec3Int) -> usize {
+        get_deck_atmos_index(
+            Vec2Int {
+                x: cell_id.x,
+                y: cell_id.z,
+            },
+            get_deck(cell_id.y).min(self.decks - 1),
+        )
+    }
 }
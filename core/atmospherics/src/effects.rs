@@ -1,20 +1,27 @@
+use std::time::Instant;
+
 use bevy::{
     core::{FixedTimesteps, Time},
     prelude::{warn, Res, ResMut},
 };
-use api::atmospherics::CELCIUS_KELVIN_OFFSET;
+use api::{
+    atmospherics::{CELCIUS_KELVIN_OFFSET, OXYGEN_RATIO},
+    metrics::Metrics,
+};
 
 use crate::diffusion::AtmosphericsResource;
 
 use super::plugin::ATMOS_DIFFUSION_LABEL;
 
-const ATMOS_EFFECT_SPEED: f32 = 0.01;
+pub const ATMOS_EFFECT_SPEED: f32 = 0.01;
 
 pub fn atmos_effects(
     time: Res<Time>,
     fixed_timesteps: Res<FixedTimesteps>,
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    metrics: Res<Metrics>,
 ) {
+    let started = Instant::now();
     let current_time_stamp = time.time_since_startup().as_millis();
 
     let overstep_percentage = fixed_timesteps
@@ -29,6 +36,7 @@ pub fn atmos_effects(
 
     for atmospherics in atmospherics_resource.atmospherics.iter_mut() {
         let mut total_amount_additive = 0.;
+        let mut total_fuel_additive = 0.;
         let mut total_temperature_additive = 0.;
 
         for effect in atmospherics.effects.values() {
@@ -51,10 +59,14 @@ pub fn atmos_effects(
             } else {
                 if atmospherics.amount < effect.target_amount {
                     total_amount_additive += effect.target_amount * effect.amount_speed;
+                    total_fuel_additive +=
+                        effect.target_amount * effect.amount_speed * effect.fuel_ratio;
                 }
             }
         }
 
+        let previous_amount = atmospherics.amount;
+
         atmospherics.amount += total_amount_additive * ATMOS_EFFECT_SPEED;
         atmospherics.temperature += total_temperature_additive * ATMOS_EFFECT_SPEED;
 
@@ -62,8 +74,22 @@ pub fn atmos_effects(
             atmospherics.amount = 0.;
         }
 
+        // Effects add air mixed with their share of fuel and remove the mixture as it is.
+        if atmospherics.amount < previous_amount {
+            let remaining = atmospherics.amount / previous_amount;
+            atmospherics.oxygen *= remaining;
+            atmospherics.fuel *= remaining;
+        } else {
+            let added = atmospherics.amount - previous_amount;
+            let added_fuel = (total_fuel_additive * ATMOS_EFFECT_SPEED).min(added);
+            atmospherics.fuel += added_fuel;
+            atmospherics.oxygen += (added - added_fuel) * OXYGEN_RATIO;
+        }
+
         if atmospherics.temperature < -270.45 + CELCIUS_KELVIN_OFFSET {
             atmospherics.temperature = -270.45 + CELCIUS_KELVIN_OFFSET;
         }
     }
+
+    metrics.record_system("atmos_effects", started.elapsed());
 }
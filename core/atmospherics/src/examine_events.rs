@@ -3,7 +3,7 @@ use api::{
     atmospherics::CELCIUS_KELVIN_OFFSET,
     chat::{ATMOSPHERICS_TEXT_COLOR, FURTHER_ITALIC_FONT},
     data::Vec2Int,
-    gridmap::{get_atmos_index, GridmapExamineMessages},
+    gridmap::GridmapExamineMessages,
     network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage},
     senser::{Senser, SensingAbility},
 };
@@ -57,7 +57,10 @@ pub fn examine_map_atmos(
 
                     let atmospherics = atmospherics_resource
                         .atmospherics
-                        .get(get_atmos_index(id))
+                        .get(
+                            atmospherics_resource
+                                .get_cell_atmos_index(examine_event.gridmap_cell_id),
+                        )
                         .unwrap();
 
                     if atmospherics.blocked {
@@ -82,6 +85,17 @@ pub fn examine_map_atmos(
                         + &atmospherics.get_pressure().floor().to_string()
                         + " kpa"
                         + "[/font]";
+
+                    if atmospherics.burning {
+                        examine_text = examine_text
+                            + "[font="
+                            + FURTHER_ITALIC_FONT
+                            + "][color="
+                            + ATMOSPHERICS_TEXT_COLOR
+                            + "]"
+                            + "\nIt is on fire!"
+                            + "[/color][/font]";
+                    }
                 }
                 _ => (),
             }
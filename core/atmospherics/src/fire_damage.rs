@@ -0,0 +1,122 @@
+use bevy::prelude::{Query, Res, ResMut, Transform};
+use pawn::pawn::Pawn;
+use api::{
+    atmospherics::IGNITION_TEMPERATURE,
+    data::{Vec2Int, Vec3Int},
+    gridmap::{
+        get_atmos_deck, get_atmos_id, get_floor_layer, get_wall_layer, world_to_cell_id,
+        GridmapMain,
+    },
+    health::{Health, HealthContainer},
+};
+
+use crate::diffusion::AtmosphericsResource;
+
+// Burn damage per fire damage tick, scaled up by how far the fire is above its ignition temperature.
+const FIRE_PAWN_DAMAGE: f32 = 1.;
+const FIRE_STRUCTURE_DAMAGE: f32 = 0.5;
+
+fn get_fire_damage(temperature: f32, damage: f32) -> f32 {
+    damage * (temperature / IGNITION_TEMPERATURE).max(1.)
+}
+
+pub fn fire_damage(
+    atmospherics_resource: Res<AtmosphericsResource>,
+    mut pawns: Query<(&Pawn, &Transform, &mut Health)>,
+    mut gridmap_main: ResMut<GridmapMain>,
+) {
+    if atmospherics_resource.burning.is_empty() {
+        return;
+    }
+
+    for (_pawn_component, transform, mut health_component) in pawns.iter_mut() {
+        let cell_id = world_to_cell_id(transform.translation);
+        let id = Vec2Int {
+            x: cell_id.x,
+            y: cell_id.z,
+        };
+
+        if AtmosphericsResource::is_id_out_of_range(id) {
+            continue;
+        }
+
+        let atmospherics = atmospherics_resource
+            .atmospherics
+            .get(atmospherics_resource.get_cell_atmos_index(cell_id))
+            .unwrap();
+
+        if !atmospherics.burning {
+            continue;
+        }
+
+        let damage = get_fire_damage(atmospherics.temperature, FIRE_PAWN_DAMAGE);
+
+        match &mut health_component.health_container {
+            HealthContainer::Humanoid(humanoid_health) => {
+                // Fire burns the whole body at once.
+                let limb_damage = damage / 6.;
+                humanoid_health.head_burn += limb_damage;
+                humanoid_health.torso_burn += limb_damage;
+                humanoid_health.left_arm_burn += limb_damage;
+                humanoid_health.right_arm_burn += limb_damage;
+                humanoid_health.left_leg_burn += limb_damage;
+                humanoid_health.right_leg_burn += limb_damage;
+            }
+            HealthContainer::Entity(entity_health) => {
+                entity_health.burn += damage;
+            }
+        }
+    }
+
+    for i in atmospherics_resource.burning.iter().copied() {
+        let atmospherics = match atmospherics_resource.atmospherics.get(i) {
+            Some(atmospherics) => atmospherics,
+            None => {
+                continue;
+            }
+        };
+
+        let id = get_atmos_id(i);
+        let floor = get_floor_layer(get_atmos_deck(i));
+        let wall = get_wall_layer(get_atmos_deck(i));
+        let damage = get_fire_damage(atmospherics.temperature, FIRE_STRUCTURE_DAMAGE);
+
+        // The floor underneath and the walls around a burning cell.
+        let cell_ids = [
+            Vec3Int {
+                x: id.x,
+                y: floor,
+                z: id.y,
+            },
+            Vec3Int {
+                x: id.x + 1,
+                y: wall,
+                z: id.y,
+            },
+            Vec3Int {
+                x: id.x - 1,
+                y: wall,
+                z: id.y,
+            },
+            Vec3Int {
+                x: id.x,
+                y: wall,
+                z: id.y + 1,
+            },
+            Vec3Int {
+                x: id.x,
+                y: wall,
+                z: id.y - 1,
+            },
+        ];
+
+        for cell_id in cell_ids {
+            match gridmap_main.grid_data.get_mut(&cell_id) {
+                Some(cell_data) => {
+                    cell_data.health.burn += damage;
+                }
+                None => {}
+            }
+        }
+    }
+}
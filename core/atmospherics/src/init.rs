@@ -1,11 +1,15 @@
-use bevy::prelude::{info, Res, ResMut};
+use bevy::prelude::{info, EventReader, Res, ResMut};
 use api::{
     atmospherics::{Atmospherics, DEFAULT_INTERNAL_AMOUNT},
     data::{Vec2Int, Vec3Int},
-    gridmap::{get_atmos_index, GridmapData, GridmapMain, FOV_MAP_WIDTH},
+    gridmap::{
+        get_deck_atmos_index, get_floor_layer, get_wall_layer, GridmapData, GridmapMain,
+        FOV_MAP_WIDTH,
+    },
+    rounds::ResetWorld,
 };
 
-use crate::diffusion::AtmosphericsResource;
+use crate::diffusion::{AtmosphericsResource, RigidBodyForcesAccumulation};
 
 pub fn startup_atmospherics(
     gridmap_main: Res<GridmapMain>,
@@ -16,74 +20,82 @@ pub fn startup_atmospherics(
     let default_x = FOV_MAP_WIDTH as i16 / 2;
     let default_z = FOV_MAP_WIDTH as i16 / 2;
 
-    let mut current_cell_id = Vec2Int {
-        x: -default_x - 1,
-        y: -default_z,
-    };
+    let decks = gridmap_main.decks.max(1);
+    atmospherics.decks = decks;
+    atmospherics.atmospherics =
+        vec![Atmospherics::default(); decks * FOV_MAP_WIDTH * FOV_MAP_WIDTH];
 
     let mut vacuum_cells: u32 = 0;
 
-    for _i in 0..FOV_MAP_WIDTH * FOV_MAP_WIDTH {
-        current_cell_id.x += 1;
+    for deck in 0..decks {
+        let mut current_cell_id = Vec2Int {
+            x: -default_x - 1,
+            y: -default_z,
+        };
 
-        if current_cell_id.x > default_x {
-            current_cell_id.x = -default_x;
-            current_cell_id.y += 1;
-        }
+        for _i in 0..FOV_MAP_WIDTH * FOV_MAP_WIDTH {
+            current_cell_id.x += 1;
 
-        let blocked;
-        let push_up;
-
-        match gridmap_main.grid_data.get(&Vec3Int {
-            x: current_cell_id.x,
-            y: 0,
-            z: current_cell_id.y,
-        }) {
-            Some(cell_data) => {
-                let properties = gridmap_main_data
-                    .main_cell_properties
-                    .get(&cell_data.item)
-                    .unwrap();
-                blocked = properties.atmospherics_blocker;
-                push_up = properties.atmospherics_pushes_up;
-            }
-            None => {
-                blocked = false;
-                push_up = false;
+            if current_cell_id.x > default_x {
+                current_cell_id.x = -default_x;
+                current_cell_id.y += 1;
             }
-        }
 
-        let internal;
+            let blocked;
+            let push_up;
 
-        match gridmap_main.grid_data.get(&Vec3Int {
-            x: current_cell_id.x,
-            y: -1,
-            z: current_cell_id.y,
-        }) {
-            Some(_cell_data) => {
-                internal = true;
+            match gridmap_main.grid_data.get(&Vec3Int {
+                x: current_cell_id.x,
+                y: get_wall_layer(deck),
+                z: current_cell_id.y,
+            }) {
+                Some(cell_data) => {
+                    let properties = gridmap_main_data
+                        .main_cell_properties
+                        .get(&cell_data.item)
+                        .unwrap();
+                    blocked = properties.atmospherics_blocker;
+                    push_up = properties.atmospherics_pushes_up;
+                }
+                None => {
+                    blocked = false;
+                    push_up = false;
+                }
             }
-            None => {
-                internal = false;
+
+            let internal;
+
+            match gridmap_main.grid_data.get(&Vec3Int {
+                x: current_cell_id.x,
+                y: get_floor_layer(deck),
+                z: current_cell_id.y,
+            }) {
+                Some(_cell_data) => {
+                    internal = true;
+                }
+                None => {
+                    internal = false;
+                }
             }
-        }
 
-        if internal {
-            atmospherics.atmospherics[get_atmos_index(current_cell_id)] =
-                Atmospherics::new_internal(blocked, push_up);
-        } else {
-            let flags = vec!["default_vacuum".to_string()];
-            atmospherics.atmospherics[get_atmos_index(current_cell_id)] = Atmospherics {
-                blocked,
-                flags,
-                forces_push_up: push_up,
-                ..Default::default()
-            };
-            vacuum_cells += 1;
+            if internal {
+                atmospherics.atmospherics[get_deck_atmos_index(current_cell_id, deck)] =
+                    Atmospherics::new_internal(blocked, push_up);
+            } else {
+                let flags = vec!["default_vacuum".to_string()];
+                atmospherics.atmospherics[get_deck_atmos_index(current_cell_id, deck)] =
+                    Atmospherics {
+                        blocked,
+                        flags,
+                        forces_push_up: push_up,
+                        ..Default::default()
+                    };
+                vacuum_cells += 1;
+            }
         }
     }
 
-    let internal_cells_count = (FOV_MAP_WIDTH * FOV_MAP_WIDTH - vacuum_cells as usize) as f32;
+    let internal_cells_count = (atmospherics.atmospherics.len() - vacuum_cells as usize) as f32;
 
     let internal_m3 = internal_cells_count / 2.;
 
@@ -93,7 +105,21 @@ pub fn startup_atmospherics(
     let internal_kilo_liter = internal_liter * 0.001;
 
     info!(
-        "Loaded {:.1}Mmol atmosphere into {:.1}kl ship.",
-        internal_mega_mol, internal_kilo_liter
+        "Loaded {:.1}Mmol atmosphere into {:.1}kl ship over {} deck(s).",
+        internal_mega_mol, internal_kilo_liter, decks
     );
 }
+
+// Empties the atmospherics, the rounds plugin initializes them again the frame after.
+pub fn reset_atmospherics(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut atmospherics: ResMut<AtmosphericsResource>,
+    mut rigid_body_forces_accumulation: ResMut<RigidBodyForcesAccumulation>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    *atmospherics = AtmosphericsResource::default();
+    rigid_body_forces_accumulation.data.clear();
+}
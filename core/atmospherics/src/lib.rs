@@ -1,6 +1,9 @@
+pub mod combustion;
+pub mod decompression;
 pub mod diffusion;
 pub mod effects;
 pub mod examine_events;
+pub mod fire_damage;
 pub mod init;
 pub mod map_events;
 pub mod net;
@@ -9,15 +9,17 @@ pub fn atmospherics_map_hover(
             Some((idx, idy)) => {
                 let id = Vec2Int { x: idx, y: idy };
 
-                if AtmosphericsResource::is_id_out_of_range(id) {
+                if AtmosphericsResource::is_id_out_of_range(id)
+                    || map_component.deck >= atmospherics.decks
+                {
                     continue;
                 }
 
-                let cell_i = get_atmos_index(id);
+                let cell_i = get_deck_atmos_index(id, map_component.deck);
 
                 let cell_atmos = atmospherics.atmospherics.get(cell_i).unwrap();
 
-                let data;
+                let mut data;
 
                 if cell_atmos.blocked {
                     data = "".to_string();
@@ -30,6 +32,14 @@ pub fn atmospherics_map_hover(
                         + "Pressure: "
                         + &cell_atmos.get_pressure().floor().to_string()
                         + " kpa";
+
+                    if cell_atmos.fuel > 0. {
+                        data = data + "\nFuel: " + &cell_atmos.fuel.floor().to_string() + " mol";
+                    }
+
+                    if cell_atmos.burning {
+                        data = data + "\nOn fire!";
+                    }
                 }
 
                 match display_atmos_state.holders.get_mut(&map_holder_entity) {
@@ -62,9 +72,11 @@ use map::map::{
     get_overlay_tile_item, get_overlay_tile_priority, Map, MapHolderData, MapHolders, OverlayTile,
 };
 use api::{
-    atmospherics::CELCIUS_KELVIN_OFFSET,
+    atmospherics::{Atmospherics, CELCIUS_KELVIN_OFFSET, IGNITION_TEMPERATURE},
     data::{ConnectedPlayer, Vec2Int},
-    gridmap::{get_atmos_id, get_atmos_index, world_to_cell_id, FOV_MAP_WIDTH},
+    gridmap::{
+        get_atmos_id, get_atmos_index, get_deck_atmos_index, world_to_cell_id, FOV_MAP_WIDTH,
+    },
     network::ReliableServerMessage,
 };
 
@@ -78,6 +90,7 @@ enum SelectedDisplayMode {
     Temperature,
     Pressure,
     Liveable,
+    Fire,
 }
 
 pub fn atmospherics_map(
@@ -97,6 +110,8 @@ pub fn atmospherics_map(
                     show_temperature = SelectedDisplayMode::Pressure;
                 } else if selected_display_mode == "atmospherics_liveable" {
                     show_temperature = SelectedDisplayMode::Liveable;
+                } else if selected_display_mode == "atmospherics_fire" {
+                    show_temperature = SelectedDisplayMode::Fire;
                 } else {
                     continue;
                 }
@@ -106,6 +121,13 @@ pub fn atmospherics_map(
             }
         }
 
+        if map_component.deck >= atmospherics.decks {
+            continue;
+        }
+
+        // The cache and batches work with indexes local to the selected deck.
+        let deck_offset = map_component.deck * FOV_MAP_WIDTH * FOV_MAP_WIDTH;
+
         let camera_center_cell_3 = world_to_cell_id(Vec3::new(
             map_component.camera_position.x,
             0.,
@@ -223,7 +245,7 @@ pub fn atmospherics_map(
 
             let atmospherics_data;
 
-            match atmospherics.atmospherics.get(cell_i) {
+            match atmospherics.atmospherics.get(deck_offset + cell_i) {
                 Some(x) => {
                     atmospherics_data = x;
                 }
@@ -283,6 +305,11 @@ pub fn atmospherics_map(
                         new_tile_color = pressure_tile_color;
                     }
                 }
+                SelectedDisplayMode::Fire => {
+                    let tile_color = fire_to_tile_color(atmospherics_data);
+                    item = get_overlay_tile_item(&tile_color);
+                    new_tile_color = tile_color;
+                }
             }
 
             let should_update;
@@ -457,3 +484,16 @@ fn pressure_to_tile_color(pressure_kpa: f32) -> OverlayTile {
         OverlayTile::Red
     }
 }
+
+fn fire_to_tile_color(atmospherics: &Atmospherics) -> OverlayTile {
+    if atmospherics.burning {
+        OverlayTile::Red
+    } else if atmospherics.is_combustible() && atmospherics.temperature > IGNITION_TEMPERATURE - 50.
+    {
+        OverlayTile::Orange
+    } else if atmospherics.is_combustible() {
+        OverlayTile::Yellow
+    } else {
+        OverlayTile::Green
+    }
+}
@@ -3,8 +3,8 @@ use std::collections::HashMap;
 use bevy::prelude::{Entity, EventWriter, Local, Query, Res, Transform};
 use pawn::pawn::Pawn;
 use api::{
-    data::{ConnectedPlayer, Vec2Int, ZeroGravity},
-    gridmap::{get_atmos_index, world_to_cell_id},
+    data::{ConnectedPlayer, ZeroGravity},
+    gridmap::world_to_cell_id,
     network::ReliableServerMessage,
 };
 
@@ -59,10 +59,7 @@ pub fn atmospherics_notices(
 
         let atmospherics = atmospherics_resource
             .atmospherics
-            .get(get_atmos_index(Vec2Int {
-                x: cell_id.x,
-                y: cell_id.z,
-            }))
+            .get(atmospherics_resource.get_cell_atmos_index(cell_id))
             .unwrap();
 
         let pressure = atmospherics.get_pressure();
@@ -1,15 +1,20 @@
 use networking::messages::net_system;
+use api::atmospherics::{AtmosIgnition, HullBreach};
 use api::data::{MapLabels, PostUpdateLabels, StartupLabels, UpdateLabels};
+use api::rounds::RoundsLabels;
 use api::examinable::ExamineLabels;
 
 use crate::diffusion::AtmosphericsResource;
 use crate::examine_events::{examine_map_atmos, NetAtmosphericsMapExamine};
-use crate::init::startup_atmospherics;
+use crate::init::{reset_atmospherics, startup_atmospherics};
 use crate::remove_cell_atmos_event::remove_cell_atmos_event;
 
 use super::{
+    combustion::{atmos_combustion, atmos_ignition},
+    decompression::explosive_decompression,
     diffusion::{atmos_diffusion, RigidBodyForcesAccumulation, DIFFUSION_STEP},
     effects::atmos_effects,
+    fire_damage::fire_damage,
     map_events::{atmospherics_map, atmospherics_map_hover},
     net::{NetAtmosphericsNotices, NetMapDisplayAtmospherics, NetMapHoverAtmospherics},
     notices::atmospherics_notices,
@@ -28,13 +33,20 @@ impl Plugin for AtmosphericsPlugin {
         app.init_resource::<AtmosphericsResource>()
             .add_system(atmospherics_map_hover.after(MapLabels::ChangeMode))
             .add_system(atmospherics_sensing_ability)
+            .add_system(
+                reset_atmospherics
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
             .add_system(remove_cell_atmos_event.label(UpdateLabels::DeconstructCell))
+            .add_system(explosive_decompression.after(UpdateLabels::DeconstructCell))
             .add_system_to_stage(CoreStage::Update, rigidbody_forces_physics)
             .add_system_to_stage(CoreStage::Update, zero_gravity)
             .add_system_set(
                 SystemSet::new()
                     .with_run_criteria(FixedTimestep::step(1. / 4.).with_label(ATMOS_LABEL))
                     .with_system(atmospherics_notices)
+                    .with_system(fire_damage)
                     .with_system(atmospherics_map.after(MapLabels::ChangeMode)),
             )
             .add_event::<NetMapDisplayAtmospherics>()
@@ -49,6 +61,11 @@ impl Plugin for AtmosphericsPlugin {
                             .after(AtmosphericsLabels::Diffusion)
                             .label(AtmosphericsLabels::Effects),
                     )
+                    .with_system(
+                        atmos_combustion
+                            .after(AtmosphericsLabels::Effects)
+                            .label(AtmosphericsLabels::Combustion),
+                    )
                     .with_system(rigidbody_forces_accumulation.after(AtmosphericsLabels::Effects)),
             )
             .init_resource::<RigidBodyForcesAccumulation>()
@@ -59,6 +76,9 @@ impl Plugin for AtmosphericsPlugin {
                     .after(StartupLabels::BuildGridmap),
             )
             .add_event::<NetAtmosphericsNotices>()
+            .add_event::<HullBreach>()
+            .add_event::<AtmosIgnition>()
+            .add_system(atmos_ignition)
             .add_event::<NetAtmosphericsMapExamine>()
             .add_system_set_to_stage(
                 PostUpdate,
@@ -84,4 +104,5 @@ use bevy::{
 pub enum AtmosphericsLabels {
     Diffusion,
     Effects,
+    Combustion,
 }
@@ -1,34 +1,98 @@
-use bevy::prelude::{EventReader, Res, ResMut};
+use bevy::prelude::{EventReader, EventWriter, Res, ResMut};
 use api::{
-    atmospherics::{EffectType, VACUUM_ATMOSEFFECT},
+    atmospherics::{EffectType, HullBreach, VACUUM_ATMOSEFFECT},
     data::Vec2Int,
-    gridmap::{get_atmos_index, GridMapType, GridmapMain, RemoveCell},
+    gridmap::{
+        get_deck, get_deck_atmos_index, get_wall_layer, is_wall_layer, GridMapType, GridmapMain,
+        RemoveCell, FOV_MAP_WIDTH,
+    },
 };
 
 use crate::diffusion::AtmosphericsResource;
 
+// Below this amount of mol a cell is not considered pressurized.
+const BREACH_MIN_AMOUNT: f32 = 1.;
+
+// The highest pressure next to vacuum if the cell now connects the two.
+fn breach_pressure(
+    atmospherics_resource: &AtmosphericsResource,
+    cell_id: Vec2Int,
+    deck: usize,
+) -> Option<f32> {
+    let mut has_vacuum = false;
+    let mut pressure_option = None;
+
+    for (offset_x, offset_y) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+        let x = cell_id.x + offset_x;
+        let y = cell_id.y + offset_y;
+
+        if x.abs() >= FOV_MAP_WIDTH as i16 / 2 || y.abs() >= FOV_MAP_WIDTH as i16 / 2 {
+            continue;
+        }
+
+        let atmospherics = atmospherics_resource
+            .atmospherics
+            .get(get_deck_atmos_index(Vec2Int { x, y }, deck))
+            .unwrap();
+
+        if atmospherics.blocked {
+            continue;
+        }
+
+        if atmospherics.effects.contains_key(&EffectType::Floorless) {
+            has_vacuum = true;
+        } else if atmospherics.amount > BREACH_MIN_AMOUNT {
+            let pressure = atmospherics.get_pressure();
+            match pressure_option {
+                Some(highest_pressure) => {
+                    if pressure > highest_pressure {
+                        pressure_option = Some(pressure);
+                    }
+                }
+                None => {
+                    pressure_option = Some(pressure);
+                }
+            }
+        }
+    }
+
+    if has_vacuum {
+        pressure_option
+    } else {
+        None
+    }
+}
+
 pub fn remove_cell_atmos_event(
     mut deconstruct_cell_events: EventReader<RemoveCell>,
     gridmap_main: Res<GridmapMain>,
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    mut hull_breach: EventWriter<HullBreach>,
 ) {
     for event in deconstruct_cell_events.iter() {
         match event.gridmap_type {
             GridMapType::Main => {
+                let cell_id = Vec2Int {
+                    x: event.id.x,
+                    y: event.id.z,
+                };
+                let deck = get_deck(event.id.y);
+
+                if deck >= atmospherics_resource.decks {
+                    continue;
+                }
+
                 let mut atmospherics = atmospherics_resource
                     .atmospherics
-                    .get_mut(get_atmos_index(Vec2Int {
-                        x: event.id.x,
-                        y: event.id.z,
-                    }))
+                    .get_mut(get_deck_atmos_index(cell_id, deck))
                     .unwrap();
 
-                if event.id.y == 0 {
+                if is_wall_layer(event.id.y) {
                     atmospherics.blocked = false;
                     atmospherics.forces_push_up = false;
                 } else {
                     let mut upper_id = event.id.clone();
-                    upper_id.y = 0;
+                    upper_id.y = get_wall_layer(deck);
 
                     // Add vacuum flag to atmos.
                     match gridmap_main.grid_data.get(&upper_id) {
@@ -40,6 +104,17 @@ pub fn remove_cell_atmos_event(
                         }
                     }
                 }
+
+                match breach_pressure(&atmospherics_resource, cell_id, deck) {
+                    Some(pressure) => {
+                        hull_breach.send(HullBreach {
+                            cell_id,
+                            deck,
+                            pressure,
+                        });
+                    }
+                    None => {}
+                }
             }
             GridMapType::Details1 => {}
         }
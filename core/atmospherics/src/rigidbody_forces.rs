@@ -8,9 +8,10 @@ use bevy_rapier3d::prelude::ExternalForce;
 use pawn::pawn::Pawn;
 use physics::physics::RigidBodyDisabled;
 use api::{
-    data::{TickRate, Vec2Int},
+    data::TickRate,
     gridmap::{
-        get_atmos_index, world_to_cell_id, AdjacentTileDirection, GridmapMain, FOV_MAP_WIDTH,
+        get_deck, get_floor_layer, world_to_cell_id, AdjacentTileDirection, GridmapMain,
+        FOV_MAP_WIDTH,
     },
 };
 
@@ -83,13 +84,9 @@ pub fn rigidbody_forces_accumulation(
             continue;
         }
 
-        let cell_id2 = Vec2Int {
-            x: cell_id.x,
-            y: cell_id.z,
-        };
         let self_atmospherics = atmospherics_resource
             .atmospherics
-            .get(get_atmos_index(cell_id2))
+            .get(atmospherics_resource.get_cell_atmos_index(cell_id))
             .unwrap();
 
         let mut atmos_force = Vec3::ZERO;
@@ -148,10 +145,7 @@ pub fn rigidbody_forces_accumulation(
 
             let adjacent_atmospherics = atmospherics_resource
                 .atmospherics
-                .get(get_atmos_index(Vec2Int {
-                    x: adjacent_cell_id.x,
-                    y: adjacent_cell_id.z,
-                }))
+                .get(atmospherics_resource.get_cell_atmos_index(adjacent_cell_id))
                 .unwrap();
 
             if adjacent_atmospherics.blocked {
@@ -173,7 +167,7 @@ pub fn rigidbody_forces_accumulation(
         }
 
         let mut floor_tile = cell_id.clone();
-        floor_tile.y = -1;
+        floor_tile.y = get_floor_layer(get_deck(cell_id.y));
 
         match gridmap_main.grid_data.get(&floor_tile) {
             Some(_) => {}
@@ -6,7 +6,7 @@ use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Friction};
 use rigid_body::rigid_body::RigidBodyData;
 use api::{
     data::ZeroGravity,
-    gridmap::{world_to_cell_id, GridmapMain},
+    gridmap::{get_deck, get_floor_layer, world_to_cell_id, GridmapMain},
 };
 pub fn zero_gravity(
     mut rigid_bodies: Query<(
@@ -54,7 +54,7 @@ pub fn zero_gravity(
 
         let mut cell_id = world_to_cell_id(rigidbody_position_component.translation.into());
 
-        cell_id.y = -1;
+        cell_id.y = get_floor_layer(get_deck(cell_id.y));
 
         match gridmap_main.grid_data.get(&cell_id) {
             Some(_) => {
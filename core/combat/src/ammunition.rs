@@ -0,0 +1,148 @@
+use std::mem::swap;
+
+use bevy::prelude::{Entity, EventWriter, Query, Res};
+use api::{
+    chat::FURTHER_ITALIC_FONT,
+    combat::{Ammunition, AmmunitionType, Magazine},
+    data::EntityDataResource,
+    data_link::DataLink,
+    entity_updates::EntityData,
+    examinable::Examinable,
+    gridmap::{CellData, GridMapType},
+    inventory::Inventory,
+    network::ReliableServerMessage,
+    tab_actions::QueuedTabActions,
+};
+
+use crate::attack::NetAttack;
+
+pub const RELOAD_TAB_ACTION_ID: &str = "actions::combat/reload";
+
+fn get_other_hand(inventory_component: &Inventory) -> Option<Entity> {
+    let other_hand = match inventory_component.active_slot.as_str() {
+        "left_hand" => "right_hand",
+        "right_hand" => "left_hand",
+        _ => {
+            return None;
+        }
+    };
+
+    inventory_component.get_slot(other_hand).slot_item
+}
+
+pub fn reload_action(
+    _self_tab_entity: Option<Entity>,
+    _entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    _distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    get_other_hand(inventory_component).is_some()
+}
+
+// Swaps the loaded magazine or power cell with the one held in the other hand,
+// the ejected one ends up in that hand.
+pub fn reload(
+    queue: Res<QueuedTabActions>,
+    inventories: Query<&Inventory>,
+    mut weapons: Query<(&mut Ammunition, &Examinable)>,
+    mut magazines: Query<(&mut Magazine, &Examinable)>,
+    mut net_attack: EventWriter<NetAttack>,
+) {
+    for queued in queue.queue.iter() {
+        if queued.tab_id != RELOAD_TAB_ACTION_ID {
+            continue;
+        }
+
+        let inventory_component;
+
+        match inventories.get(queued.player_entity) {
+            Ok(i) => {
+                inventory_component = i;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let weapon_entity;
+
+        match inventory_component.get_active_slot_entity() {
+            Some(entity) => {
+                weapon_entity = entity;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let magazine_entity;
+
+        match get_other_hand(inventory_component) {
+            Some(entity) => {
+                magazine_entity = entity;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let (mut ammunition_component, weapon_examinable_component) =
+            match weapons.get_mut(weapon_entity) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
+        let text;
+
+        match magazines.get_mut(magazine_entity) {
+            Ok((mut magazine_component, magazine_examinable_component)) => {
+                if magazine_component.ammunition_type != ammunition_component.loaded.ammunition_type
+                {
+                    text = "The ".to_string()
+                        + &magazine_examinable_component.name.get_name()
+                        + " does not fit the "
+                        + &weapon_examinable_component.name.get_name()
+                        + ".";
+                } else {
+                    swap(&mut ammunition_component.loaded, &mut *magazine_component);
+
+                    let verb = match ammunition_component.loaded.ammunition_type {
+                        AmmunitionType::Rounds => "reload",
+                        AmmunitionType::Charge => "swap the power cell of",
+                    };
+
+                    text = "You ".to_string()
+                        + verb
+                        + " the "
+                        + &weapon_examinable_component.name.get_name()
+                        + ", it now has "
+                        + &ammunition_component.loaded.get_description()
+                        + ".";
+                }
+            }
+            Err(_rr) => {
+                text = "You cannot load that into the ".to_string()
+                    + &weapon_examinable_component.name.get_name()
+                    + ".";
+            }
+        }
+
+        match queued.handle_option {
+            Some(handle) => {
+                net_attack.send(NetAttack {
+                    handle,
+                    message: ReliableServerMessage::ChatMessage(
+                        "[font=".to_string() + FURTHER_ITALIC_FONT + "]" + &text + "[/font]",
+                    ),
+                });
+            }
+            None => {}
+        }
+    }
+}
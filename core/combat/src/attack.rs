@@ -11,25 +11,42 @@ use bevy_rapier3d::{
 };
 use gridmap::events::Cell;
 use physics::physics::{get_bit_masks, ColliderGroup};
-use sfx::entity_update::SfxAutoDestroyTimers;
+use sfx::{builder::sfx_builder, entity_update::SfxAutoDestroyTimers};
 use api::{
+    atmospherics::AtmosIgnition,
     combat::{
-        CombatType, DamageModel, DamageType, HitResult, NetProjectileType, ProjectileFOV,
-        ProjectileType,
+        Ammunition, CombatType, DamageModel, DamageType, HitResult, NetProjectileType,
+        ProjectileFOV, ProjectileType,
     },
     data::{HandleToEntity, Vec3Int},
     examinable::Examinable,
     gridmap::{cell_id_to_world, get_cell_name, world_to_cell_id, GridmapData, GridmapMain},
     health::{Health, NetHealth},
-    network::ReliableServerMessage,
+    network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage},
     senser::Senser,
 };
-use sounds::shared::CombatSoundSet;
+use sounds::{
+    combat::empty_click_sfx::EmptyClickSfxBundle,
+    shared::{sfx_auto_destroy, CombatSoundSet},
+};
+
+use crate::{
+    ballistic::BallisticProjectile,
+    lag_compensation::{intersects_rewound, LagCompensation},
+};
 
 pub struct NetAttack {
     pub handle: u64,
     pub message: ReliableServerMessage,
 }
+impl PendingMessage for NetAttack {
+    fn get_message(&self) -> PendingNetworkMessage {
+        PendingNetworkMessage {
+            handle: self.handle,
+            message: self.message.clone(),
+        }
+    }
+}
 
 #[derive(Debug)]
 struct AttackResult {
@@ -40,6 +57,8 @@ struct AttackResult {
     collider_handle: Entity,
     is_combat_obstacle: bool,
     is_laser_obstacle: bool,
+    // Current minus rewound translation of lag compensated hits.
+    rewind_offset: Vec3,
 }
 
 const ATTACK_HEIGHT: f32 = 1.6;
@@ -58,10 +77,22 @@ pub fn attack(
     sensers: Query<(Entity, &Senser)>,
     gridmap_data: Res<GridmapData>,
     colliders: Query<&Parent, With<Collider>>,
+    mut ammunition: Query<&mut Ammunition>,
+    mut lag_compensation: ResMut<LagCompensation>,
+    mut atmos_ignition: EventWriter<AtmosIgnition>,
 ) {
     for attack_event in attack_events.iter() {
         let direction_additive = Vec3::new(-attack_event.angle.cos(), 0., attack_event.angle.sin());
 
+        // Hits get tested against entities where the attacking client saw them.
+        let view_time = lag_compensation.get_view_time(
+            handle_to_entity
+                .inv_map
+                .get(&attack_event.attacker_entity)
+                .copied(),
+        );
+        let rewound_translations = lag_compensation.get_rewound_translations(view_time);
+
         let attacker_cell_id = world_to_cell_id(attack_event.attacker_position);
 
         let mut sound_transform = Transform {
@@ -98,14 +129,18 @@ pub fn attack(
 
                 let shape_vec = Vec3::new(attack_event.range, cast_vertical_extents, 0.1);
 
+                let shape_position = Vec3::new(
+                    attack_event.attacker_position.x,
+                    attack_height,
+                    attack_event.attacker_position.z,
+                ) - additive;
+                let shape_rotation = Quat::from_rotation_y(attack_event.angle);
+                let shape = Collider::cuboid(shape_vec.x, shape_vec.y, shape_vec.z);
+
                 rapier_context.intersections_with_shape(
-                    Vec3::new(
-                        attack_event.attacker_position.x,
-                        attack_height,
-                        attack_event.attacker_position.z,
-                    ) - additive,
-                    Quat::from_rotation_y(attack_event.angle).into(),
-                    &Collider::cuboid(shape_vec.x, shape_vec.y, shape_vec.z),
+                    shape_position,
+                    shape_rotation.into(),
+                    &shape,
                     query_filter,
                     |child_entity| {
                         let collider_entity;
@@ -119,7 +154,9 @@ pub fn attack(
                             }
                         }
 
-                        if collider_entity == attack_event.attacker_entity {
+                        if collider_entity == attack_event.attacker_entity
+                            || is_rewound(&rewound_translations, collider_entity)
+                        {
                             return true;
                         }
 
@@ -152,6 +189,7 @@ pub fn attack(
                                     collider_handle: collider_entity,
                                     is_combat_obstacle: health_component.is_combat_obstacle,
                                     is_laser_obstacle: health_component.is_laser_obstacle,
+                                    rewind_offset: Vec3::ZERO,
                                 });
                             }
                             Err(_rr) => {}
@@ -176,6 +214,7 @@ pub fn attack(
                                     is_laser_obstacle: !gridmap_data
                                         .non_laser_obstacle_cells_list
                                         .contains(&cell_data.item),
+                                    rewind_offset: Vec3::ZERO,
                                 });
                             }
                             Err(_rr) => {}
@@ -185,6 +224,20 @@ pub fn attack(
                     },
                 );
 
+                push_rewound_hits(
+                    &mut hit_entities,
+                    &rewound_translations,
+                    attack_event,
+                    &rigidbody_query,
+                    &rapier_context,
+                    &colliders,
+                    shape_position,
+                    shape_rotation,
+                    &shape,
+                    interaction_groups,
+                    &mut lag_compensation,
+                );
+
                 hit_entities.sort_by(|a, b| b.distance.partial_cmp(&a.distance).unwrap());
                 hit_entities.reverse();
 
@@ -353,6 +406,26 @@ pub fn attack(
                 }
             }
             CombatType::Projectile(projectile_type) => {
+                // Weapons that take ammunition click empty without a loaded round or charge.
+                match attack_event.weapon_entity {
+                    Some(weapon_entity) => match ammunition.get_mut(weapon_entity) {
+                        Ok(mut ammunition_component) => {
+                            if ammunition_component.loaded.rounds == 0 {
+                                let sfx_entity = sfx_builder(
+                                    &mut commands,
+                                    sound_transform,
+                                    Box::new(EmptyClickSfxBundle::new),
+                                );
+                                sfx_auto_destroy(sfx_entity, &mut sfx_auto_destroy_timers);
+                                continue;
+                            }
+                            ammunition_component.loaded.rounds -= 1;
+                        }
+                        Err(_rr) => {}
+                    },
+                    None => {}
+                }
+
                 match projectile_type {
                     ProjectileType::Laser(laser_color, laser_height, laser_radius, laser_range) => {
                         attack_event.combat_sound_set.spawn_default_sfx(
@@ -395,10 +468,13 @@ pub fn attack(
 
                         let points_vec = Vec3::new(*laser_range, cast_vertical_extents, 0.1);
 
+                        let shape_rotation = Quat::from_rotation_y(attack_event.angle);
+                        let shape = Collider::cuboid(points_vec.x, points_vec.y, points_vec.z);
+
                         rapier_context.intersections_with_shape(
                             projectile_rough_end_position,
-                            Quat::from_rotation_y(attack_event.angle).into(),
-                            &Collider::cuboid(points_vec.x, points_vec.y, points_vec.z),
+                            shape_rotation.into(),
+                            &shape,
                             query_filter,
                             |child_entity| {
                                 let collider_entity;
@@ -412,7 +488,9 @@ pub fn attack(
                                     }
                                 }
 
-                                if collider_entity == attack_event.attacker_entity {
+                                if collider_entity == attack_event.attacker_entity
+                                    || is_rewound(&rewound_translations, collider_entity)
+                                {
                                     return true;
                                 }
 
@@ -446,6 +524,7 @@ pub fn attack(
                                             collider_handle: collider_entity,
                                             is_combat_obstacle: health_component.is_combat_obstacle,
                                             is_laser_obstacle: health_component.is_laser_obstacle,
+                                            rewind_offset: Vec3::ZERO,
                                         });
                                     }
                                     Err(_rr) => {}
@@ -476,6 +555,7 @@ pub fn attack(
                                             is_laser_obstacle: !gridmap_data
                                                 .non_laser_obstacle_cells_list
                                                 .contains(&cell_data.item),
+                                            rewind_offset: Vec3::ZERO,
                                         };
 
                                         hit_entities.push(r);
@@ -487,6 +567,20 @@ pub fn attack(
                             },
                         );
 
+                        push_rewound_hits(
+                            &mut hit_entities,
+                            &rewound_translations,
+                            attack_event,
+                            &rigidbody_query,
+                            &rapier_context,
+                            &colliders,
+                            projectile_rough_end_position,
+                            shape_rotation,
+                            &shape,
+                            interaction_groups,
+                            &mut lag_compensation,
+                        );
+
                         hit_entities.sort_by(|a, b| b.distance.partial_cmp(&a.distance).unwrap());
                         hit_entities.reverse();
 
@@ -577,8 +671,10 @@ pub fn attack(
 
                         match hit_entity {
                             Some(attack_result) => {
+                                // Rewound hits get cast from the offset start against the current collider.
                                 let ray = Ray::new(
-                                    projectile_start_position.into(),
+                                    (projectile_start_position + attack_result.rewind_offset)
+                                        .into(),
                                     (attack_result.rigid_body_position - projectile_start_position)
                                         .into(),
                                 );
@@ -607,7 +703,8 @@ pub fn attack(
                                         ),
                                     )
                                 {
-                                    hit_point = ray.point_at(hit_toi).into();
+                                    hit_point = Vec3::from(ray.point_at(hit_toi))
+                                        - attack_result.rewind_offset;
 
                                     match attack_result.entity_option {
                                         Some(_) => {}
@@ -705,6 +802,11 @@ pub fn attack(
                             }
                         }
 
+                        // The laser heats up whatever it ends on, fuel in that cell catches fire.
+                        atmos_ignition.send(AtmosIgnition {
+                            cell_id: world_to_cell_id(hit_point),
+                        });
+
                         let c_start_pos = projectile_start_position - (direction_additive * 0.5);
 
                         if c_start_pos.distance(hit_point) > 0.8 {
@@ -719,9 +821,114 @@ pub fn attack(
                             });
                         }
                     }
-                    ProjectileType::Ballistic => {}
+                    ProjectileType::Ballistic(muzzle_velocity, range, penetration) => {
+                        attack_event.combat_sound_set.spawn_default_sfx(
+                            &mut commands,
+                            sound_transform,
+                            &mut sfx_auto_destroy_timers,
+                        );
+
+                        let projectile_start_position = Vec3::new(
+                            attack_event.attacker_position.x,
+                            ATTACK_HEIGHT,
+                            attack_event.attacker_position.z,
+                        );
+
+                        let mut projectile = BallisticProjectile::new(
+                            projectile_start_position,
+                            -direction_additive,
+                            *muzzle_velocity,
+                            *range,
+                            *penetration,
+                        );
+
+                        projectile
+                            .ignored_entities
+                            .push(attack_event.attacker_entity);
+                        match attack_event.weapon_entity {
+                            Some(weapon_entity) => {
+                                projectile.ignored_entities.push(weapon_entity);
+                            }
+                            None => {}
+                        }
+                        projectile.attacker_name = attack_event.attacker_name.clone();
+                        projectile.attacker_cell_id = attacker_cell_id;
+                        projectile.weapon_name = attack_event.weapon_name.clone();
+                        projectile.weapon_a_name = attack_event.weapon_a_name.clone();
+                        projectile.targetted_limb = attack_event.targetted_limb.clone();
+                        projectile.damage_model = attack_event.damage_model.clone();
+                        projectile.combat_sound_set = attack_event.combat_sound_set.clone();
+                        projectile.offense_words = attack_event.offense_words.clone();
+                        projectile.trigger_words = attack_event.trigger_words.clone();
+
+                        commands.spawn().insert(projectile);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_rewound(rewound_translations: &Vec<(Entity, Vec3)>, entity: Entity) -> bool {
+    rewound_translations
+        .iter()
+        .any(|(rewound_entity, _)| *rewound_entity == entity)
+}
+
+// Entities that moved since the attacker saw them get hit tested at their rewound translation.
+fn push_rewound_hits(
+    hit_entities: &mut Vec<AttackResult>,
+    rewound_translations: &Vec<(Entity, Vec3)>,
+    attack_event: &Attack,
+    rigidbody_query: &Query<(&mut Health, &Examinable, &Transform)>,
+    rapier_context: &RapierContext,
+    colliders: &Query<&Parent, With<Collider>>,
+    shape_position: Vec3,
+    shape_rotation: Quat,
+    shape: &Collider,
+    interaction_groups: InteractionGroups,
+    lag_compensation: &mut LagCompensation,
+) {
+    for (rewound_entity, rewound_translation) in rewound_translations.iter() {
+        if *rewound_entity == attack_event.attacker_entity
+            || Some(*rewound_entity) == attack_event.weapon_entity
+        {
+            continue;
+        }
+
+        match rigidbody_query.get(*rewound_entity) {
+            Ok((health_component, _examinable_component, rigid_body_position_component)) => {
+                let rewind_offset =
+                    rigid_body_position_component.translation - *rewound_translation;
+
+                if !intersects_rewound(
+                    rapier_context,
+                    colliders,
+                    *rewound_entity,
+                    shape_position + rewind_offset,
+                    shape_rotation,
+                    shape,
+                    interaction_groups,
+                ) {
+                    continue;
                 }
+
+                lag_compensation.add_debug_hitbox(*rewound_translation);
+
+                hit_entities.push(AttackResult {
+                    entity_option: Some(*rewound_entity),
+                    cell_id_option: None,
+                    distance: attack_event
+                        .attacker_position
+                        .distance(*rewound_translation),
+                    rigid_body_position: *rewound_translation,
+                    collider_handle: *rewound_entity,
+                    is_combat_obstacle: health_component.is_combat_obstacle,
+                    is_laser_obstacle: health_component.is_laser_obstacle,
+                    rewind_offset,
+                });
             }
+            Err(_rr) => {}
         }
     }
 }
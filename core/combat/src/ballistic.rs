@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+
+use bevy::{
+    core::Time,
+    hierarchy::Parent,
+    math::Vec3,
+    prelude::{
+        Commands, Component, Entity, EventReader, EventWriter, Query, Res, ResMut, Transform, With,
+    },
+};
+use bevy_rapier3d::{
+    pipeline::QueryFilter,
+    plugin::RapierContext,
+    prelude::{Collider, InteractionGroups},
+    rapier::geometry::RayIntersection,
+};
+use gridmap::events::Cell;
+use physics::physics::{get_bit_masks, ColliderGroup};
+use sfx::entity_update::SfxAutoDestroyTimers;
+use api::{
+    combat::{DamageModel, DamageType, HitResult, NetProjectileType, ProjectileFOV},
+    data::{HandleToEntity, Vec3Int},
+    examinable::Examinable,
+    gridmap::{get_cell_name, world_to_cell_id, GridmapData, GridmapMain},
+    health::{Health, HealthFlag, NetHealth},
+    rounds::ResetWorld,
+    senser::Senser,
+};
+use sounds::shared::CombatSoundSet;
+
+// How much penetration power it costs to go through something.
+const ARMOUR_PLATED_RESISTANCE: f32 = 3.;
+const SOFT_RESISTANCE: f32 = 0.5;
+// Impacts closer to parallel with the surface than this (dot product with its normal) ricochet.
+const RICOCHET_MAX_INCIDENCE: f32 = 0.35;
+const MAX_RICOCHETS: u8 = 2;
+const RICOCHET_SPEED_RETAINED: f32 = 0.6;
+const RICOCHET_DAMAGE_FACTOR: f32 = 0.25;
+const PENETRATION_SPEED_RETAINED: f32 = 0.7;
+// Projectiles slower than this have lost their lethality and are removed.
+const MIN_PROJECTILE_SPEED: f32 = 5.;
+
+#[derive(Component)]
+pub struct BallisticProjectile {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub muzzle_speed: f32,
+    pub distance_left: f32,
+    pub penetration: f32,
+    pub ricochets_left: u8,
+    // Whether clients were sent the straight stretch of path the projectile is on.
+    pub path_sent: bool,
+    // The attacker, its weapon and everything already hit or penetrated.
+    pub ignored_entities: Vec<Entity>,
+    pub attacker_name: String,
+    pub attacker_cell_id: Vec3Int,
+    pub weapon_name: String,
+    pub weapon_a_name: String,
+    pub targetted_limb: String,
+    pub damage_model: DamageModel,
+    pub combat_sound_set: CombatSoundSet,
+    pub offense_words: Vec<String>,
+    pub trigger_words: Vec<String>,
+}
+
+impl BallisticProjectile {
+    pub fn new(
+        position: Vec3,
+        direction: Vec3,
+        muzzle_speed: f32,
+        range: f32,
+        penetration: f32,
+    ) -> Self {
+        Self {
+            position,
+            velocity: direction * muzzle_speed,
+            muzzle_speed,
+            distance_left: range,
+            penetration,
+            ricochets_left: MAX_RICOCHETS,
+            path_sent: false,
+            ignored_entities: vec![],
+            attacker_name: "".to_string(),
+            attacker_cell_id: Vec3Int::default(),
+            weapon_name: "".to_string(),
+            weapon_a_name: "".to_string(),
+            targetted_limb: "".to_string(),
+            damage_model: DamageModel::default(),
+            combat_sound_set: CombatSoundSet::default(),
+            offense_words: vec![],
+            trigger_words: vec![],
+        }
+    }
+
+    // Slower projectiles deal less damage.
+    fn get_damage_model(&self, factor: f32) -> DamageModel {
+        let factor = factor * self.velocity.length() / self.muzzle_speed;
+        let mut damage_model = self.damage_model.clone();
+        damage_model.brute *= factor;
+        damage_model.burn *= factor;
+        damage_model.toxin *= factor;
+        damage_model
+    }
+}
+
+enum Impact {
+    Ricochet,
+    Penetrate,
+    Stop,
+}
+
+fn get_penetration_resistance(health_flags: &HashMap<u32, HealthFlag>) -> f32 {
+    if health_flags
+        .values()
+        .any(|health_flag| health_flag == &HealthFlag::ArmourPlated)
+    {
+        ARMOUR_PLATED_RESISTANCE
+    } else {
+        SOFT_RESISTANCE
+    }
+}
+
+fn get_impact(
+    projectile: &BallisticProjectile,
+    normal: Vec3,
+    resistance: f32,
+    can_ricochet: bool,
+) -> Impact {
+    let incidence = projectile.velocity.normalize().dot(normal).abs();
+
+    if can_ricochet && projectile.ricochets_left > 0 && incidence < RICOCHET_MAX_INCIDENCE {
+        Impact::Ricochet
+    } else if projectile.penetration > resistance {
+        Impact::Penetrate
+    } else {
+        Impact::Stop
+    }
+}
+
+fn cast_projectile_ray(
+    rapier_context: &RapierContext,
+    colliders: &Query<&Parent, With<Collider>>,
+    interaction_groups: InteractionGroups,
+    projectile: &BallisticProjectile,
+    max_toi: f32,
+) -> Option<(Entity, RayIntersection)> {
+    let ignored_entities = &projectile.ignored_entities;
+
+    rapier_context.cast_ray_and_get_normal(
+        projectile.position,
+        projectile.velocity.normalize(),
+        max_toi,
+        true,
+        QueryFilter::new()
+            .groups(interaction_groups)
+            .predicate(&|child_entity| {
+                !ignored_entities.contains(&get_collider_entity(colliders, child_entity))
+            }),
+    )
+}
+
+fn get_collider_entity(colliders: &Query<&Parent, With<Collider>>, child_entity: Entity) -> Entity {
+    match colliders.get(child_entity) {
+        Ok(parent_entity) => parent_entity.0,
+        Err(_rr) => child_entity,
+    }
+}
+
+pub fn ballistic_projectiles(
+    mut commands: Commands,
+    mut projectiles: Query<(Entity, &mut BallisticProjectile)>,
+    time: Res<Time>,
+    rapier_context: Res<RapierContext>,
+    mut rigidbody_query: Query<(&mut Health, &Examinable, &Transform)>,
+    mut world_cells: ResMut<GridmapMain>,
+    physics_cells: Query<&Cell>,
+    mut net_message_event: EventWriter<NetHealth>,
+    handle_to_entity: Res<HandleToEntity>,
+    mut sfx_auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
+    mut projectile_fov: EventWriter<ProjectileFOV>,
+    sensers: Query<(Entity, &Senser)>,
+    gridmap_data: Res<GridmapData>,
+    colliders: Query<&Parent, With<Collider>>,
+) {
+    let collider_groups = get_bit_masks(ColliderGroup::Standard);
+    let interaction_groups = InteractionGroups::new(collider_groups.0, collider_groups.1);
+
+    for (projectile_entity, mut projectile) in projectiles.iter_mut() {
+        let mut step_left =
+            (projectile.velocity.length() * time.delta_seconds()).min(projectile.distance_left);
+        let mut stopped = false;
+
+        // Ricochets and penetrations continue the step from the point of impact.
+        while step_left > 0. && !stopped {
+            let start_position = projectile.position;
+            let direction = projectile.velocity.normalize();
+
+            // Clients get the straight stretch up to the next impact once and play it out
+            // themselves, instead of a new segment every tick.
+            if !projectile.path_sent {
+                let path_length = match cast_projectile_ray(
+                    &rapier_context,
+                    &colliders,
+                    interaction_groups,
+                    &projectile,
+                    projectile.distance_left,
+                ) {
+                    Some((_, intersection)) => intersection.toi,
+                    None => projectile.distance_left,
+                };
+
+                projectile_fov.send(ProjectileFOV {
+                    laser_projectile: NetProjectileType::Ballistic(
+                        projectile.velocity.length(),
+                        start_position,
+                        start_position + direction * path_length,
+                    ),
+                });
+                projectile.path_sent = true;
+            }
+
+            let hit_option = cast_projectile_ray(
+                &rapier_context,
+                &colliders,
+                interaction_groups,
+                &projectile,
+                step_left,
+            );
+
+            let (child_entity, intersection) = match hit_option {
+                Some(hit) => hit,
+                None => {
+                    projectile.position = start_position + direction * step_left;
+                    projectile.distance_left -= step_left;
+                    step_left = 0.;
+                    continue;
+                }
+            };
+
+            let hit_point = start_position + direction * intersection.toi;
+            let normal: Vec3 = intersection.normal.into();
+
+            projectile.position = hit_point;
+            projectile.distance_left -= intersection.toi;
+            step_left -= intersection.toi;
+
+            // Whatever happens at the impact, the path continues from here with a new stretch.
+            projectile.path_sent = false;
+
+            let collider_entity = get_collider_entity(&colliders, child_entity);
+
+            projectile.ignored_entities.push(collider_entity);
+
+            let sound_transform = Transform::from_translation(hit_point);
+
+            let mut hit_result = HitResult::Blocked;
+            let mut resistance = ARMOUR_PLATED_RESISTANCE;
+            let impact;
+
+            match rigidbody_query.get_mut(collider_entity) {
+                Ok((mut health_component, examinable_component, rigid_body_position_component)) => {
+                    let is_armour_plated = health_component
+                        .health_flags
+                        .values()
+                        .any(|health_flag| health_flag == &HealthFlag::ArmourPlated);
+
+                    resistance = get_penetration_resistance(&health_component.health_flags);
+                    impact = get_impact(&projectile, normal, resistance, is_armour_plated);
+
+                    let damage_model = match impact {
+                        Impact::Ricochet => projectile.get_damage_model(RICOCHET_DAMAGE_FACTOR),
+                        _ => projectile.get_damage_model(1.),
+                    };
+
+                    let attacked_cell_id =
+                        world_to_cell_id(rigid_body_position_component.translation.into());
+
+                    hit_result = health_component.apply_damage(
+                        &projectile.targetted_limb,
+                        &damage_model,
+                        &mut net_message_event,
+                        &handle_to_entity,
+                        &projectile.attacker_cell_id,
+                        &attacked_cell_id,
+                        &sensers,
+                        &projectile.attacker_name,
+                        &examinable_component.name.get_a_name(),
+                        &DamageType::Projectile,
+                        &projectile.weapon_name,
+                        &projectile.weapon_a_name,
+                        &projectile.offense_words,
+                        &projectile.trigger_words,
+                    );
+                }
+                Err(_rr) => match physics_cells.get(collider_entity) {
+                    Ok(cell_component) => match world_cells.grid_data.get_mut(&cell_component.id) {
+                        Some(cell_data) => {
+                            if gridmap_data
+                                .non_combat_obstacle_cells_list
+                                .contains(&cell_data.item)
+                            {
+                                continue;
+                            }
+
+                            resistance = get_penetration_resistance(&cell_data.health.health_flags);
+                            impact = get_impact(&projectile, normal, resistance, true);
+
+                            let damage_model = match impact {
+                                Impact::Ricochet => {
+                                    projectile.get_damage_model(RICOCHET_DAMAGE_FACTOR)
+                                }
+                                _ => projectile.get_damage_model(1.),
+                            };
+
+                            hit_result = cell_data.health.apply_damage(
+                                &projectile.targetted_limb,
+                                &damage_model,
+                                &mut net_message_event,
+                                &handle_to_entity,
+                                &projectile.attacker_cell_id,
+                                &cell_component.id,
+                                &sensers,
+                                &projectile.attacker_name,
+                                &get_cell_name(cell_data, &gridmap_data),
+                                &DamageType::Projectile,
+                                &projectile.weapon_name,
+                                &projectile.weapon_a_name,
+                                &projectile.offense_words,
+                                &projectile.trigger_words,
+                            );
+                        }
+                        None => {
+                            impact = Impact::Stop;
+                        }
+                    },
+                    Err(_rr) => {
+                        // Anything without health simply stops the projectile.
+                        impact = Impact::Stop;
+                    }
+                },
+            }
+
+            match hit_result {
+                HitResult::HitSoft => {
+                    projectile.combat_sound_set.spawn_hit_sfx(
+                        &mut commands,
+                        sound_transform,
+                        &mut sfx_auto_destroy_timers,
+                    );
+                }
+                HitResult::Blocked => {
+                    projectile.combat_sound_set.spawn_hit_blocked(
+                        &mut commands,
+                        sound_transform,
+                        &mut sfx_auto_destroy_timers,
+                    );
+                }
+                HitResult::Missed => {}
+            }
+
+            match impact {
+                Impact::Ricochet => {
+                    let velocity = projectile.velocity;
+                    let mut reflected = velocity - 2. * velocity.dot(normal) * normal;
+                    // Projectiles stay in the horizontal plane the field of view works in.
+                    reflected.y = 0.;
+                    projectile.velocity =
+                        reflected.normalize_or_zero() * velocity.length() * RICOCHET_SPEED_RETAINED;
+                    projectile.ricochets_left -= 1;
+                }
+                Impact::Penetrate => {
+                    projectile.penetration -= resistance;
+                    projectile.velocity *= PENETRATION_SPEED_RETAINED;
+                }
+                Impact::Stop => {
+                    stopped = true;
+                }
+            }
+
+            // Less speed means less distance covered in the rest of this step.
+            step_left = step_left.min(projectile.velocity.length() * time.delta_seconds());
+        }
+
+        if stopped
+            || projectile.distance_left <= 0.
+            || projectile.velocity.length() < MIN_PROJECTILE_SPEED
+        {
+            commands.entity(projectile_entity).despawn();
+        }
+    }
+}
+
+// Projectiles are not part of the world the rounds plugin despawns, they go here.
+pub fn reset_ballistic_projectiles(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut commands: Commands,
+    projectiles: Query<Entity, With<BallisticProjectile>>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    for projectile_entity in projectiles.iter() {
+        commands.entity(projectile_entity).despawn();
+    }
+}
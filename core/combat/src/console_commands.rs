@@ -0,0 +1,114 @@
+use bevy::prelude::{EventReader, EventWriter, Query, ResMut};
+use console_commands::commands::{AllConsoleCommands, NetConsoleCommands};
+use networking::messages::InputConsoleCommand;
+use api::{
+    console_commands::{
+        ConsoleCommandVariant, ConsoleCommandVariantValues, CONSOLE_ERROR_COLOR,
+        CONSOLE_SUCCESS_COLOR,
+    },
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+};
+
+use crate::lag_compensation::LagCompensation;
+
+pub fn initialize_console_commands(mut commands: ResMut<AllConsoleCommands>) {
+    commands.list.push((
+        "lagCompensation".to_string(),
+        "Set the maximum milliseconds attacks get rewound and whether rewound hitboxes get shown."
+            .to_string(),
+        vec![
+            ("maxRewind".to_string(), ConsoleCommandVariant::Int),
+            ("debug".to_string(), ConsoleCommandVariant::Bool),
+        ],
+    ));
+}
+
+pub fn combat_console_commands(
+    mut queue: EventReader<InputConsoleCommand>,
+    connected_players: Query<&ConnectedPlayer>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+    mut lag_compensation: ResMut<LagCompensation>,
+) {
+    for console_command_event in queue.iter() {
+        if console_command_event.command_name != "lagCompensation" {
+            continue;
+        }
+
+        let handle;
+        match console_command_event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        match connected_players.get(console_command_event.entity) {
+            Ok(connected_player) => {
+                if !connected_player.rcon {
+                    net_console_commands.send(NetConsoleCommands {
+                        handle,
+                        message: ReliableServerMessage::ConsoleWriteLine(
+                            "[color=".to_string()
+                                + CONSOLE_ERROR_COLOR
+                                + "]RCON status denied.[/color]",
+                        ),
+                    });
+                    continue;
+                }
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let (max_rewind, debug) = match (
+            console_command_event.command_arguments.get(0),
+            console_command_event.command_arguments.get(1),
+        ) {
+            (
+                Some(ConsoleCommandVariantValues::Int(max_rewind)),
+                Some(ConsoleCommandVariantValues::Bool(debug)),
+            ) => (*max_rewind, *debug),
+            _ => {
+                net_console_commands.send(NetConsoleCommands {
+                    handle,
+                    message: ReliableServerMessage::ConsoleWriteLine(
+                        "[color=".to_string()
+                            + CONSOLE_ERROR_COLOR
+                            + "]Usage: lagCompensation <maxRewind> <debug>[/color]",
+                    ),
+                });
+                continue;
+            }
+        };
+
+        if max_rewind < 0 {
+            net_console_commands.send(NetConsoleCommands {
+                handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string()
+                        + CONSOLE_ERROR_COLOR
+                        + "]The maximum rewind cannot be negative.[/color]",
+                ),
+            });
+            continue;
+        }
+
+        lag_compensation.max_rewind = max_rewind as f32 / 1000.;
+        lag_compensation.debug = debug;
+
+        net_console_commands.send(NetConsoleCommands {
+            handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string()
+                    + CONSOLE_SUCCESS_COLOR
+                    + "]Attacks get rewound up to "
+                    + &max_rewind.to_string()
+                    + "ms.[/color]",
+            ),
+        });
+    }
+}
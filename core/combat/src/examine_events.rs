@@ -0,0 +1,58 @@
+use bevy::prelude::{Entity, Query, ResMut};
+use networking::messages::ExamineEntityMessages;
+use api::{
+    chat::FURTHER_ITALIC_FONT,
+    combat::{Ammunition, AmmunitionType, Magazine},
+    senser::Senser,
+};
+
+pub fn examine_ammunition(
+    mut examine_entity_events: ResMut<ExamineEntityMessages>,
+    criteria_query: Query<&Senser>,
+    weapons: Query<&Ammunition>,
+    magazines: Query<&Magazine>,
+) {
+    for examine_event in examine_entity_events.messages.iter_mut() {
+        match criteria_query.get(examine_event.entity) {
+            Ok(_) => {}
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let examined_entity = Entity::from_bits(examine_event.examine_entity_bits);
+
+        let examine_text;
+
+        match weapons.get(examined_entity) {
+            Ok(ammunition_component) => {
+                let loaded = &ammunition_component.loaded;
+                if loaded.rounds == 0 {
+                    examine_text = match loaded.ammunition_type {
+                        AmmunitionType::Rounds => "\nIt is not loaded.",
+                        AmmunitionType::Charge => "\nIts power cell is drained.",
+                    }
+                    .to_string();
+                } else {
+                    examine_text = "\nIt has ".to_string() + &loaded.get_description() + " left.";
+                }
+            }
+            Err(_rr) => match magazines.get(examined_entity) {
+                Ok(magazine_component) => {
+                    examine_text =
+                        "\nIt holds ".to_string() + &magazine_component.get_description() + ".";
+                }
+                Err(_rr) => {
+                    continue;
+                }
+            },
+        }
+
+        examine_event.message = examine_event.message.clone()
+            + "[font="
+            + FURTHER_ITALIC_FONT
+            + "]"
+            + &examine_text
+            + "[/font]\n";
+    }
+}
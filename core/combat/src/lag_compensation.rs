@@ -0,0 +1,206 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::{
+    core::Time,
+    hierarchy::Parent,
+    math::{Quat, Vec3},
+    prelude::{
+        Entity, EventWriter, Query, RemovedComponents, Res, ResMut, Transform, With, Without,
+    },
+};
+use bevy_rapier3d::{
+    pipeline::QueryFilter,
+    plugin::RapierContext,
+    prelude::{Collider, InteractionGroups, RigidBody},
+};
+use physics::physics::RigidBodyDisabled;
+use api::{combat::DebugHitbox, connected_player::NetworkStats, data::ConnectedPlayer, health::Health};
+
+pub struct LagCompensation {
+    // Seconds, attacks never get rewound further back than this.
+    pub max_rewind: f32,
+    // Seconds clients display other entities behind the latest transform they received.
+    pub interpolation_delay: f32,
+    // Show rewound hitboxes with line arrows.
+    pub debug: bool,
+    // Round trip times in seconds per client handle.
+    pub client_rtt: HashMap<u64, f32>,
+    pub debug_hitboxes: Vec<Vec3>,
+    // Only translations get recorded, hitboxes of pawns are upright capsules.
+    history: HashMap<Entity, VecDeque<(f64, Vec3)>>,
+    time: f64,
+}
+
+impl Default for LagCompensation {
+    fn default() -> Self {
+        Self {
+            max_rewind: 0.25,
+            interpolation_delay: 2. / 24.,
+            debug: false,
+            client_rtt: HashMap::new(),
+            debug_hitboxes: vec![],
+            history: HashMap::new(),
+            time: 0.,
+        }
+    }
+}
+
+impl LagCompensation {
+    // The time the world looked like this on the screen of the client.
+    pub fn get_view_time(&self, handle_option: Option<u64>) -> f64 {
+        let rewind = match handle_option {
+            Some(handle) => match self.client_rtt.get(&handle) {
+                Some(rtt) => (rtt * 0.5 + self.interpolation_delay).min(self.max_rewind),
+                None => 0.,
+            },
+            None => 0.,
+        };
+
+        self.time - rewind.max(0.) as f64
+    }
+
+    // Entities that moved since view_time with their translation at that time.
+    pub fn get_rewound_translations(&self, view_time: f64) -> Vec<(Entity, Vec3)> {
+        let mut rewound = vec![];
+
+        if view_time >= self.time {
+            return rewound;
+        }
+
+        for (entity, snapshots) in self.history.iter() {
+            match snapshots.back() {
+                Some((newest_time, _)) => {
+                    if *newest_time <= view_time {
+                        continue;
+                    }
+                }
+                None => {
+                    continue;
+                }
+            }
+
+            let mut translation = snapshots.front().unwrap().1;
+
+            for (i, (time, snapshot_translation)) in snapshots.iter().enumerate() {
+                if *time <= view_time {
+                    translation = *snapshot_translation;
+                    continue;
+                }
+                if i > 0 {
+                    let (previous_time, previous_translation) = snapshots[i - 1];
+                    let t = (view_time - previous_time) / (time - previous_time);
+                    translation = previous_translation.lerp(*snapshot_translation, t as f32);
+                }
+                break;
+            }
+
+            rewound.push((*entity, translation));
+        }
+
+        rewound
+    }
+
+    pub fn add_debug_hitbox(&mut self, translation: Vec3) {
+        if self.debug {
+            self.debug_hitboxes.push(translation);
+        }
+    }
+}
+
+pub fn record_transform_history(
+    time: Res<Time>,
+    hit_testable: Query<
+        (Entity, &Transform),
+        (With<Health>, With<RigidBody>, Without<RigidBodyDisabled>),
+    >,
+    removed_health: RemovedComponents<Health>,
+    mut lag_compensation: ResMut<LagCompensation>,
+) {
+    let now = time.seconds_since_startup();
+    // Keep a little more than the max rewind so there is something to interpolate from.
+    let oldest = now - lag_compensation.max_rewind as f64 - 0.1;
+
+    lag_compensation.time = now;
+
+    for entity in removed_health.iter() {
+        lag_compensation.history.remove(&entity);
+    }
+
+    for (entity, transform) in hit_testable.iter() {
+        let snapshots = lag_compensation.history.entry(entity).or_default();
+
+        // Unchanged translations are not recorded, the newest snapshot stays valid until it moves.
+        match snapshots.back() {
+            Some((_, translation)) => {
+                if *translation != transform.translation {
+                    snapshots.push_back((now, transform.translation));
+                }
+            }
+            None => {
+                snapshots.push_back((now, transform.translation));
+            }
+        }
+
+        while snapshots.len() > 1 && snapshots[1].0 < oldest {
+            snapshots.pop_front();
+        }
+    }
+}
+
+// Attack is out of system params, the hitboxes it collected get sent on from here.
+pub fn send_debug_hitboxes(
+    mut lag_compensation: ResMut<LagCompensation>,
+    mut debug_hitboxes: EventWriter<DebugHitbox>,
+) {
+    for translation in lag_compensation.debug_hitboxes.drain(..) {
+        debug_hitboxes.send(DebugHitbox { translation });
+    }
+}
+
+pub fn update_client_rtt(
+    connected_players: Query<(&ConnectedPlayer, &NetworkStats)>,
+    mut lag_compensation: ResMut<LagCompensation>,
+) {
+    lag_compensation.client_rtt.clear();
+
+    for (connected_player_component, network_stats) in connected_players.iter() {
+        if !connected_player_component.connected {
+            continue;
+        }
+        lag_compensation
+            .client_rtt
+            .insert(connected_player_component.handle, network_stats.rtt / 1000.);
+    }
+}
+
+// Moving the attack shape by the offset between the current and rewound translation is the same
+// as testing the shape against the collider at its rewound translation.
+pub fn intersects_rewound(
+    rapier_context: &RapierContext,
+    colliders: &Query<&Parent, With<Collider>>,
+    rewound_entity: Entity,
+    shape_position: Vec3,
+    shape_rotation: Quat,
+    shape: &Collider,
+    interaction_groups: InteractionGroups,
+) -> bool {
+    let mut intersects = false;
+
+    rapier_context.intersections_with_shape(
+        shape_position,
+        shape_rotation.into(),
+        shape,
+        QueryFilter::new()
+            .groups(interaction_groups)
+            .predicate(&|child_entity| match colliders.get(child_entity) {
+                Ok(parent_entity) => parent_entity.0 == rewound_entity,
+                Err(_rr) => child_entity == rewound_entity,
+            }),
+        |_child_entity| {
+            intersects = true;
+            false
+        },
+    );
+
+    intersects
+}
@@ -1,2 +1,7 @@
+pub mod ammunition;
 pub mod attack;
+pub mod ballistic;
+pub mod console_commands;
+pub mod examine_events;
+pub mod lag_compensation;
 pub mod plugin;
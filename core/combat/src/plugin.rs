@@ -1,16 +1,70 @@
-use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin};
-use api::{combat::ProjectileFOV, data::UpdateLabels};
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, SystemLabel, SystemSet};
+use networking::messages::net_system;
+use api::{
+    combat::{DebugHitbox, ProjectileFOV},
+    console_commands::ConsoleCommandsLabels,
+    data::{PostUpdateLabels, StartupLabels, UpdateLabels},
+    examinable::ExamineLabels,
+    rounds::RoundsLabels,
+    tab_actions::TabActionsQueueLabels,
+};
 
-use crate::attack::Attack;
+use crate::{
+    ammunition::reload,
+    attack::{Attack, NetAttack},
+    ballistic::{ballistic_projectiles, reset_ballistic_projectiles},
+    console_commands::{combat_console_commands, initialize_console_commands},
+    examine_events::examine_ammunition,
+    lag_compensation::{
+        record_transform_history, send_debug_hitboxes, update_client_rtt, LagCompensation,
+    },
+};
 
 use super::attack::attack;
+use bevy::app::CoreStage::PostUpdate;
 
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(attack.after(UpdateLabels::StandardCharacters))
+        app.init_resource::<LagCompensation>()
+            .add_startup_system(
+                initialize_console_commands
+                    .before(ConsoleCommandsLabels::Finalize)
+                    .label(StartupLabels::ConsoleCommands),
+            )
+            .add_system(combat_console_commands)
+            .add_system(update_client_rtt.before(UpdateLabels::StandardCharacters))
+            .add_system(
+                attack
+                    .label(CombatLabels::Attack)
+                    .after(UpdateLabels::StandardCharacters),
+            )
+            .add_system(send_debug_hitboxes.after(CombatLabels::Attack))
+            .add_system(ballistic_projectiles.after(UpdateLabels::StandardCharacters))
+            .add_system(
+                reset_ballistic_projectiles
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
+            .add_system(reload.after(TabActionsQueueLabels::TabAction))
+            .add_system(examine_ammunition.after(ExamineLabels::Default))
             .add_event::<Attack>()
-            .add_event::<ProjectileFOV>();
+            .add_event::<ProjectileFOV>()
+            .add_event::<DebugHitbox>()
+            .add_event::<NetAttack>()
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetAttack>),
+            )
+            .add_system_to_stage(PostUpdate, record_transform_history);
     }
 }
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum CombatLabels {
+    Attack,
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use bevy::math::{Quat, Vec3};
+use bincode::serialize;
+use connected_player::entity_update_batch::{
+    encode_entity_update_batch, intern_entity_updates, EntityUpdateIds,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use api::{
+    entity_updates::EntityUpdateData,
+    network::{EntityWorldType, ReliableServerMessage},
+};
+
+const ENTITIES: u64 = 50;
+
+// What a humanoid typically sends when it moves and changes its held item.
+fn humanoid_updates(i: u64) -> HashMap<String, HashMap<String, EntityUpdateData>> {
+    let mut updates = HashMap::new();
+
+    let mut animation = HashMap::new();
+    animation.insert(
+        "animation_state".to_string(),
+        EntityUpdateData::String("Jogging".to_string()),
+    );
+    animation.insert("blend_speed".to_string(), EntityUpdateData::Float(0.5));
+    updates.insert(
+        "Smoothing/pawn/humanMale/rig/animationTree".to_string(),
+        animation,
+    );
+
+    let mut hand = HashMap::new();
+    hand.insert(
+        "attachedItem".to_string(),
+        EntityUpdateData::AttachedItem(
+            1000 + i,
+            Vec3::new(0.1, 0.2, 0.3),
+            Quat::IDENTITY,
+            Vec3::ONE,
+        ),
+    );
+    updates.insert(
+        "Smoothing/pawn/humanMale/rig/rightHand/rightHandAttachment".to_string(),
+        hand,
+    );
+
+    updates
+}
+
+fn entity_updates() -> Vec<(u64, HashMap<String, HashMap<String, EntityUpdateData>>)> {
+    (0..ENTITIES).map(|i| (i, humanoid_updates(i))).collect()
+}
+
+// One message per entity, with full strings.
+fn old_path_bytes(
+    updates: &Vec<(u64, HashMap<String, HashMap<String, EntityUpdateData>>)>,
+) -> usize {
+    updates
+        .iter()
+        .map(|(entity_bits, entity_updates)| {
+            serialize(&ReliableServerMessage::EntityUpdate(
+                *entity_bits,
+                entity_updates.clone(),
+                false,
+                EntityWorldType::Main,
+            ))
+            .unwrap()
+            .len()
+        })
+        .sum()
+}
+
+fn new_path_bytes(
+    updates: &Vec<(u64, HashMap<String, HashMap<String, EntityUpdateData>>)>,
+    compression: bool,
+) -> usize {
+    let mut ids = EntityUpdateIds::default();
+    // The ids were already sent in an earlier tick.
+    intern_entity_updates(&mut ids, updates.clone());
+    let (batch, _) = intern_entity_updates(&mut ids, updates.clone());
+    serialize(&encode_entity_update_batch(batch, compression, 256))
+        .unwrap()
+        .len()
+}
+
+fn bench_entity_update_bandwidth(c: &mut Criterion) {
+    let updates = entity_updates();
+
+    println!(
+        "{} entity updates for one client per tick: old {} bytes, batched {} bytes, batched and compressed {} bytes.",
+        ENTITIES,
+        old_path_bytes(&updates),
+        new_path_bytes(&updates, false),
+        new_path_bytes(&updates, true),
+    );
+
+    c.bench_function("entity updates old path", |b| {
+        b.iter(|| black_box(old_path_bytes(&updates)))
+    });
+    c.bench_function("entity updates batched", |b| {
+        b.iter(|| black_box(new_path_bytes(&updates, false)))
+    });
+    c.bench_function("entity updates batched and compressed", |b| {
+        b.iter(|| black_box(new_path_bytes(&updates, true)))
+    });
+}
+
+criterion_group!(benches, bench_entity_update_bandwidth);
+criterion_main!(benches);
@@ -0,0 +1,465 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::{warn, EventReader, EventWriter, Query, ResMut};
+use console_commands::commands::{player_selector_to_entities, NetEntityConsole};
+use pawn::pawn::{Pawn, PersistentPlayerData, UsedNames};
+use api::{
+    chat::{
+        escape_bb, ADMIN_CLOSE_CHATPREFIX, ADMIN_HELP_CHATPREFIX, ADMIN_HELP_COLOR,
+        ADMIN_REPLY_CHATPREFIX, EMOTE_CHATPREFIX, PRIVATE_MESSAGE_CHATPREFIX,
+        PRIVATE_MESSAGE_COLOR, STAFF_CHATPREFIX, STAFF_COLOR, WARNING_COLOR,
+    },
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+};
+
+use crate::{
+    chat::NetChatMessage,
+    chat_filter::{FilteredChatMessage, LogChatMessage},
+};
+
+pub struct AdminHelpTicket {
+    pub id: u32,
+    pub handle: u64,
+    pub user_name: String,
+}
+
+// Open tickets only, closed tickets are left to tickets.log.
+#[derive(Default)]
+pub struct AdminHelpTickets {
+    pub list: Vec<AdminHelpTicket>,
+    pub last_id: u32,
+}
+
+impl AdminHelpTickets {
+    pub fn get_open(&self, handle: u64) -> Option<u32> {
+        self.list
+            .iter()
+            .find(|ticket| ticket.handle == handle)
+            .map(|ticket| ticket.id)
+    }
+    pub fn get(&self, id: u32) -> Option<&AdminHelpTicket> {
+        self.list.iter().find(|ticket| ticket.id == id)
+    }
+    pub fn close(&mut self, id: u32) {
+        self.list.retain(|ticket| ticket.id != id);
+    }
+    // Nobody is left to reply to once the player disconnected.
+    pub fn close_for_handle(&mut self, handle: u64) {
+        for ticket in self.list.iter() {
+            if ticket.handle == handle {
+                log_ticket(&format!(
+                    "#{} closed, {} disconnected",
+                    ticket.id, ticket.user_name
+                ));
+            }
+        }
+        self.list.retain(|ticket| ticket.handle != handle);
+    }
+}
+
+const CHAT_COMMANDS: [&str; 6] = [
+    PRIVATE_MESSAGE_CHATPREFIX,
+    ADMIN_HELP_CHATPREFIX,
+    ADMIN_REPLY_CHATPREFIX,
+    ADMIN_CLOSE_CHATPREFIX,
+    STAFF_CHATPREFIX,
+    EMOTE_CHATPREFIX,
+];
+
+// Chat commands are not said out loud, chat_message_input_event skips them.
+pub fn is_chat_command(message: &str) -> bool {
+    let first_word = message.split_whitespace().next().unwrap_or("");
+    CHAT_COMMANDS.contains(&first_word)
+}
+
+// Splits off the first word, or the first quoted part so names with spaces can be selected.
+pub fn split_first_argument(text: &str) -> (String, String) {
+    let text = text.trim_start();
+
+    for quote in ['"', '\''] {
+        if text.starts_with(quote) {
+            match text[1..].find(quote) {
+                Some(end) => {
+                    return (
+                        text[..end + 2].to_string(),
+                        text[end + 2..].trim().to_string(),
+                    );
+                }
+                None => {}
+            }
+        }
+    }
+
+    match text.split_once(char::is_whitespace) {
+        Some((first, rest)) => (first.to_string(), rest.trim().to_string()),
+        None => (text.to_string(), "".to_string()),
+    }
+}
+
+fn log_ticket(line: &str) {
+    let logs_path = Path::new("logs");
+    match fs::create_dir_all(logs_path) {
+        Ok(_) => {}
+        Err(rr) => {
+            warn!("Couldn't create logs directory: {}", rr);
+            return;
+        }
+    }
+
+    let timestamp = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs(),
+        Err(_rr) => 0,
+    };
+
+    match OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(logs_path.join("tickets.log"))
+    {
+        Ok(mut file) => match writeln!(file, "[{}] {}", timestamp, line) {
+            Ok(_) => {}
+            Err(rr) => {
+                warn!("Couldn't write to tickets.log: {}", rr);
+            }
+        },
+        Err(rr) => {
+            warn!("Couldn't open tickets.log: {}", rr);
+        }
+    }
+}
+
+pub fn send_chat(net_chat_message: &mut EventWriter<NetChatMessage>, handle: u64, message: String) {
+    net_chat_message.send(NetChatMessage {
+        handle,
+        message: ReliableServerMessage::ChatMessage(message),
+    });
+}
+
+fn send_to_staff(
+    net_chat_message: &mut EventWriter<NetChatMessage>,
+    connected_players: &Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    message: String,
+) -> Vec<u64> {
+    let mut staff_handles = vec![];
+    for (connected_player, _persistent_player_data) in connected_players.iter() {
+        if !connected_player.connected || !connected_player.rcon {
+            continue;
+        }
+        send_chat(net_chat_message, connected_player.handle, message.clone());
+        staff_handles.push(connected_player.handle);
+    }
+    staff_handles
+}
+
+pub fn colored(color: &str, text: &str) -> String {
+    "[color=".to_string() + color + "]" + text + "[/color]"
+}
+
+pub fn chat_commands(
+    mut chat_message_input_events: EventReader<FilteredChatMessage>,
+    connected_players: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    pawns: Query<&Pawn>,
+    mut used_names: ResMut<UsedNames>,
+    mut admin_help_tickets: ResMut<AdminHelpTickets>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut net_entity_console: EventWriter<NetEntityConsole>,
+    mut log_chat_message: EventWriter<LogChatMessage>,
+) {
+    for chat_message_input_event in chat_message_input_events.iter() {
+        if !is_chat_command(&chat_message_input_event.message) {
+            continue;
+        }
+
+        let (connected_player, persistent_player_data) =
+            match connected_players.get(chat_message_input_event.entity) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+        let handle = connected_player.handle;
+
+        let mut raw_message = chat_message_input_event.message.trim().to_string();
+        if raw_message.chars().count() > 500 {
+            raw_message = raw_message.chars().take(500).collect();
+        }
+        let (command, arguments) = split_first_argument(&raw_message);
+
+        let sender_name = match pawns.get(chat_message_input_event.entity) {
+            Ok(pawn) => pawn.name.clone() + " (" + &persistent_player_data.user_name + ")",
+            Err(_rr) => persistent_player_data.user_name.clone(),
+        };
+
+        if command == PRIVATE_MESSAGE_CHATPREFIX {
+            let (player_selector, message) = split_first_argument(&arguments);
+            if message.is_empty() {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(WARNING_COLOR, "Usage: /pm <player> <message>"),
+                );
+                continue;
+            }
+            if player_selector == "*" && !connected_player.rcon {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(WARNING_COLOR, "Only admins can message everyone at once."),
+                );
+                continue;
+            }
+            let message = escape_bb(message, false, false);
+
+            for target_entity in player_selector_to_entities(
+                chat_message_input_event.entity,
+                Some(handle),
+                &player_selector,
+                &mut used_names,
+                &mut net_entity_console,
+            ) {
+                let (target_connected_player, target_persistent_player_data) =
+                    match connected_players.get(target_entity) {
+                        Ok(components) => components,
+                        Err(_rr) => {
+                            continue;
+                        }
+                    };
+                if !target_connected_player.connected {
+                    send_chat(
+                        &mut net_chat_message,
+                        handle,
+                        colored(WARNING_COLOR, "That player is not connected."),
+                    );
+                    continue;
+                }
+                let target_name = match pawns.get(target_entity) {
+                    Ok(pawn) => pawn.name.clone(),
+                    Err(_rr) => target_persistent_player_data.user_name.clone(),
+                };
+
+                send_chat(
+                    &mut net_chat_message,
+                    target_connected_player.handle,
+                    colored(
+                        PRIVATE_MESSAGE_COLOR,
+                        &("[b]PM from ".to_string() + &sender_name + ":[/b] " + &message),
+                    ),
+                );
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(
+                        PRIVATE_MESSAGE_COLOR,
+                        &("[b]PM to ".to_string() + &target_name + ":[/b] " + &message),
+                    ),
+                );
+                log_chat_message.send(LogChatMessage {
+                    channel: "PM".to_string(),
+                    sender: persistent_player_data.user_name.clone(),
+                    message: message.clone(),
+                    recipients: vec![target_connected_player.handle],
+                });
+            }
+        } else if command == ADMIN_HELP_CHATPREFIX {
+            if arguments.is_empty() {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(WARNING_COLOR, "Usage: /ahelp <message>"),
+                );
+                continue;
+            }
+            let message = escape_bb(arguments, false, false);
+
+            // Follow-up messages go to the ticket that is still open.
+            let ticket_id = match admin_help_tickets.get_open(handle) {
+                Some(id) => id,
+                None => {
+                    admin_help_tickets.last_id += 1;
+                    let id = admin_help_tickets.last_id;
+                    admin_help_tickets.list.push(AdminHelpTicket {
+                        id,
+                        handle,
+                        user_name: persistent_player_data.user_name.clone(),
+                    });
+                    log_ticket(&format!(
+                        "#{} opened by {}",
+                        id, persistent_player_data.user_name
+                    ));
+                    id
+                }
+            };
+            log_ticket(&format!(
+                "#{} {}: {}",
+                ticket_id, persistent_player_data.user_name, message
+            ));
+
+            let staff_handles = send_to_staff(
+                &mut net_chat_message,
+                &connected_players,
+                colored(
+                    ADMIN_HELP_COLOR,
+                    &format!(
+                        "[b]Ticket #{} from {}:[/b] {}\nReply with /areply {} <message>.",
+                        ticket_id, sender_name, message, ticket_id
+                    ),
+                ),
+            );
+
+            log_chat_message.send(LogChatMessage {
+                channel: format!("Ticket #{}", ticket_id),
+                sender: persistent_player_data.user_name.clone(),
+                message: message.clone(),
+                recipients: staff_handles.clone(),
+            });
+
+            let mut reply = format!("[b]Ticket #{}:[/b] {}", ticket_id, message);
+            if staff_handles.is_empty() {
+                reply = reply + "\nNo admins are online right now, your ticket has been logged.";
+            }
+            if !connected_player.rcon {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(ADMIN_HELP_COLOR, &reply),
+                );
+            }
+        } else if !connected_player.rcon {
+            send_chat(
+                &mut net_chat_message,
+                handle,
+                colored(WARNING_COLOR, "RCON status denied."),
+            );
+        } else if command == STAFF_CHATPREFIX {
+            if arguments.is_empty() {
+                continue;
+            }
+            let message = escape_bb(arguments, false, false);
+            let staff_handles = send_to_staff(
+                &mut net_chat_message,
+                &connected_players,
+                colored(
+                    STAFF_COLOR,
+                    &("[b](Staff) ".to_string()
+                        + &persistent_player_data.user_name
+                        + ":[/b] "
+                        + &message),
+                ),
+            );
+            log_chat_message.send(LogChatMessage {
+                channel: "Staff".to_string(),
+                sender: persistent_player_data.user_name.clone(),
+                message,
+                recipients: staff_handles,
+            });
+        } else if command == ADMIN_REPLY_CHATPREFIX || command == ADMIN_CLOSE_CHATPREFIX {
+            let (ticket_argument, message) = split_first_argument(&arguments);
+
+            let ticket_id_option = ticket_argument.trim_start_matches('#').parse::<u32>().ok();
+
+            let ticket = match ticket_id_option.and_then(|id| admin_help_tickets.get(id)) {
+                Some(ticket) => ticket,
+                None => {
+                    // Tickets are numbered in order, lower ids than the last one were closed.
+                    let warning = match ticket_id_option {
+                        Some(id) => {
+                            if id > 0 && id <= admin_help_tickets.last_id {
+                                "That ticket has already been closed."
+                            } else {
+                                "Unknown ticket."
+                            }
+                        }
+                        None => "Unknown ticket.",
+                    };
+                    send_chat(
+                        &mut net_chat_message,
+                        handle,
+                        colored(WARNING_COLOR, warning),
+                    );
+                    continue;
+                }
+            };
+
+            let ticket_id = ticket.id;
+            let ticket_handle = ticket.handle;
+            let ticket_user_name = ticket.user_name.clone();
+
+            let player_message;
+            let staff_message;
+
+            if command == ADMIN_REPLY_CHATPREFIX {
+                if message.is_empty() {
+                    send_chat(
+                        &mut net_chat_message,
+                        handle,
+                        colored(WARNING_COLOR, "Usage: /areply <ticket> <message>"),
+                    );
+                    continue;
+                }
+                let message = escape_bb(message, false, false);
+                log_ticket(&format!(
+                    "#{} {} (admin): {}",
+                    ticket_id, persistent_player_data.user_name, message
+                ));
+                player_message = format!(
+                    "[b]Admin {}:[/b] {}",
+                    persistent_player_data.user_name, message
+                );
+                staff_message = format!(
+                    "[b]Ticket #{}, {} replied to {}:[/b] {}",
+                    ticket_id, persistent_player_data.user_name, ticket_user_name, message
+                );
+            } else {
+                admin_help_tickets.close(ticket_id);
+                log_ticket(&format!(
+                    "#{} closed by {}",
+                    ticket_id, persistent_player_data.user_name
+                ));
+                player_message = format!(
+                    "Your ticket #{} was closed by {}.",
+                    ticket_id, persistent_player_data.user_name
+                );
+                staff_message = format!(
+                    "Ticket #{} was closed by {}.",
+                    ticket_id, persistent_player_data.user_name
+                );
+            }
+
+            send_to_staff(
+                &mut net_chat_message,
+                &connected_players,
+                colored(ADMIN_HELP_COLOR, &staff_message),
+            );
+
+            let ticket_player_is_staff = connected_players
+                .iter()
+                .any(|(c, _)| c.handle == ticket_handle && c.connected && c.rcon);
+            let ticket_player_connected = connected_players
+                .iter()
+                .any(|(c, _)| c.handle == ticket_handle && c.connected);
+
+            if !ticket_player_connected {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(
+                        WARNING_COLOR,
+                        "The ticket's player is not connected, the reply was only logged.",
+                    ),
+                );
+            } else if !ticket_player_is_staff {
+                send_chat(
+                    &mut net_chat_message,
+                    ticket_handle,
+                    colored(ADMIN_HELP_COLOR, &player_message),
+                );
+            }
+        }
+    }
+}
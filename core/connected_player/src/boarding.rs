@@ -6,38 +6,112 @@ use crate::{
 use super::{
     connection::Boarding,
     net::{NetDoneBoarding, NetOnBoarding},
-    setup_ui::INPUT_NAME_PATH,
+    setup_ui::{INPUT_JOB_PATH, INPUT_NAME_PATH},
 };
 
+#[derive(Clone)]
 pub struct BoardingPlayer {
     pub player_handle: u64,
     pub player_character_name: String,
+    pub job_preference: Option<String>,
     pub entity: Entity,
 }
+// Sent once a boarding player got assigned a job, ASANA announces these.
+pub struct NewArrival {
+    pub entity: Entity,
+    pub character_name: String,
+    pub job_title: String,
+}
 // Logic works witha timer, better as resource.
 #[derive(Default)]
 pub struct BoardingAnnouncements {
     pub announcements: Vec<(String, Timer)>,
 }
+// Players that finished boarding before the round started, they board once it does.
+#[derive(Default)]
+pub struct BoardingQueue {
+    pub players: Vec<BoardingPlayer>,
+}
 pub fn done_boarding(
     mut spawn_points: ResMut<SpawnPoints>,
     mut net_done_boarding: EventWriter<NetDoneBoarding>,
     mut boarding_player_event: EventReader<BoardingPlayer>,
     mut commands: Commands,
-
-    mut asana_boarding_announcements: ResMut<BoardingAnnouncements>,
+    jobs: Res<Jobs>,
+    mut job_slots: ResMut<JobSlots>,
+    mut new_arrival: EventWriter<NewArrival>,
+    round_status: Res<RoundStatus>,
+    mut boarding_queue: ResMut<BoardingQueue>,
+    handle_to_entity: Res<HandleToEntity>,
 ) {
     for boarding_player in boarding_player_event.iter() {
+        boarding_queue
+            .players
+            .retain(|queued| queued.entity != boarding_player.entity);
+        boarding_queue.players.push(boarding_player.clone());
+
+        if !round_status.boarding_allowed() {
+            net_done_boarding.send(NetDoneBoarding {
+                handle: boarding_player.player_handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string()
+                        + CONSOLE_SUCCESS_COLOR
+                        + "]You will board the spaceship once the round starts. Use \"ready\" to vote for an early start.[/color]",
+                ),
+            });
+        }
+    }
+
+    if !round_status.boarding_allowed() || boarding_queue.players.is_empty() {
+        return;
+    }
+
+    let mut boarding_players = HashMap::new();
+    let mut job_requests = vec![];
+
+    for boarding_player in boarding_queue.players.drain(..) {
+        // Players that left while waiting don't board.
+        match handle_to_entity.map.get(&boarding_player.player_handle) {
+            Some(entity) => {
+                if *entity != boarding_player.entity {
+                    continue;
+                }
+            }
+            None => {
+                continue;
+            }
+        }
+
+        job_requests.push(JobRequest {
+            entity: boarding_player.entity,
+            handle: boarding_player.player_handle,
+            preference: boarding_player.job_preference.clone(),
+        });
+        boarding_players.insert(boarding_player.entity, boarding_player);
+    }
+
+    // Everyone queued up before the round started draws for limited job slots at once.
+    for (entity_id, job) in assign_jobs(job_requests, &jobs, &mut job_slots) {
+        let boarding_player = boarding_players.get(&entity_id).unwrap();
+
         let player_character_name = boarding_player.player_character_name.clone();
         let player_handle = boarding_player.player_handle;
-        let entity_id = boarding_player.entity;
 
         info!(
-            "{} [{}] has boarded the spaceship.",
-            player_character_name, player_handle
+            "{} [{}] has boarded the spaceship as {}.",
+            player_character_name, player_handle, job.title
         );
 
-        let assigned_spawn_transform = spawn_points.list[spawn_points.i].transform;
+        let assigned_spawn_transform;
+        match spawn_points.get_next(&job.spawn_point_type) {
+            Some(transform) => {
+                assigned_spawn_transform = transform;
+            }
+            None => {
+                warn!("The map has no spawn points, spawning at the origin.");
+                assigned_spawn_transform = Transform::identity();
+            }
+        }
 
         commands
             .entity(entity_id)
@@ -46,15 +120,10 @@ pub fn done_boarding(
                 Spawning {
                     transform: assigned_spawn_transform,
                 },
+                AssignedJob { job: job.clone() },
             ))
             .remove_bundle::<(SetupPhase, SoftPlayer)>();
 
-        spawn_points.i += 1;
-
-        if spawn_points.i >= spawn_points.list.len() {
-            spawn_points.i = 0;
-        }
-
         // Queue net_code message for client so he goes back to the main scene and ditches setupUI.
         net_done_boarding.send(NetDoneBoarding {
             handle: player_handle,
@@ -64,7 +133,8 @@ pub fn done_boarding(
             )),
         });
 
-        let talk_spaces = get_talk_spaces();
+        // Radio talk spaces follow once the pawn's headset is registered.
+        let talk_spaces = get_talk_spaces(vec![]);
 
         net_done_boarding.send(NetDoneBoarding {
             handle: player_handle,
@@ -73,32 +143,37 @@ pub fn done_boarding(
             )),
         });
 
-        asana_boarding_announcements.announcements.push((
-            ";Security Officer ".to_owned() + &player_character_name + " is now on board.",
-            Timer::from_seconds(2., false),
-        ));
+        new_arrival.send(NewArrival {
+            entity: entity_id,
+            character_name: player_character_name,
+            job_title: job.title.clone(),
+        });
     }
 }
 
+use std::collections::HashMap;
+
 use bevy::{
     core::Timer,
-    prelude::{info, warn, Added, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut},
+    prelude::{
+        info, warn, Added, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut,
+        Transform,
+    },
 };
-use entity::{
-    entity_data::{CONSTRUCTION_TOOL_ENTITY_NAME, HELMET_SECURITY_ENTITY_NAME},
-    spawn::{SpawnData, SpawnEvent},
+use entity::spawn::{SpawnData, SpawnEvent};
+use jobs::{
+    assign::{assign_jobs, JobRequest},
+    jobs::{AssignedJob, JobSlots, Jobs},
 };
 use pawn::pawn::{PersistentPlayerData, UsedNames};
 use api::{
     chat::{escape_bb, get_talk_spaces},
     connected_player::SoftPlayer,
-    console_commands::CONSOLE_ERROR_COLOR,
-    data::{
-        ConnectedPlayer, HandleToEntity, HUMAN_MALE_ENTITY_NAME, JUMPSUIT_SECURITY_ENTITY_NAME,
-        PISTOL_L1_ENTITY_NAME,
-    },
+    console_commands::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+    data::{ConnectedPlayer, HandleToEntity, HUMAN_MALE_ENTITY_NAME},
     network::{InputUIInputTransmitText, ReliableServerMessage, ServerConfigMessage},
     pawn::{PawnDesignation, SpawnPoints, Spawning},
+    rounds::RoundStatus,
 };
 
 use super::net::NetUIInputTransmitData;
@@ -111,6 +186,7 @@ pub fn ui_input_transmit_data_event(
     mut query: Query<(&mut PersistentPlayerData, &Boarding, &ConnectedPlayer)>,
     mut commands: Commands,
     mut net_ui_input_transmit_data_event: EventWriter<NetUIInputTransmitData>,
+    jobs: Res<Jobs>,
 ) {
     for new_event in event.iter() {
         let player_entity = handle_to_entity.map.get(&new_event.handle).expect(
@@ -133,7 +209,38 @@ pub fn ui_input_transmit_data_event(
         let connected_player_component = player_components.2;
 
         if new_event.ui_type == "setupUI" {
-            if new_event.node_path == INPUT_NAME_PATH {
+            if new_event.node_path == INPUT_JOB_PATH {
+                match jobs.get(&new_event.input_text) {
+                    Some(job) => {
+                        if !job.selectable {
+                            continue;
+                        }
+
+                        persistent_player_data.job_preference = Some(job.id.clone());
+
+                        net_ui_input_transmit_data_event.send(NetUIInputTransmitData {
+                            handle: new_event.handle,
+                            message: ReliableServerMessage::ConsoleWriteLine(
+                                "[color=".to_string()
+                                    + CONSOLE_SUCCESS_COLOR
+                                    + "]Job preference set to "
+                                    + &job.title
+                                    + ".[/color]",
+                            ),
+                        });
+                    }
+                    None => {
+                        net_ui_input_transmit_data_event.send(NetUIInputTransmitData {
+                            handle: new_event.handle,
+                            message: ReliableServerMessage::ConsoleWriteLine(
+                                "[color=".to_string()
+                                    + CONSOLE_ERROR_COLOR
+                                    + "]Unknown job.[/color]",
+                            ),
+                        });
+                    }
+                }
+            } else if new_event.node_path == INPUT_NAME_PATH {
                 // In the future check if we have recieved all requested data sets and THEN remove Boarding component.
 
                 persistent_player_data.character_name =
@@ -185,6 +292,7 @@ pub fn ui_input_transmit_data_event(
                     entity: *player_entity,
                     player_handle: connected_player_component.handle,
                     player_character_name: persistent_player_data.character_name.clone(),
+                    job_preference: persistent_player_data.job_preference.clone(),
                 });
             }
         }
@@ -196,6 +304,14 @@ pub fn on_boarding(
     mut net_on_boarding: EventWriter<NetOnBoarding>,
 ) {
     for connected_player_component in query.iter() {
+        // The job preference has to arrive before the name, the name finishes boarding.
+        net_on_boarding.send(NetOnBoarding {
+            handle: connected_player_component.handle,
+            message: ReliableServerMessage::UIRequestInput(
+                "setupUI".to_string(),
+                INPUT_JOB_PATH.to_string(),
+            ),
+        });
         net_on_boarding.send(NetOnBoarding {
             handle: connected_player_component.handle,
             message: ReliableServerMessage::UIRequestInput(
@@ -210,7 +326,16 @@ use super::net::NetOnSpawning;
 
 pub fn on_spawning(
     mut net_on_new_player_connection: EventWriter<NetOnSpawning>,
-    query: Query<(Entity, &Spawning, &ConnectedPlayer, &PersistentPlayerData), Added<Spawning>>,
+    query: Query<
+        (
+            Entity,
+            &Spawning,
+            &ConnectedPlayer,
+            &PersistentPlayerData,
+            &AssignedJob,
+        ),
+        Added<Spawning>,
+    >,
     mut commands: Commands,
     mut handle_to_entity: ResMut<HandleToEntity>,
     mut used_names: ResMut<UsedNames>,
@@ -221,24 +346,9 @@ pub fn on_spawning(
         spawning_component,
         connected_player_component,
         persistent_player_data_component,
+        assigned_job_component,
     ) in query.iter()
     {
-        let passed_inventory_setup = vec![
-            (
-                "jumpsuit".to_string(),
-                JUMPSUIT_SECURITY_ENTITY_NAME.to_string(),
-            ),
-            (
-                "helmet".to_string(),
-                HELMET_SECURITY_ENTITY_NAME.to_string(),
-            ),
-            ("holster".to_string(), PISTOL_L1_ENTITY_NAME.to_string()),
-            (
-                "left_hand".to_string(),
-                CONSTRUCTION_TOOL_ENTITY_NAME.to_string(),
-            ),
-        ];
-
         let new_entity = commands.spawn().id();
 
         summon_human_male.send(SpawnEvent {
@@ -254,7 +364,7 @@ pub fn on_spawning(
                 spawn_pawn_data: SpawnPawnData {
                     persistent_player_data: persistent_player_data_component.clone(),
                     connected_player_option: Some(connected_player_component.clone()),
-                    inventory_setup: passed_inventory_setup,
+                    job: assigned_job_component.job.clone(),
                     designation: PawnDesignation::Player,
                 },
             },
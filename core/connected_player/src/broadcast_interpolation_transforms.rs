@@ -1,22 +1,25 @@
+use std::{cmp::Ordering, collections::HashMap, time::Instant};
+
 use bevy::{
     core::Time,
-    math::Vec3,
+    math::{Quat, Vec3},
     prelude::{Entity, Local, Query, Res, ResMut, Transform, With, Without},
 };
 use bevy_rapier3d::prelude::{RigidBody, Velocity};
 use bevy_renet::renet::RenetServer;
 use bincode::serialize;
 use entity::entity_data::CachedBroadcastTransform;
+use humanoid::humanoid::Humanoid;
 use networking::plugin::RENET_UNRELIABLE_CHANNEL_ID;
 use physics::physics::RigidBodyDisabled;
 use api::{
-    data::{ConnectedPlayer, HandleToEntity},
-    network::UnreliableServerMessage,
-    sensable::Sensable,
+    connected_player::NetworkStats, data::HandleToEntity, metrics::Metrics,
+    network::UnreliableServerMessage, sensable::Sensable,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum InterpolationPriorityRates {
+    T2,
     T4,
     T8,
     T12,
@@ -30,6 +33,138 @@ pub struct InterpolationFrame {
 
 pub const BROADCAST_INTERPOLATION_TRANSFORM_RATE: f64 = 24.;
 
+// Entities closer than this to a client update faster.
+const NEAR_DISTANCE: f32 = 20.;
+const MID_DISTANCE: f32 = 45.;
+// Far entities slower than this are considered idle.
+const IDLE_SPEED: f32 = 0.5;
+
+// Translations are sent in 1/64th meters, enough for the 1km map to fit an i16.
+pub const TRANSLATION_QUANTIZATION: f32 = 64.;
+const ROTATION_COMPONENT_BITS: u32 = 10;
+
+// Approximate serialized size of a QuantizedTransformUpdate.
+const QUANTIZED_TRANSFORM_UPDATE_BYTES: usize = 31;
+
+// Every percent of packet loss takes this much off the budget of a client, down to the minimum.
+const PACKET_LOSS_BUDGET_FACTOR: f32 = 4.;
+const MIN_BUDGET_SCALE: f32 = 0.25;
+
+// Priority gained per time an update got left over budget, so far entities still get their turn.
+const SKIPPED_PRIORITY: f32 = 1000.;
+
+pub struct TransformBroadcastBudget {
+    // Per client, transforms over budget are left for a later frame in order of priority.
+    pub bytes_per_second: usize,
+}
+
+impl Default for TransformBroadcastBudget {
+    fn default() -> Self {
+        Self {
+            bytes_per_second: 32000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct QuantizedTransform {
+    pub translation: [i16; 3],
+    pub rotation: u32,
+}
+
+// The last transform each client received per entity, unchanged transforms are not sent again.
+#[derive(Default)]
+pub struct TransformBroadcastState {
+    pub last_sent: HashMap<u64, HashMap<Entity, QuantizedTransform>>,
+    // How often in a row an update got left over budget, per client and entity.
+    pub skipped: HashMap<u64, HashMap<Entity, u16>>,
+}
+
+pub fn quantize_translation(translation: Vec3) -> [i16; 3] {
+    let quantize = |v: f32| {
+        (v * TRANSLATION_QUANTIZATION)
+            .round()
+            .clamp(i16::MIN as f32, i16::MAX as f32) as i16
+    };
+    [
+        quantize(translation.x),
+        quantize(translation.y),
+        quantize(translation.z),
+    ]
+}
+
+// Smallest three, the index of the largest component in the top two bits, then the other three.
+pub fn quantize_rotation(rotation: Quat) -> u32 {
+    let mut components = rotation.normalize().to_array();
+
+    let mut largest = 0;
+    for i in 1..4 {
+        if components[i].abs() > components[largest].abs() {
+            largest = i;
+        }
+    }
+
+    // q and -q are the same rotation, so the largest component can be kept positive.
+    if components[largest] < 0. {
+        for component in components.iter_mut() {
+            *component = -*component;
+        }
+    }
+
+    let max_value = (1 << ROTATION_COMPONENT_BITS) - 1;
+    let mut packed = (largest as u32) << (ROTATION_COMPONENT_BITS * 3);
+    let mut shift = ROTATION_COMPONENT_BITS * 2;
+
+    for (i, component) in components.iter().enumerate() {
+        if i == largest {
+            continue;
+        }
+        let normalized = (component * std::f32::consts::SQRT_2 * 0.5 + 0.5).clamp(0., 1.);
+        packed |= ((normalized * max_value as f32).round() as u32) << shift;
+        if shift > 0 {
+            shift -= ROTATION_COMPONENT_BITS;
+        }
+    }
+
+    packed
+}
+
+fn get_tick_rate(
+    is_self: bool,
+    in_combat: bool,
+    distance: f32,
+    speed: f32,
+) -> InterpolationPriorityRates {
+    if is_self || (in_combat && distance < NEAR_DISTANCE) {
+        InterpolationPriorityRates::T24
+    } else if distance < NEAR_DISTANCE {
+        InterpolationPriorityRates::T12
+    } else if distance < MID_DISTANCE {
+        InterpolationPriorityRates::T8
+    } else if speed > IDLE_SPEED {
+        InterpolationPriorityRates::T4
+    } else {
+        InterpolationPriorityRates::T2
+    }
+}
+
+fn get_rate_u(entity_tick_rate: InterpolationPriorityRates) -> u8 {
+    match entity_tick_rate {
+        InterpolationPriorityRates::T2 => 2,
+        InterpolationPriorityRates::T4 => 4,
+        InterpolationPriorityRates::T8 => 8,
+        InterpolationPriorityRates::T12 => 12,
+        InterpolationPriorityRates::T24 => 24,
+    }
+}
+
+struct PendingTransformUpdate {
+    entity: Entity,
+    quantized: QuantizedTransform,
+    rate_u: u8,
+    priority: f32,
+}
+
 pub fn broadcast_interpolation_transforms(
     time: Res<Time>,
 
@@ -42,33 +177,55 @@ pub fn broadcast_interpolation_transforms(
             &Transform,
             &Velocity,
             &mut CachedBroadcastTransform,
-            Option<&ConnectedPlayer>,
+            Option<&Humanoid>,
         ),
         (With<RigidBody>, Without<RigidBodyDisabled>),
     >,
+    sensers: Query<&Transform>,
     mut interpolation_frame: Local<InterpolationFrame>,
+    budget: Res<TransformBroadcastBudget>,
+    mut broadcast_state: ResMut<TransformBroadcastState>,
+    network_stats: Query<&NetworkStats>,
+    metrics: Res<Metrics>,
 ) {
+    let started = Instant::now();
+
     interpolation_frame.i += 1;
 
     if interpolation_frame.i > 24 {
         interpolation_frame.i = 1;
+
+        // Forget clients that left and entities that are gone, once a second.
+        broadcast_state
+            .last_sent
+            .retain(|handle, _| handle_to_entity.map.contains_key(handle));
+        broadcast_state
+            .skipped
+            .retain(|handle, _| handle_to_entity.map.contains_key(handle));
+        for last_sent in broadcast_state.last_sent.values_mut() {
+            last_sent.retain(|entity, _| query_interpolated_entities.get(*entity).is_ok());
+        }
+        for skipped in broadcast_state.skipped.values_mut() {
+            skipped.retain(|entity, _| query_interpolated_entities.get(*entity).is_ok());
+        }
     }
 
     let current_time_stamp = time.time_since_startup().as_millis();
 
+    let mut pending_updates: HashMap<u64, Vec<PendingTransformUpdate>> = HashMap::new();
+
     for (
         interpolated_entity,
         visible_component,
         rigid_body_position_component,
         rigid_body_velocity_component,
         mut cached_transform_component,
-        connected_player_component_option,
+        humanoid_component_option,
     ) in query_interpolated_entities.iter_mut()
     {
         let rigid_body_position = rigid_body_position_component;
 
         let rigid_body_translation = rigid_body_position.translation;
-        let rigid_body_velocity = rigid_body_velocity_component.linvel;
         let rigid_body_rotation = rigid_body_position.rotation;
 
         let this_transform = Transform {
@@ -79,87 +236,148 @@ pub fn broadcast_interpolation_transforms(
 
         if this_transform == cached_transform_component.transform {
             cached_transform_component.is_active = false;
+        } else {
+            cached_transform_component.is_active = true;
+            cached_transform_component.transform = this_transform;
+        }
+
+        let quantized = QuantizedTransform {
+            translation: quantize_translation(rigid_body_translation),
+            rotation: quantize_rotation(rigid_body_rotation),
+        };
+
+        // Resting entities that every client already has need no further work.
+        if !cached_transform_component.is_active
+            && visible_component.sensed_by.iter().all(|sensed_by_entity| {
+                match handle_to_entity.inv_map.get(sensed_by_entity) {
+                    Some(handle) => match broadcast_state.last_sent.get(handle) {
+                        Some(last_sent) => last_sent.get(&interpolated_entity) == Some(&quantized),
+                        None => false,
+                    },
+                    None => true,
+                }
+            })
+        {
             continue;
         }
-        cached_transform_component.is_active = true;
 
-        cached_transform_component.transform = this_transform;
+        let speed = rigid_body_velocity_component.linvel.length();
+        let in_combat = match humanoid_component_option {
+            Some(humanoid_component) => humanoid_component.combat_mode,
+            None => false,
+        };
 
         for sensed_by_entity in visible_component.sensed_by.iter() {
-            let player_handle_option = handle_to_entity.inv_map.get(&sensed_by_entity);
+            let handle;
 
-            if player_handle_option.is_none() {
-                continue;
+            match handle_to_entity.inv_map.get(&sensed_by_entity) {
+                Some(h) => {
+                    handle = *h;
+                }
+                None => {
+                    continue;
+                }
             }
 
-            let mut entity_tick_rate = &InterpolationPriorityRates::T4;
-
-            if *sensed_by_entity == interpolated_entity {
-                entity_tick_rate = &InterpolationPriorityRates::T24;
-            } else if connected_player_component_option.is_some() {
-                entity_tick_rate = &InterpolationPriorityRates::T12;
+            // Clients already have this transform, whether it was sent at full or lower rate.
+            match broadcast_state.last_sent.get(&handle) {
+                Some(last_sent) => {
+                    if last_sent.get(&interpolated_entity) == Some(&quantized) {
+                        continue;
+                    }
+                }
+                None => {}
             }
 
+            let distance = match sensers.get(*sensed_by_entity) {
+                Ok(senser_transform) => senser_transform
+                    .translation
+                    .distance(rigid_body_translation),
+                Err(_rr) => 0.,
+            };
+
+            let is_self = *sensed_by_entity == interpolated_entity;
+            let entity_tick_rate = get_tick_rate(is_self, in_combat, distance, speed);
+
             if !is_interpolation_frame(&entity_tick_rate, interpolation_frame.i) {
                 continue;
             }
 
-            let rate_u: u8;
-            let send_vel;
+            let rate_u = get_rate_u(entity_tick_rate);
 
-            match entity_tick_rate {
-                InterpolationPriorityRates::T4 => {
-                    rate_u = 4;
-                    //send_vel = true;
-                    send_vel = false;
-                }
-                InterpolationPriorityRates::T8 => {
-                    rate_u = 8;
-                    //send_vel = true;
-                    send_vel = false;
-                }
-                InterpolationPriorityRates::T12 => {
-                    rate_u = 12;
-                    send_vel = false;
-                }
-                InterpolationPriorityRates::T24 => {
-                    rate_u = 24;
-                    send_vel = false;
-                }
-            }
+            let skipped = match broadcast_state.skipped.get(&handle) {
+                Some(skipped) => *skipped.get(&interpolated_entity).unwrap_or(&0),
+                None => 0,
+            };
+
+            pending_updates
+                .entry(handle)
+                .or_default()
+                .push(PendingTransformUpdate {
+                    entity: interpolated_entity,
+                    quantized,
+                    rate_u,
+                    priority: rate_u as f32 * 1000. - distance + skipped as f32 * SKIPPED_PRIORITY,
+                });
+        }
+    }
 
-            let velocity_option;
+    let frame_budget = (budget.bytes_per_second as f64 / BROADCAST_INTERPOLATION_TRANSFORM_RATE)
+        as usize
+        / QUANTIZED_TRANSFORM_UPDATE_BYTES;
 
-            if send_vel {
-                velocity_option = Some(rigid_body_velocity);
-            } else {
-                velocity_option = None;
-            }
+    for (handle, mut updates) in pending_updates {
+        // Lossy connections get less, sending more would only congest them further.
+        let budget_scale = match handle_to_entity.map.get(&handle) {
+            Some(entity) => match network_stats.get(*entity) {
+                Ok(network_stats_component) => (1.
+                    - network_stats_component.packet_loss * PACKET_LOSS_BUDGET_FACTOR)
+                    .max(MIN_BUDGET_SCALE),
+                Err(_rr) => 1.,
+            },
+            None => 1.,
+        };
 
-            match player_handle_option {
-                Some(handle) => {
-                    net.send_message(
-                        *handle,
-                        RENET_UNRELIABLE_CHANNEL_ID,
-                        serialize::<UnreliableServerMessage>(
-                            &UnreliableServerMessage::TransformUpdate(
-                                interpolated_entity.to_bits(),
-                                rigid_body_translation,
-                                rigid_body_rotation,
-                                velocity_option,
-                                current_time_stamp as u64,
-                                rate_u,
-                            ),
-                        )
-                        .unwrap(),
-                    );
-                }
-                None => {
-                    continue;
-                }
-            }
+        // Over budget the most relevant entities go first, the rest get another chance next time.
+        updates.sort_by(|a, b| {
+            b.priority
+                .partial_cmp(&a.priority)
+                .unwrap_or(Ordering::Equal)
+        });
+        let left_over =
+            updates.split_off(((frame_budget as f32 * budget_scale) as usize).min(updates.len()));
+
+        let skipped = broadcast_state.skipped.entry(handle).or_default();
+
+        for update in left_over {
+            let times = skipped.entry(update.entity).or_insert(0);
+            *times = times.saturating_add(1);
+        }
+
+        for update in updates.iter() {
+            skipped.remove(&update.entity);
+        }
+
+        let last_sent = broadcast_state.last_sent.entry(handle).or_default();
+
+        for update in updates {
+            let bytes = serialize::<UnreliableServerMessage>(
+                &UnreliableServerMessage::QuantizedTransformUpdate(
+                    update.entity.to_bits(),
+                    update.quantized.translation,
+                    update.quantized.rotation,
+                    current_time_stamp as u64,
+                    update.rate_u,
+                ),
+            )
+            .unwrap();
+            metrics.add_sent("QuantizedTransformUpdate", bytes.len());
+            net.send_message(handle, RENET_UNRELIABLE_CHANNEL_ID, bytes);
+            last_sent.insert(update.entity, update.quantized);
         }
     }
+
+    metrics.record_system("broadcast_interpolation_transforms", started.elapsed());
 }
 
 fn is_interpolation_frame(
@@ -167,6 +385,13 @@ fn is_interpolation_frame(
     current_frame: u8,
 ) -> bool {
     match entity_tick_rate {
+        InterpolationPriorityRates::T2 => {
+            if current_frame % 12 == 0 {
+                true
+            } else {
+                false
+            }
+        }
         InterpolationPriorityRates::T4 => {
             if current_frame % 6 == 0 {
                 true
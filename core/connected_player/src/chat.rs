@@ -9,9 +9,8 @@ pub enum RadioChannel {
     Proximity,
     ProximityEmote,
     Global,
-    Common,
-    Security,
-    SpecialOps,
+    // Id of a channel from the RadioChannels catalogue.
+    Radio(String),
 }
 pub struct NetChatMessage {
     pub handle: u64,
@@ -26,7 +25,7 @@ impl PendingMessage for NetChatMessage {
     }
 }
 pub fn chat_message_input_event(
-    mut chat_message_input_events: EventReader<InputChatMessage>,
+    mut chat_message_input_events: EventReader<FilteredChatMessage>,
     handle_to_entity: Res<HandleToEntity>,
     player_pawns: Query<(&Pawn, &Transform, &Sensable)>,
     radio_pawns: Query<(Entity, &Radio, &Transform, &PersistentPlayerData)>,
@@ -34,8 +33,16 @@ pub fn chat_message_input_event(
     mut net_new_chat_message_event: EventWriter<NetChatMessage>,
     mut net_send_entity_updates: EventWriter<NetSendEntityUpdates>,
     global_listeners: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    radio_channels: Res<RadioChannels>,
+    mut log_chat_message: EventWriter<LogChatMessage>,
+    languages: Res<Languages>,
+    known_languages: Query<&KnownLanguages>,
 ) {
     for chat_message_input_event in chat_message_input_events.iter() {
+        if is_chat_command(&chat_message_input_event.message) {
+            continue;
+        }
+
         let player_pawn_entity;
         player_pawn_entity = chat_message_input_event.entity;
 
@@ -55,12 +62,16 @@ pub fn chat_message_input_event(
                     &player_components.2.sensed_by_cached,
                     player_position,
                     player_components.0.name.clone(),
-                    player_components.0.job,
+                    &player_components.0.job,
                     chat_message_input_event.message.clone(),
                     Communicator::Standard,
                     false,
                     &radio_pawns,
+                    &radio_channels,
+                    &languages,
+                    &known_languages,
                     &global_listeners,
+                    &mut log_chat_message,
                     Some(&player_pawn_entity),
                     Some(&mut net_send_entity_updates),
                     &MessagingPlayerState::Alive,
@@ -96,12 +107,16 @@ pub fn chat_message_input_event(
                     &vec![],
                     Vec3::ZERO,
                     persistent_player_data_component.user_name.clone(),
-                    ShipJobsEnum::Security,
+                    &ShipJob::default(),
                     chat_message_input_event.message.clone(),
                     Communicator::Standard,
                     false,
                     &radio_pawns,
+                    &radio_channels,
+                    &languages,
+                    &known_languages,
                     &global_listeners,
+                    &mut log_chat_message,
                     Some(&player_pawn_entity),
                     Some(&mut net_send_entity_updates),
                     &MessagingPlayerState::SoftConnected,
@@ -118,67 +133,65 @@ use bevy::{
     prelude::{error, warn, Component, Entity, EventReader, EventWriter, Query, Res, Transform},
 };
 
-use pawn::pawn::{Pawn, PersistentPlayerData, ShipJobsEnum};
+use pawn::pawn::{Pawn, PersistentPlayerData, ShipJob};
 use sfx::{proximity_message::PlaySoundProximityMessage, radio_sound::PlaySoundRadioMessage};
 use api::{
     chat::{
         escape_bb, BILLBOARD_DATA_SECURITY_END, BILLBOARD_DATA_SECURITY_START,
         BILLBOARD_SHOUT_FONT, BILLBOARD_SHOUT_ITALIC_FONT, FAR_BOLD_FONT, FAR_SHOUT_FONT,
-        FURTHER_BOLD_FONT, FURTHER_SHOUT_FONT, JOB_CONTROL_WORD, JOB_SECURITY_WORD,
-        NEARBY_BOLD_FONT, NEARBY_SHOUT_FONT, SHOUT_DATA_MACHINE_FAR_I_END,
-        SHOUT_DATA_MACHINE_FAR_I_START, SHOUT_DATA_MACHINE_FURTHER_I_END,
-        SHOUT_DATA_MACHINE_FURTHER_I_START, SHOUT_DATA_MACHINE_NEARBY_I_END,
-        SHOUT_DATA_MACHINE_NEARBY_I_START, SHOUT_DATA_STANDARD_FAR_I_END,
-        SHOUT_DATA_STANDARD_FAR_I_START, SHOUT_DATA_STANDARD_FURTHER_I_END,
-        SHOUT_DATA_STANDARD_FURTHER_I_START, SHOUT_DATA_STANDARD_NEARBY_I_END,
-        SHOUT_DATA_STANDARD_NEARBY_I_START, TALK_DATA_MACHINE_B_FAR_END,
-        TALK_DATA_MACHINE_B_FAR_START, TALK_DATA_MACHINE_B_FURTHER_END,
-        TALK_DATA_MACHINE_B_FURTHER_START, TALK_DATA_MACHINE_B_NEARBY_END,
-        TALK_DATA_MACHINE_B_NEARBY_START, TALK_DATA_MACHINE_I_FAR_END,
-        TALK_DATA_MACHINE_I_FAR_START, TALK_DATA_MACHINE_I_FURTHER_END,
-        TALK_DATA_MACHINE_I_FURTHER_START, TALK_DATA_MACHINE_I_NEARBY_END,
-        TALK_DATA_MACHINE_I_NEARBY_START, TALK_DATA_MACHINE_NORMAL_FAR_END,
-        TALK_DATA_MACHINE_NORMAL_FAR_START, TALK_DATA_MACHINE_NORMAL_FURTHER_END,
-        TALK_DATA_MACHINE_NORMAL_FURTHER_START, TALK_DATA_MACHINE_NORMAL_NEARBY_END,
-        TALK_DATA_MACHINE_NORMAL_NEARBY_START, TALK_DATA_STANDARD_B_FAR_END,
-        TALK_DATA_STANDARD_B_FAR_START, TALK_DATA_STANDARD_B_FURTHER_END,
-        TALK_DATA_STANDARD_B_FURTHER_START, TALK_DATA_STANDARD_B_NEARBY_END,
-        TALK_DATA_STANDARD_B_NEARBY_START, TALK_DATA_STANDARD_I_FAR_END,
-        TALK_DATA_STANDARD_I_FAR_START, TALK_DATA_STANDARD_I_FURTHER_END,
-        TALK_DATA_STANDARD_I_FURTHER_START, TALK_DATA_STANDARD_I_NEARBY_END,
-        TALK_DATA_STANDARD_I_NEARBY_START, TALK_DATA_STANDARD_NORMAL_FAR_END,
-        TALK_DATA_STANDARD_NORMAL_FAR_START, TALK_DATA_STANDARD_NORMAL_FURTHER_END,
-        TALK_DATA_STANDARD_NORMAL_FURTHER_START, TALK_DATA_STANDARD_NORMAL_NEARBY_END,
-        TALK_DATA_STANDARD_NORMAL_NEARBY_START, TALK_SPACE_COMMON_CHATPREFIX,
-        TALK_SPACE_COMMON_MESSAGEBBEND, TALK_SPACE_COMMON_MESSAGEBBSTART,
-        TALK_SPACE_COMMON_PREFIXBBEND, TALK_SPACE_COMMON_PREFIXBBSTART, TALK_SPACE_COMMON_WORD,
+        FURTHER_BOLD_FONT, FURTHER_SHOUT_FONT, NEARBY_BOLD_FONT, NEARBY_SHOUT_FONT,
+        SHOUT_DATA_MACHINE_FAR_I_END, SHOUT_DATA_MACHINE_FAR_I_START,
+        SHOUT_DATA_MACHINE_FURTHER_I_END, SHOUT_DATA_MACHINE_FURTHER_I_START,
+        SHOUT_DATA_MACHINE_NEARBY_I_END, SHOUT_DATA_MACHINE_NEARBY_I_START,
+        SHOUT_DATA_STANDARD_FAR_I_END, SHOUT_DATA_STANDARD_FAR_I_START,
+        SHOUT_DATA_STANDARD_FURTHER_I_END, SHOUT_DATA_STANDARD_FURTHER_I_START,
+        SHOUT_DATA_STANDARD_NEARBY_I_END, SHOUT_DATA_STANDARD_NEARBY_I_START,
+        TALK_DATA_MACHINE_B_FAR_END, TALK_DATA_MACHINE_B_FAR_START,
+        TALK_DATA_MACHINE_B_FURTHER_END, TALK_DATA_MACHINE_B_FURTHER_START,
+        TALK_DATA_MACHINE_B_NEARBY_END, TALK_DATA_MACHINE_B_NEARBY_START,
+        TALK_DATA_MACHINE_I_FAR_END, TALK_DATA_MACHINE_I_FAR_START,
+        TALK_DATA_MACHINE_I_FURTHER_END, TALK_DATA_MACHINE_I_FURTHER_START,
+        TALK_DATA_MACHINE_I_NEARBY_END, TALK_DATA_MACHINE_I_NEARBY_START,
+        TALK_DATA_MACHINE_NORMAL_FAR_END, TALK_DATA_MACHINE_NORMAL_FAR_START,
+        TALK_DATA_MACHINE_NORMAL_FURTHER_END, TALK_DATA_MACHINE_NORMAL_FURTHER_START,
+        TALK_DATA_MACHINE_NORMAL_NEARBY_END, TALK_DATA_MACHINE_NORMAL_NEARBY_START,
+        TALK_DATA_STANDARD_B_FAR_END, TALK_DATA_STANDARD_B_FAR_START,
+        TALK_DATA_STANDARD_B_FURTHER_END, TALK_DATA_STANDARD_B_FURTHER_START,
+        TALK_DATA_STANDARD_B_NEARBY_END, TALK_DATA_STANDARD_B_NEARBY_START,
+        TALK_DATA_STANDARD_I_FAR_END, TALK_DATA_STANDARD_I_FAR_START,
+        TALK_DATA_STANDARD_I_FURTHER_END, TALK_DATA_STANDARD_I_FURTHER_START,
+        TALK_DATA_STANDARD_I_NEARBY_END, TALK_DATA_STANDARD_I_NEARBY_START,
+        TALK_DATA_STANDARD_NORMAL_FAR_END, TALK_DATA_STANDARD_NORMAL_FAR_START,
+        TALK_DATA_STANDARD_NORMAL_FURTHER_END, TALK_DATA_STANDARD_NORMAL_FURTHER_START,
+        TALK_DATA_STANDARD_NORMAL_NEARBY_END, TALK_DATA_STANDARD_NORMAL_NEARBY_START,
         TALK_SPACE_GLOBAL_CHATPREFIX, TALK_SPACE_PROXIMITY_EMOTE_CHATPREFIX,
         TALK_SPACE_PROXIMITY_EMOTE_MESSAGEBBEND, TALK_SPACE_PROXIMITY_EMOTE_MESSAGEBBSTART,
         TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBEND, TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBSTART,
         TALK_SPACE_PROXIMITY_MESSAGEBBEND, TALK_SPACE_PROXIMITY_MESSAGEBBSTART,
         TALK_SPACE_PROXIMITY_PREFIXBBEND, TALK_SPACE_PROXIMITY_PREFIXBBSTART,
-        TALK_SPACE_SECURITY_CHATPREFIX, TALK_SPACE_SECURITY_MESSAGEBBEND,
-        TALK_SPACE_SECURITY_MESSAGEBBSTART, TALK_SPACE_SECURITY_PREFIXBBEND,
-        TALK_SPACE_SECURITY_PREFIXBBSTART, TALK_SPACE_SECURITY_WORD,
-        TALK_SPACE_SPECIALOPS_CHATPREFIX, TALK_SPACE_SPECIALOPS_MESSAGEBBEND,
-        TALK_SPACE_SPECIALOPS_MESSAGEBBSTART, TALK_SPACE_SPECIALOPS_PREFIXBBEND,
-        TALK_SPACE_SPECIALOPS_PREFIXBBSTART, TALK_SPACE_SPECIALOPS_WORD, TALK_STYLE_MACHINE_ASKS,
-        TALK_STYLE_MACHINE_EXCLAIMS, TALK_STYLE_MACHINE_SHOUTS, TALK_STYLE_MACHINE_STANDARD,
-        TALK_STYLE_STANDARD_ASKS, TALK_STYLE_STANDARD_EXCLAIMS, TALK_STYLE_STANDARD_SHOUTS,
-        TALK_STYLE_STANDARD_STANDARD, TALK_TYPE_MACHINE_NEARBY_END, TALK_TYPE_MACHINE_NEARBY_START,
-        TALK_TYPE_STANDARD_NEARBY_END, TALK_TYPE_STANDARD_NEARBY_START,
+        TALK_STYLE_MACHINE_ASKS, TALK_STYLE_MACHINE_EXCLAIMS, TALK_STYLE_MACHINE_SHOUTS,
+        TALK_STYLE_MACHINE_STANDARD, TALK_STYLE_STANDARD_ASKS, TALK_STYLE_STANDARD_EXCLAIMS,
+        TALK_STYLE_STANDARD_SHOUTS, TALK_STYLE_STANDARD_STANDARD, TALK_TYPE_MACHINE_NEARBY_END,
+        TALK_TYPE_MACHINE_NEARBY_START, TALK_TYPE_STANDARD_NEARBY_END,
+        TALK_TYPE_STANDARD_NEARBY_START,
     },
     connected_player::SoftPlayer,
     data::{ConnectedPlayer, HandleToEntity},
     entity_updates::{EntityUpdateData, NetSendEntityUpdates},
-    network::{
-        EntityWorldType, InputChatMessage, PendingMessage, PendingNetworkMessage,
-        ReliableServerMessage,
-    },
+    network::{EntityWorldType, PendingMessage, PendingNetworkMessage, ReliableServerMessage},
     sensable::Sensable,
 };
 use voca_rs::*;
 
+use crate::{
+    admin_help::is_chat_command,
+    chat_filter::{FilteredChatMessage, LogChatMessage},
+    language::{
+        heard_message, KnownLanguages, Languages, MUFFLED_HEAR_DISTANCE, SPOKEN_MESSAGE_PLACEHOLDER,
+    },
+    radio::RadioChannels,
+};
+
 enum Distance {
     Nearby,
     Further,
@@ -221,9 +234,14 @@ pub fn get_talk_spaces_setupui() -> Vec<(String, String)> {
     )]
 }
 
-fn get_talk_space(message: String) -> (RadioChannel, String, bool, bool) {
+fn get_talk_space(
+    message: String,
+    radio_channels: &RadioChannels,
+    languages: &Languages,
+) -> (RadioChannel, String, bool, bool, Option<String>) {
     let radio_channel;
-    let content;
+    let mut content: String;
+    let mut language_option = None;
     let mut exclusive_proximity = false;
     let mut is_emote = false;
 
@@ -237,22 +255,36 @@ fn get_talk_space(message: String) -> (RadioChannel, String, bool, bool) {
             .collect();
         exclusive_proximity = true;
         is_emote = true;
-    } else if message.starts_with(TALK_SPACE_COMMON_CHATPREFIX) {
-        radio_channel = RadioChannel::Common;
-        content = message.split(TALK_SPACE_COMMON_CHATPREFIX).collect();
-    } else if message.starts_with(TALK_SPACE_SECURITY_CHATPREFIX) {
-        radio_channel = RadioChannel::Security;
-        content = message.split(TALK_SPACE_SECURITY_CHATPREFIX).collect();
-    } else if message.starts_with(TALK_SPACE_SPECIALOPS_CHATPREFIX) {
-        radio_channel = RadioChannel::SpecialOps;
-        content = message.split(TALK_SPACE_SPECIALOPS_CHATPREFIX).collect();
     } else {
-        radio_channel = RadioChannel::Proximity;
-        content = message.to_owned();
-        exclusive_proximity = true;
+        match radio_channels.from_message(&message) {
+            Some(channel) => {
+                radio_channel = RadioChannel::Radio(channel.id.clone());
+                content = message[channel.prefix.len()..].to_string();
+            }
+            None => {
+                radio_channel = RadioChannel::Proximity;
+                content = message.to_owned();
+                exclusive_proximity = true;
+            }
+        }
+
+        let trimmed = content.trim_start().to_string();
+        match languages.from_message(&trimmed) {
+            Some(language) => {
+                language_option = Some(language.id.clone());
+                content = trimmed[language.prefix.len()..].to_string();
+            }
+            None => {}
+        }
     }
 
-    (radio_channel, content, exclusive_proximity, is_emote)
+    (
+        radio_channel,
+        content,
+        exclusive_proximity,
+        is_emote,
+        language_option,
+    )
 }
 
 pub fn new_global_message(
@@ -289,12 +321,16 @@ pub fn new_chat_message(
     sensed_by_distance: &Vec<Entity>,
     position: Vec3,
     name: String,
-    job: ShipJobsEnum,
+    job: &ShipJob,
     mut raw_message: String,
     communicator: Communicator,
     exclusive_radio: bool,
     radio_pawns: &Query<(Entity, &Radio, &Transform, &PersistentPlayerData)>,
+    radio_channels: &RadioChannels,
+    languages: &Languages,
+    known_languages: &Query<&KnownLanguages>,
     global_listeners: &Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    log_chat_message: &mut EventWriter<LogChatMessage>,
     messenger_entity_option: Option<&Entity>,
     mut net_send_entity_updates_option: Option<&mut EventWriter<NetSendEntityUpdates>>,
     messaging_player_state: &MessagingPlayerState,
@@ -310,7 +346,8 @@ pub fn new_chat_message(
     let mut exclusive_proximity;
     let mut is_emote;
 
-    let result = get_talk_space(raw_message.clone());
+    let result = get_talk_space(raw_message.clone(), radio_channels, languages);
+    let mut language_prefix_option = result.4.clone();
     radio_channel = result.0;
     message = result.1;
     exclusive_proximity = result.2;
@@ -348,7 +385,10 @@ pub fn new_chat_message(
     }
 
     while !prev_was_proximity {
-        let result = get_talk_space(message.clone());
+        let result = get_talk_space(message.clone(), radio_channels, languages);
+        if result.4.is_some() {
+            language_prefix_option = result.4.clone();
+        }
 
         if matches!(result.0, RadioChannel::ProximityEmote) {
             proximity_emote_included = true;
@@ -401,6 +441,16 @@ pub fn new_chat_message(
     if matches!(radio_channel, RadioChannel::Global) {
         match global_listeners.get(*messenger_entity_option.unwrap()) {
             Ok((_connected, persistent_player_data_component)) => {
+                log_chat_message.send(LogChatMessage {
+                    channel: "Global".to_string(),
+                    sender: persistent_player_data_component.user_name.clone(),
+                    message: message.clone(),
+                    recipients: global_listeners
+                        .iter()
+                        .filter(|(connected_player, _)| connected_player.connected)
+                        .map(|(connected_player, _)| connected_player.handle)
+                        .collect(),
+                });
                 new_global_message(
                     persistent_player_data_component,
                     global_listeners,
@@ -436,6 +486,37 @@ pub fn new_chat_message(
         }
     }
 
+    // Speakers can only use languages they know, anything else falls back to their default.
+    let speaker_known_option = match messenger_entity_option {
+        Some(messenger_entity) => known_languages.get(*messenger_entity).ok(),
+        None => None,
+    };
+    let language_id_option = match (language_prefix_option, speaker_known_option) {
+        (Some(id), Some(speaker_known)) => {
+            if speaker_known.known.contains(&id) {
+                Some(id)
+            } else {
+                Some(speaker_known.default.clone())
+            }
+        }
+        (None, Some(speaker_known)) => Some(speaker_known.default.clone()),
+        (language_prefix_option, None) => language_prefix_option,
+    };
+    let language_option = match language_id_option {
+        Some(id) => {
+            if is_emote {
+                None
+            } else {
+                languages.get(&id)
+            }
+        }
+        None => None,
+    };
+
+    // Every listener gets their own version of the words, see heard_message.
+    let spoken_message = message;
+    let message = SPOKEN_MESSAGE_PLACEHOLDER.to_string();
+
     let mut radio_message: String = "".to_string();
 
     if exclusive_proximity == false {
@@ -445,41 +526,34 @@ pub fn new_chat_message(
         let talk_space_prefix_bb_end;
         let talk_space_message_bb_start;
         let talk_space_message_bb_end;
-        let mut talk_space_word = "";
-        match radio_channel {
+        let mut talk_space_word = "".to_string();
+        match &radio_channel {
             RadioChannel::Proximity => {
-                talk_space_prefix_bb_start = TALK_SPACE_PROXIMITY_PREFIXBBSTART;
+                talk_space_prefix_bb_start = TALK_SPACE_PROXIMITY_PREFIXBBSTART.to_string();
                 talk_space_prefix_bb_end = TALK_SPACE_PROXIMITY_PREFIXBBEND;
-                talk_space_message_bb_start = TALK_SPACE_PROXIMITY_MESSAGEBBSTART;
+                talk_space_message_bb_start = TALK_SPACE_PROXIMITY_MESSAGEBBSTART.to_string();
                 talk_space_message_bb_end = TALK_SPACE_PROXIMITY_MESSAGEBBEND;
             }
             RadioChannel::ProximityEmote => {
-                talk_space_prefix_bb_start = TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBSTART;
+                talk_space_prefix_bb_start = TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBSTART.to_string();
                 talk_space_prefix_bb_end = TALK_SPACE_PROXIMITY_EMOTE_PREFIXBBEND;
-                talk_space_message_bb_start = TALK_SPACE_PROXIMITY_EMOTE_MESSAGEBBSTART;
+                talk_space_message_bb_start = TALK_SPACE_PROXIMITY_EMOTE_MESSAGEBBSTART.to_string();
                 talk_space_message_bb_end = TALK_SPACE_PROXIMITY_EMOTE_MESSAGEBBEND;
             }
-            RadioChannel::Common => {
-                talk_space_prefix_bb_start = TALK_SPACE_COMMON_PREFIXBBSTART;
-                talk_space_word = TALK_SPACE_COMMON_WORD;
-                talk_space_prefix_bb_end = TALK_SPACE_COMMON_PREFIXBBEND;
-                talk_space_message_bb_start = TALK_SPACE_COMMON_MESSAGEBBSTART;
-                talk_space_message_bb_end = TALK_SPACE_COMMON_MESSAGEBBEND;
-            }
-            RadioChannel::Security => {
-                talk_space_prefix_bb_start = TALK_SPACE_SECURITY_PREFIXBBSTART;
-                talk_space_word = TALK_SPACE_SECURITY_WORD;
-                talk_space_prefix_bb_end = TALK_SPACE_SECURITY_PREFIXBBEND;
-                talk_space_message_bb_start = TALK_SPACE_SECURITY_MESSAGEBBSTART;
-                talk_space_message_bb_end = TALK_SPACE_SECURITY_MESSAGEBBEND;
-            }
-            RadioChannel::SpecialOps => {
-                talk_space_prefix_bb_start = TALK_SPACE_SPECIALOPS_PREFIXBBSTART;
-                talk_space_word = TALK_SPACE_SPECIALOPS_WORD;
-                talk_space_prefix_bb_end = TALK_SPACE_SPECIALOPS_PREFIXBBEND;
-                talk_space_message_bb_start = TALK_SPACE_SPECIALOPS_MESSAGEBBSTART;
-                talk_space_message_bb_end = TALK_SPACE_SPECIALOPS_MESSAGEBBEND;
-            }
+            RadioChannel::Radio(id) => match radio_channels.get(id) {
+                Some(channel) => {
+                    talk_space_prefix_bb_start = "[color=".to_string() + &channel.color + "]";
+                    talk_space_word = channel.name.clone();
+                    talk_space_prefix_bb_end = "[/color]";
+                    talk_space_message_bb_start =
+                        "[color=".to_string() + &channel.message_color + "]";
+                    talk_space_message_bb_end = "[/color]";
+                }
+                None => {
+                    warn!("Unknown radio channel {}.", id);
+                    return;
+                }
+            },
             RadioChannel::Global => {
                 warn!("Processing global chat while we shouldn't?");
                 return;
@@ -538,34 +612,26 @@ pub fn new_chat_message(
             }
         }
 
-        let rank_word;
-        match job {
-            ShipJobsEnum::Security => {
-                rank_word = JOB_SECURITY_WORD;
-            }
-            ShipJobsEnum::Control => {
-                rank_word = JOB_CONTROL_WORD;
-            }
-        }
+        let rank_word = &job.name;
 
         if is_emote {
-            radio_message = radio_message + talk_space_prefix_bb_start;
+            radio_message = radio_message + &talk_space_prefix_bb_start;
             radio_message = radio_message + talk_font_nearby_start;
             radio_message =
-                radio_message + &name + " [" + talk_space_word + "][" + rank_word + "] ";
+                radio_message + &name + " [" + &talk_space_word + "][" + rank_word + "] ";
             radio_message = radio_message + talk_font_nearby_end + talk_space_prefix_bb_end;
-            radio_message = radio_message + talk_space_message_bb_start;
+            radio_message = radio_message + &talk_space_message_bb_start;
 
             radio_message =
                 radio_message + talk_font_nearby_start_1 + &message + talk_font_nearby_end_1;
             radio_message = radio_message + talk_space_message_bb_end;
         } else {
-            radio_message = radio_message + talk_space_prefix_bb_start;
+            radio_message = radio_message + &talk_space_prefix_bb_start;
             radio_message = radio_message + talk_font_nearby_start;
             radio_message =
-                radio_message + &name + " [" + talk_space_word + "][" + rank_word + "] ";
+                radio_message + &name + " [" + &talk_space_word + "][" + rank_word + "] ";
             radio_message = radio_message + talk_font_nearby_end + talk_space_prefix_bb_end;
-            radio_message = radio_message + talk_space_message_bb_start;
+            radio_message = radio_message + &talk_space_message_bb_start;
 
             radio_message = radio_message + talk_style_variation_word + ",\n";
 
@@ -649,15 +715,7 @@ pub fn new_chat_message(
             proximity_message_further + &name + further_talk_data_b_end + " ";
         proximity_message_far = proximity_message_far + &name + far_talk_data_b_end + " ";
 
-        let rank_word;
-        match job {
-            ShipJobsEnum::Security => {
-                rank_word = JOB_SECURITY_WORD;
-            }
-            ShipJobsEnum::Control => {
-                rank_word = JOB_CONTROL_WORD;
-            }
-        }
+        let rank_word = &job.name;
 
         if is_emote == false {
             proximity_message_nearby = proximity_message_nearby + "[" + rank_word + "]";
@@ -1072,7 +1130,16 @@ pub fn new_chat_message(
                         Some(listener_handle) => {
                             net_new_chat_message_event.send(NetChatMessage {
                                 handle: *listener_handle,
-                                message: ReliableServerMessage::ChatMessage(radio_message.clone()),
+                                message: ReliableServerMessage::ChatMessage(radio_message.replace(
+                                    SPOKEN_MESSAGE_PLACEHOLDER,
+                                    &heard_message(
+                                        &spoken_message,
+                                        language_option,
+                                        known_languages.get(entity).ok(),
+                                        false,
+                                        *listener_handle,
+                                    ),
+                                )),
                             });
 
                             handles_radio.push(*listener_handle);
@@ -1086,36 +1153,22 @@ pub fn new_chat_message(
 
     if exclusive_radio == false {
         // Proximity messages to listeners based on distance and shouting.
-        let mut sensed_by_list;
+        // Listeners without line of sight hear the speech muffled, if at all.
+        let shouts = matches!(talk_style_variation, TalkStyleVariant::Shouts);
+        let mut sensed_by_list: Vec<(Entity, bool)> = vec![];
 
-        if matches!(talk_style_variation, TalkStyleVariant::Shouts) {
-            sensed_by_list = vec![];
-            for entity in sensed_by {
-                sensed_by_list.push(*entity);
-            }
+        for entity in sensed_by {
+            sensed_by_list.push((*entity, false));
+        }
+        if !is_emote {
             for entity in sensed_by_distance {
-                sensed_by_list.push(*entity);
+                if !sensed_by.contains(entity) {
+                    sensed_by_list.push((*entity, true));
+                }
             }
-        } else {
-            sensed_by_list = sensed_by.to_vec();
         }
 
-        // Build billboard entity_update
-        let mut billboard_entity_update = HashMap::new();
-
-        let mut parameters_entity_update = HashMap::new();
-
-        parameters_entity_update.insert(
-            "billboardMessage".to_string(),
-            EntityUpdateData::String(billboard_message.clone()),
-        );
-
-        billboard_entity_update.insert(
-            "Smoothing/pawn/humanMale/textViewPortChat0/ViewPort/chatText".to_string(),
-            parameters_entity_update,
-        );
-
-        for entity in sensed_by_list {
+        for (entity, muffled) in sensed_by_list {
             let sensed_by_entity_components_result = radio_pawns.get(entity);
 
             match sensed_by_entity_components_result {
@@ -1138,6 +1191,18 @@ pub fn new_chat_message(
 
                             let listener_distance = position.distance(listener_position);
 
+                            if muffled && !shouts && listener_distance > MUFFLED_HEAR_DISTANCE {
+                                continue;
+                            }
+
+                            let heard = heard_message(
+                                &spoken_message,
+                                language_option,
+                                known_languages.get(entity).ok(),
+                                muffled,
+                                *listener_handle,
+                            );
+
                             let distance;
 
                             if listener_distance > 24. {
@@ -1148,42 +1213,54 @@ pub fn new_chat_message(
                                 distance = Distance::Nearby;
                             }
 
-                            match distance {
-                                Distance::Nearby => {
-                                    net_new_chat_message_event.send(NetChatMessage {
-                                        handle: *listener_handle,
-                                        message: ReliableServerMessage::ChatMessage(
-                                            proximity_message_nearby.clone(),
-                                        ),
-                                    });
-                                }
-                                Distance::Further => {
-                                    net_new_chat_message_event.send(NetChatMessage {
-                                        handle: *listener_handle,
-                                        message: ReliableServerMessage::ChatMessage(
-                                            proximity_message_further.clone(),
-                                        ),
-                                    });
-                                }
-                                Distance::Far => {
-                                    net_new_chat_message_event.send(NetChatMessage {
-                                        handle: *listener_handle,
-                                        message: ReliableServerMessage::ChatMessage(
-                                            proximity_message_far.clone(),
-                                        ),
-                                    });
-                                }
+                            let proximity_message = match distance {
+                                Distance::Nearby => &proximity_message_nearby,
+                                Distance::Further => &proximity_message_further,
+                                Distance::Far => &proximity_message_far,
+                            };
+
+                            net_new_chat_message_event.send(NetChatMessage {
+                                handle: *listener_handle,
+                                message: ReliableServerMessage::ChatMessage(
+                                    proximity_message.replace(SPOKEN_MESSAGE_PLACEHOLDER, &heard),
+                                ),
+                            });
+
+                            // The billboard above the speaker is only seen with line of sight.
+                            if muffled {
+                                handles_direct_proximity.push(*listener_handle);
+                                continue;
                             }
 
+                            // Build billboard entity_update
+                            let mut billboard_entity_update = HashMap::new();
+
+                            let mut parameters_entity_update = HashMap::new();
+
+                            parameters_entity_update.insert(
+                                "billboardMessage".to_string(),
+                                EntityUpdateData::String(
+                                    billboard_message.replace(SPOKEN_MESSAGE_PLACEHOLDER, &heard),
+                                ),
+                            );
+
+                            billboard_entity_update.insert(
+                                "Smoothing/pawn/humanMale/textViewPortChat0/ViewPort/chatText"
+                                    .to_string(),
+                                parameters_entity_update,
+                            );
+
                             match net_send_entity_updates_option {
                                 Some(ref mut net_send_entity_updates) => {
                                     match messenger_entity_option {
                                         Some(messenger_entity) => {
+                                            // Unbatched so the billboard shows up together
+                                            // with the chat line it belongs to.
                                             net_send_entity_updates.send(NetSendEntityUpdates {
                                                 handle: *listener_handle,
                                                 message: ReliableServerMessage::EntityUpdate(
                                                     messenger_entity.to_bits(),
-                                                    billboard_entity_update.clone(),
+                                                    billboard_entity_update,
                                                     false,
                                                     EntityWorldType::Main,
                                                 ),
@@ -1208,6 +1285,25 @@ pub fn new_chat_message(
         }
     }
 
+    let log_channel = match &radio_channel {
+        RadioChannel::Proximity => "Local".to_string(),
+        RadioChannel::ProximityEmote => "Me".to_string(),
+        RadioChannel::Global => "Global".to_string(),
+        RadioChannel::Radio(id) => id.clone(),
+    };
+    let mut recipients = handles_radio.clone();
+    for player_handle in handles_direct_proximity.iter() {
+        if !recipients.contains(player_handle) {
+            recipients.push(*player_handle);
+        }
+    }
+    log_chat_message.send(LogChatMessage {
+        channel: log_channel,
+        sender: name,
+        message: spoken_message,
+        recipients,
+    });
+
     for player_handle in handles_direct_proximity.iter() {
         net_new_chat_message_event.send(NetChatMessage {
             handle: *player_handle,
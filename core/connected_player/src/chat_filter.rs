@@ -0,0 +1,599 @@
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{
+    core::Time,
+    prelude::{info, warn, Entity, EventReader, EventWriter, Query, Res, ResMut},
+};
+use console_commands::commands::{
+    player_selector_to_entities, AllConsoleCommands, NetConsoleCommands, NetEntityConsole,
+};
+use networking::messages::InputConsoleCommand;
+use pawn::pawn::{PersistentPlayerData, UsedNames};
+use regex::Regex;
+use serde::Deserialize;
+use api::{
+    chat::{escape_bb, ADMIN_HELP_CHATPREFIX, STAFF_COLOR, WARNING_COLOR},
+    console_commands::{
+        ConsoleCommandVariant, ConsoleCommandVariantValues, CONSOLE_ERROR_COLOR,
+        CONSOLE_SUCCESS_COLOR,
+    },
+    data::ConnectedPlayer,
+    network::{InputChatMessage, ReliableServerMessage},
+};
+
+use crate::chat::NetChatMessage;
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub enum LinkPolicy {
+    Allow,
+    Strip,
+    Block,
+}
+
+#[derive(Deserialize)]
+pub struct ChatReplacementData {
+    pub pattern: String,
+    pub replacement: String,
+}
+
+#[derive(Deserialize)]
+pub struct ChatFilterData {
+    pub blocklist: Vec<String>,
+    pub replacements: Vec<ChatReplacementData>,
+    pub link_policy: LinkPolicy,
+    pub allowed_link_domains: Vec<String>,
+    pub log_max_bytes: u64,
+    pub log_max_files: u8,
+}
+
+pub struct ChatFilter {
+    pub blocklist: Vec<Regex>,
+    pub replacements: Vec<(Regex, String)>,
+    pub link_policy: LinkPolicy,
+    pub allowed_link_domains: Vec<String>,
+    pub link_regex: Regex,
+    pub log_max_bytes: u64,
+    pub log_max_files: u8,
+}
+
+impl Default for ChatFilter {
+    fn default() -> Self {
+        Self {
+            blocklist: vec![],
+            replacements: vec![],
+            link_policy: LinkPolicy::Allow,
+            allowed_link_domains: vec![],
+            link_regex: Regex::new(r"(?i)\b(?:https?://|www\.)([^\s/]+)\S*").unwrap(),
+            log_max_bytes: 5242880,
+            log_max_files: 5,
+        }
+    }
+}
+
+impl ChatFilter {
+    // Returns the message to send on, or why it was blocked.
+    pub fn apply(&self, message: &str) -> Result<String, String> {
+        for pattern in self.blocklist.iter() {
+            if pattern.is_match(message) {
+                return Err("Your message was blocked by the chat filter.".to_string());
+            }
+        }
+
+        let mut filtered = message.to_string();
+
+        for (pattern, replacement) in self.replacements.iter() {
+            filtered = pattern
+                .replace_all(&filtered, replacement.as_str())
+                .to_string();
+        }
+
+        if self.link_policy == LinkPolicy::Allow {
+            return Ok(filtered);
+        }
+
+        let has_disallowed_link = self.link_regex.captures_iter(&filtered).any(|captures| {
+            let host = captures[1].to_lowercase();
+            !self
+                .allowed_link_domains
+                .iter()
+                .any(|domain| host == *domain || host.ends_with(&(".".to_string() + domain)))
+        });
+
+        if !has_disallowed_link {
+            return Ok(filtered);
+        }
+
+        match self.link_policy {
+            LinkPolicy::Block => Err("Links are not allowed in chat.".to_string()),
+            _ => {
+                let allowed_link_domains = &self.allowed_link_domains;
+                Ok(self
+                    .link_regex
+                    .replace_all(&filtered, |captures: &regex::Captures| {
+                        let host = captures[1].to_lowercase();
+                        if allowed_link_domains.iter().any(|domain| {
+                            host == *domain || host.ends_with(&(".".to_string() + domain))
+                        }) {
+                            captures[0].to_string()
+                        } else {
+                            "[link removed]".to_string()
+                        }
+                    })
+                    .to_string())
+            }
+        }
+    }
+}
+
+// Mutes are kept by user name so reconnecting does not lift them.
+// None mutes until the server restarts or an admin unmutes.
+#[derive(Default)]
+pub struct ChatMutes {
+    pub muted: HashMap<String, Option<f64>>,
+}
+
+impl ChatMutes {
+    pub fn is_muted(&mut self, user_name: &str, now: f64) -> bool {
+        match self.muted.get(user_name) {
+            Some(Some(until)) => {
+                if *until <= now {
+                    self.muted.remove(user_name);
+                    false
+                } else {
+                    true
+                }
+            }
+            Some(None) => true,
+            None => false,
+        }
+    }
+}
+
+// Chat input after it passed mutes and filters, the rest of the chat systems read this.
+pub struct FilteredChatMessage {
+    pub entity: Entity,
+    pub message: String,
+}
+
+// Sent for every delivered chat message so it ends up in the chat log.
+pub struct LogChatMessage {
+    pub channel: String,
+    pub sender: String,
+    pub message: String,
+    pub recipients: Vec<u64>,
+}
+
+pub fn startup_chat_filter(mut chat_filter: ResMut<ChatFilter>) {
+    let filter_json = Path::new("data").join("chat").join("chat_filter.json");
+    let filter_raw_json: String = fs::read_to_string(filter_json)
+        .expect("startup_chat_filter() Error reading chat_filter.json from drive.");
+    let filter_data: ChatFilterData = serde_json::from_str(&filter_raw_json)
+        .expect("startup_chat_filter() Error parsing chat_filter.json String.");
+
+    chat_filter.blocklist = filter_data
+        .blocklist
+        .iter()
+        .map(|pattern| {
+            Regex::new(pattern).expect("startup_chat_filter() Error compiling blocklist pattern.")
+        })
+        .collect();
+    chat_filter.replacements = filter_data
+        .replacements
+        .iter()
+        .map(|replacement| {
+            (
+                Regex::new(&replacement.pattern)
+                    .expect("startup_chat_filter() Error compiling replacement pattern."),
+                replacement.replacement.clone(),
+            )
+        })
+        .collect();
+    chat_filter.link_policy = filter_data.link_policy;
+    chat_filter.allowed_link_domains = filter_data
+        .allowed_link_domains
+        .iter()
+        .map(|domain| domain.to_lowercase())
+        .collect();
+    chat_filter.log_max_bytes = filter_data.log_max_bytes;
+    chat_filter.log_max_files = filter_data.log_max_files.max(1);
+
+    info!(
+        "Loaded chat filter with {} blocked and {} replaced patterns.",
+        chat_filter.blocklist.len(),
+        chat_filter.replacements.len()
+    );
+}
+
+pub fn filter_chat_messages(
+    mut chat_message_input_events: EventReader<InputChatMessage>,
+    connected_players: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    chat_filter: Res<ChatFilter>,
+    mut chat_mutes: ResMut<ChatMutes>,
+    time: Res<Time>,
+    mut filtered_chat_messages: EventWriter<FilteredChatMessage>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+) {
+    for chat_message_input_event in chat_message_input_events.iter() {
+        let (connected_player, persistent_player_data) =
+            match connected_players.get(chat_message_input_event.entity) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
+        let message = chat_message_input_event.message.trim();
+
+        // Muted players can still reach the admins.
+        let is_admin_help = message.split_whitespace().next() == Some(ADMIN_HELP_CHATPREFIX);
+
+        if !is_admin_help
+            && chat_mutes.is_muted(
+                &persistent_player_data.user_name,
+                time.seconds_since_startup(),
+            )
+        {
+            net_chat_message.send(NetChatMessage {
+                handle: connected_player.handle,
+                message: ReliableServerMessage::ChatMessage(
+                    "[color=".to_string() + WARNING_COLOR + "]You are muted.[/color]",
+                ),
+            });
+            continue;
+        }
+
+        match chat_filter.apply(message) {
+            Ok(filtered) => {
+                filtered_chat_messages.send(FilteredChatMessage {
+                    entity: chat_message_input_event.entity,
+                    message: filtered,
+                });
+            }
+            Err(reason) => {
+                net_chat_message.send(NetChatMessage {
+                    handle: connected_player.handle,
+                    message: ReliableServerMessage::ChatMessage(
+                        "[color=".to_string() + WARNING_COLOR + "]" + &reason + "[/color]",
+                    ),
+                });
+
+                // Let the admins see what was held back.
+                for (staff_player, _persistent_player_data) in connected_players.iter() {
+                    if !staff_player.connected || !staff_player.rcon {
+                        continue;
+                    }
+                    net_chat_message.send(NetChatMessage {
+                        handle: staff_player.handle,
+                        message: ReliableServerMessage::ChatMessage(
+                            "[color=".to_string()
+                                + STAFF_COLOR
+                                + "][b](Filter)[/b] Blocked from "
+                                + &persistent_player_data.user_name
+                                + ": "
+                                + &escape_bb(message.to_string(), false, false)
+                                + "[/color]",
+                        ),
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn rotate_chat_log(logs_path: &Path, max_files: u8) {
+    let rotated = |i: u8| logs_path.join(format!("chat.{}.log", i));
+
+    let _ = fs::remove_file(rotated(max_files));
+    for i in (1..max_files).rev() {
+        let _ = fs::rename(rotated(i), rotated(i + 1));
+    }
+    let _ = fs::rename(logs_path.join("chat.log"), rotated(1));
+}
+
+// Chat log lines waiting for flush_chat_log, so the log isn't written to every frame.
+#[derive(Default)]
+pub struct ChatLogBuffer {
+    pub lines: Vec<String>,
+}
+
+pub fn write_chat_log(
+    mut log_chat_messages: EventReader<LogChatMessage>,
+    connected_players: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    mut chat_log_buffer: ResMut<ChatLogBuffer>,
+) {
+    let timestamp = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs(),
+        Err(_rr) => 0,
+    };
+
+    for log_chat_message in log_chat_messages.iter() {
+        let recipients: Vec<String> = connected_players
+            .iter()
+            .filter(|(connected_player, _)| {
+                log_chat_message
+                    .recipients
+                    .contains(&connected_player.handle)
+            })
+            .map(|(_, persistent_player_data)| persistent_player_data.user_name.clone())
+            .collect();
+
+        chat_log_buffer.lines.push(format!(
+            "[{}] [{}] {} -> {}: {}",
+            timestamp,
+            log_chat_message.channel,
+            log_chat_message.sender,
+            recipients.join(", "),
+            log_chat_message.message
+        ));
+    }
+}
+
+pub fn flush_chat_log(mut chat_log_buffer: ResMut<ChatLogBuffer>, chat_filter: Res<ChatFilter>) {
+    if chat_log_buffer.lines.is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = chat_log_buffer.lines.drain(..).collect();
+
+    let logs_path = Path::new("logs");
+    match fs::create_dir_all(logs_path) {
+        Ok(_) => {}
+        Err(rr) => {
+            warn!("Couldn't create logs directory: {}", rr);
+            return;
+        }
+    }
+
+    let log_path = logs_path.join("chat.log");
+
+    match fs::metadata(&log_path) {
+        Ok(metadata) => {
+            if metadata.len() >= chat_filter.log_max_bytes {
+                rotate_chat_log(logs_path, chat_filter.log_max_files);
+            }
+        }
+        Err(_rr) => {}
+    }
+
+    match OpenOptions::new().create(true).append(true).open(&log_path) {
+        Ok(mut file) => {
+            for line in lines {
+                match writeln!(file, "{}", line) {
+                    Ok(_) => {}
+                    Err(rr) => {
+                        warn!("Couldn't write to chat.log: {}", rr);
+                        return;
+                    }
+                }
+            }
+        }
+        Err(rr) => {
+            warn!("Couldn't open chat.log: {}", rr);
+        }
+    }
+}
+
+pub fn initialize_console_commands(mut commands: ResMut<AllConsoleCommands>) {
+    commands.list.push((
+        "mute".to_string(),
+        "Mute a player in chat for the given amount of minutes, 0 mutes until unmuted.".to_string(),
+        vec![
+            ("player_selector".to_string(), ConsoleCommandVariant::String),
+            ("minutes".to_string(), ConsoleCommandVariant::Int),
+        ],
+    ));
+    commands.list.push((
+        "unmute".to_string(),
+        "Lift the chat mute of a player.".to_string(),
+        vec![("player_selector".to_string(), ConsoleCommandVariant::String)],
+    ));
+}
+
+pub fn mute_console_commands(
+    mut queue: EventReader<InputConsoleCommand>,
+    connected_players: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    mut used_names: ResMut<UsedNames>,
+    mut chat_mutes: ResMut<ChatMutes>,
+    time: Res<Time>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+    mut net_entity_console: EventWriter<NetEntityConsole>,
+) {
+    for console_command_event in queue.iter() {
+        if console_command_event.command_name != "mute"
+            && console_command_event.command_name != "unmute"
+        {
+            continue;
+        }
+
+        let handle;
+        match console_command_event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        match connected_players.get(console_command_event.entity) {
+            Ok((connected_player, _persistent_player_data)) => {
+                if !connected_player.rcon {
+                    net_console_commands.send(NetConsoleCommands {
+                        handle,
+                        message: ReliableServerMessage::ConsoleWriteLine(
+                            "[color=".to_string()
+                                + CONSOLE_ERROR_COLOR
+                                + "]RCON status denied.[/color]",
+                        ),
+                    });
+                    continue;
+                }
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let player_selector;
+        match console_command_event.command_arguments.get(0) {
+            Some(ConsoleCommandVariantValues::String(value)) => {
+                player_selector = value.clone();
+            }
+            _ => {
+                continue;
+            }
+        }
+
+        let mute_until = if console_command_event.command_name == "mute" {
+            match console_command_event.command_arguments.get(1) {
+                Some(ConsoleCommandVariantValues::Int(minutes)) => {
+                    if *minutes > 0 {
+                        Some(Some(time.seconds_since_startup() + *minutes as f64 * 60.))
+                    } else {
+                        Some(None)
+                    }
+                }
+                _ => {
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        for target_entity in player_selector_to_entities(
+            console_command_event.entity,
+            Some(handle),
+            &player_selector,
+            &mut used_names,
+            &mut net_entity_console,
+        ) {
+            let (target_connected_player, target_persistent_player_data) =
+                match connected_players.get(target_entity) {
+                    Ok(components) => components,
+                    Err(_rr) => {
+                        continue;
+                    }
+                };
+            let user_name = target_persistent_player_data.user_name.clone();
+
+            let reply;
+            let target_message;
+
+            match mute_until {
+                Some(until) => {
+                    chat_mutes.muted.insert(user_name.clone(), until);
+                    reply = format!("Muted {}.", user_name);
+                    target_message = "You have been muted by an administrator.";
+                }
+                None => {
+                    if chat_mutes.muted.remove(&user_name).is_none() {
+                        net_console_commands.send(NetConsoleCommands {
+                            handle,
+                            message: ReliableServerMessage::ConsoleWriteLine(
+                                "[color=".to_string()
+                                    + CONSOLE_ERROR_COLOR
+                                    + "]"
+                                    + &user_name
+                                    + " is not muted.[/color]",
+                            ),
+                        });
+                        continue;
+                    }
+                    reply = format!("Unmuted {}.", user_name);
+                    target_message = "You are no longer muted.";
+                }
+            }
+
+            net_console_commands.send(NetConsoleCommands {
+                handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string() + CONSOLE_SUCCESS_COLOR + "]" + &reply + "[/color]",
+                ),
+            });
+            if target_connected_player.connected {
+                net_console_commands.send(NetConsoleCommands {
+                    handle: target_connected_player.handle,
+                    message: ReliableServerMessage::ChatMessage(
+                        "[color=".to_string() + WARNING_COLOR + "]" + target_message + "[/color]",
+                    ),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::{ChatFilter, LinkPolicy};
+
+    fn filter(link_policy: LinkPolicy) -> ChatFilter {
+        ChatFilter {
+            blocklist: vec![Regex::new(r"(?i)\bkys\b").unwrap()],
+            replacements: vec![(Regex::new(r"(?i)\bsh+i+t+\w*").unwrap(), "****".to_string())],
+            link_policy,
+            allowed_link_domains: vec!["github.com".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn blocklist_blocks() {
+        assert!(filter(LinkPolicy::Allow).apply("just KYS already").is_err());
+        assert!(filter(LinkPolicy::Allow).apply("skys are blue").is_ok());
+    }
+
+    #[test]
+    fn replacements_replace() {
+        assert_eq!(
+            filter(LinkPolicy::Allow).apply("oh Shiiit, again"),
+            Ok("oh ****, again".to_string())
+        );
+    }
+
+    #[test]
+    fn allow_keeps_links() {
+        assert_eq!(
+            filter(LinkPolicy::Allow).apply("see https://example.com/page"),
+            Ok("see https://example.com/page".to_string())
+        );
+    }
+
+    #[test]
+    fn strip_removes_disallowed_links() {
+        assert_eq!(
+            filter(LinkPolicy::Strip).apply("see www.example.com and https://github.com/x"),
+            Ok("see [link removed] and https://github.com/x".to_string())
+        );
+    }
+
+    #[test]
+    fn strip_allows_subdomains() {
+        assert_eq!(
+            filter(LinkPolicy::Strip).apply("https://gist.github.com/x"),
+            Ok("https://gist.github.com/x".to_string())
+        );
+        assert_eq!(
+            filter(LinkPolicy::Strip).apply("https://notgithub.com/x"),
+            Ok("[link removed]".to_string())
+        );
+    }
+
+    #[test]
+    fn block_rejects_disallowed_links() {
+        assert!(filter(LinkPolicy::Block)
+            .apply("https://example.com")
+            .is_err());
+        assert!(filter(LinkPolicy::Block)
+            .apply("https://github.com")
+            .is_ok());
+    }
+}
@@ -5,6 +5,7 @@ use bevy::{
 use bevy_renet::renet::ServerEvent;
 use console_commands::{commands::AllConsoleCommands, rcon::GiveAllRCON};
 use humanoid::humanoid::{CharacterAnimationState, Humanoid};
+use jobs::jobs::{JobData, JobSlots};
 use map::map_input::MapData;
 use networking::messages::NetPlayerConn;
 use pawn::pawn::{ControllerInput, PersistentPlayerData, UsedNames};
@@ -14,7 +15,10 @@ use api::{
     pawn::PawnDesignation,
 };
 
-use crate::{connection_events::on_new_player_connection, health_ui::ClientHealthUICache};
+use crate::{
+    admin_help::AdminHelpTickets, connection_events::on_new_player_connection,
+    entity_update_batch::EntityUpdateIds, health_ui::ClientHealthUICache,
+};
 
 #[derive(Component)]
 pub struct Boarding;
@@ -29,7 +33,7 @@ pub struct OnBoard;
 pub struct SpawnPawnData {
     pub persistent_player_data: PersistentPlayerData,
     pub connected_player_option: Option<ConnectedPlayer>,
-    pub inventory_setup: Vec<(String, String)>,
+    pub job: JobData,
     pub designation: PawnDesignation,
 }
 
@@ -53,6 +57,9 @@ pub fn connections(
     map_data: Res<MapData>,
     console_commands: Res<AllConsoleCommands>,
     give_all_rcon: Res<GiveAllRCON>,
+    mut entity_update_ids: ResMut<EntityUpdateIds>,
+    mut job_slots: ResMut<JobSlots>,
+    mut admin_help_tickets: ResMut<AdminHelpTickets>,
 ) {
     for event in reader.iter() {
         match event {
@@ -82,6 +89,9 @@ pub fn connections(
                     &mut used_names,
                     &mut client_health_ui_cache,
                 );
+                entity_update_ids.remove_handle(*handle);
+                job_slots.release(*handle);
+                admin_help_tickets.close_for_handle(*handle);
             }
         }
     }
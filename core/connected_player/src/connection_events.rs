@@ -178,7 +178,7 @@ pub fn on_new_player_connection(
         )),
     });
 
-    for add in map_data.to_net() {
+    for add in map_data.to_net(0) {
         net_on_new_player_connection.send(NetPlayerConn {
             handle: *handle,
             message: ReliableServerMessage::MapDefaultAddition(add.0, add.1, add.2),
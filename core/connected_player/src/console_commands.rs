@@ -59,6 +59,10 @@ pub fn entity_console_commands(
             }
         }
 
+        if console_command_event.command_name != "spawn" {
+            continue;
+        }
+
         if player_entity.rcon == false {
             match console_command_event.handle_option {
                 Some(t) => {
@@ -271,6 +275,10 @@ pub fn inventory_item_console_commands(
             }
         }
 
+        if console_command_event.command_name != "spawnHeld" {
+            continue;
+        }
+
         if player_entity.rcon == false {
             match console_command_event.handle_option {
                 Some(t) => {
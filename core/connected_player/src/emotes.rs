@@ -0,0 +1,357 @@
+use std::{collections::HashMap, fs, path::Path, sync::Arc};
+
+use bevy::{
+    core::Timer,
+    prelude::{
+        info, warn, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, Transform,
+    },
+};
+use humanoid::humanoid::{CharacterAnimationState, Humanoid};
+use pawn::pawn::{Pawn, PersistentPlayerData};
+use serde::Deserialize;
+use sfx::builder::sfx_builder;
+use api::{
+    chat::{EMOTE_CHATPREFIX, TALK_SPACE_PROXIMITY_EMOTE_CHATPREFIX, WARNING_COLOR},
+    data::{ConnectedPlayer, HandleToEntity},
+    entity_updates::NetSendEntityUpdates,
+    sensable::Sensable,
+    tab_actions::{QueuedTabActions, TabAction},
+};
+use sounds::emotes::{cry1_sfx::Cry1SfxBundle, salute1_sfx::Salute1SfxBundle};
+use tab_actions::tab_action::{emote_tab_prerequisite_check, EMOTE_TARGET_DISTANCE};
+
+use crate::{
+    admin_help::{colored, send_chat, split_first_argument},
+    chat::{new_chat_message, Communicator, MessagingPlayerState, NetChatMessage, Radio},
+    chat_filter::{FilteredChatMessage, LogChatMessage},
+    language::{KnownLanguages, Languages},
+    radio::RadioChannels,
+};
+
+#[derive(Deserialize, Clone)]
+pub struct EmoteData {
+    pub id: String,
+    // Text of the tab action on other humanoids, emotes without one can't be aimed with tab.
+    pub tab_text: Option<String>,
+    // Third person text, said with the name of the emoting pawn in front of it.
+    pub text: String,
+    // Used instead of text when there is a target, {target} is replaced with its name.
+    pub target_text: Option<String>,
+    // Upper body state of the humanoid animation tree.
+    pub animation: Option<String>,
+    pub animation_duration: f32,
+    pub sound: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Emotes {
+    pub list: Vec<EmoteData>,
+}
+
+impl Emotes {
+    pub fn get(&self, id: &str) -> Option<&EmoteData> {
+        self.list.iter().find(|emote| emote.id == id)
+    }
+
+    pub fn tab_actions(&self) -> Vec<(String, TabAction)> {
+        let mut tab_actions = vec![];
+        for emote in self.list.iter() {
+            match &emote.tab_text {
+                Some(tab_text) => {
+                    let id = emote_tab_id(&emote.id);
+                    tab_actions.push((
+                        id.clone(),
+                        TabAction {
+                            id,
+                            text: tab_text.clone(),
+                            tab_list_priority: 50,
+                            prerequisite_check: Arc::new(emote_tab_prerequisite_check),
+                            belonging_entity: None,
+                        },
+                    ));
+                }
+                None => {}
+            }
+        }
+        tab_actions
+    }
+}
+
+// Emote sounds by the name emotes.json refers to them with.
+pub struct EmoteSounds {
+    pub builders: HashMap<String, fn(&mut Commands) -> Entity>,
+}
+
+impl Default for EmoteSounds {
+    fn default() -> Self {
+        let mut builders: HashMap<String, fn(&mut Commands) -> Entity> = HashMap::new();
+        builders.insert("cry1".to_string(), Cry1SfxBundle::new);
+        builders.insert("salute1".to_string(), Salute1SfxBundle::new);
+        Self { builders }
+    }
+}
+
+const EMOTE_TAB_ID_PREFIX: &str = "actions::pawn/emote/";
+
+pub fn emote_tab_id(emote_id: &str) -> String {
+    EMOTE_TAB_ID_PREFIX.to_string() + emote_id
+}
+
+pub struct InputEmote {
+    pub entity: Entity,
+    pub emote_id: String,
+    pub target_entity_option: Option<Entity>,
+}
+
+pub fn startup_emotes(mut emotes: ResMut<Emotes>, emote_sounds: Res<EmoteSounds>) {
+    let emotes_json = Path::new("data").join("emotes").join("emotes.json");
+    let emotes_raw_json: String = fs::read_to_string(emotes_json)
+        .expect("startup_emotes() Error reading emotes.json from drive.");
+    emotes.list = serde_json::from_str(&emotes_raw_json)
+        .expect("startup_emotes() Error parsing emotes.json String.");
+
+    for emote in emotes.list.iter() {
+        match &emote.sound {
+            Some(sound) => {
+                if !emote_sounds.builders.contains_key(sound) {
+                    panic!(
+                        "startup_emotes() Emote {} has unknown sound {}.",
+                        emote.id, sound
+                    );
+                }
+            }
+            None => {}
+        }
+    }
+
+    info!("Loaded {} emotes.", emotes.list.len());
+}
+
+fn emote_usage(emotes: &Emotes) -> String {
+    let ids: Vec<&str> = emotes.list.iter().map(|emote| emote.id.as_str()).collect();
+    "Usage: /emote <name> [target]. Available emotes: ".to_string() + &ids.join(", ") + "."
+}
+
+pub fn emote_chat_commands(
+    mut chat_message_input_events: EventReader<FilteredChatMessage>,
+    emotes: Res<Emotes>,
+    connected_players: Query<&ConnectedPlayer>,
+    pawns: Query<(Entity, &Pawn, &Transform)>,
+    mut net_chat_message: EventWriter<NetChatMessage>,
+    mut input_emote: EventWriter<InputEmote>,
+) {
+    for chat_message_input_event in chat_message_input_events.iter() {
+        let (command, arguments) = split_first_argument(&chat_message_input_event.message);
+        if command != EMOTE_CHATPREFIX {
+            continue;
+        }
+
+        let handle = match connected_players.get(chat_message_input_event.entity) {
+            Ok(connected_player) => connected_player.handle,
+            Err(_rr) => {
+                continue;
+            }
+        };
+
+        let (emote_id, target_name) = split_first_argument(&arguments);
+        let emote_id = emote_id.to_lowercase();
+
+        let emote = match emotes.get(&emote_id) {
+            Some(emote) => emote,
+            None => {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(WARNING_COLOR, &emote_usage(&emotes)),
+                );
+                continue;
+            }
+        };
+
+        let emoter_position = match pawns.get(chat_message_input_event.entity) {
+            Ok((_entity, _pawn, transform)) => transform.translation,
+            Err(_rr) => {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(WARNING_COLOR, "You need a body to emote."),
+                );
+                continue;
+            }
+        };
+
+        if !target_name.is_empty() && emote.target_text.is_none() {
+            send_chat(
+                &mut net_chat_message,
+                handle,
+                colored(
+                    WARNING_COLOR,
+                    &("The ".to_string() + &emote_id + " emote doesn't take a target."),
+                ),
+            );
+            continue;
+        }
+
+        let mut target_entity_option = None;
+
+        if !target_name.is_empty() {
+            let target_name = target_name
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_lowercase();
+
+            for (entity, pawn, transform) in pawns.iter() {
+                if entity == chat_message_input_event.entity
+                    || transform.translation.distance(emoter_position) > EMOTE_TARGET_DISTANCE
+                {
+                    continue;
+                }
+                if pawn.name.to_lowercase().starts_with(&target_name) {
+                    target_entity_option = Some(entity);
+                    break;
+                }
+            }
+
+            if target_entity_option.is_none() {
+                send_chat(
+                    &mut net_chat_message,
+                    handle,
+                    colored(
+                        WARNING_COLOR,
+                        &("There is nobody called ".to_string() + &target_name + " nearby."),
+                    ),
+                );
+                continue;
+            }
+        }
+
+        input_emote.send(InputEmote {
+            entity: chat_message_input_event.entity,
+            emote_id,
+            target_entity_option,
+        });
+    }
+}
+
+pub fn emote_tab_actions(queue: Res<QueuedTabActions>, mut input_emote: EventWriter<InputEmote>) {
+    for queued in queue.queue.iter() {
+        if !queued.tab_id.starts_with(EMOTE_TAB_ID_PREFIX) {
+            continue;
+        }
+
+        input_emote.send(InputEmote {
+            entity: queued.player_entity,
+            emote_id: queued.tab_id[EMOTE_TAB_ID_PREFIX.len()..].to_string(),
+            target_entity_option: queued.target_entity_option.map(Entity::from_bits),
+        });
+    }
+}
+
+pub fn emote_events(
+    mut input_emote: EventReader<InputEmote>,
+    emotes: Res<Emotes>,
+    mut emoters: Query<(&Pawn, &Transform, &Sensable, Option<&mut Humanoid>)>,
+    handle_to_entity: Res<HandleToEntity>,
+    radio_pawns: Query<(Entity, &Radio, &Transform, &PersistentPlayerData)>,
+    mut net_new_chat_message_event: EventWriter<NetChatMessage>,
+    mut net_send_entity_updates: EventWriter<NetSendEntityUpdates>,
+    global_listeners: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    radio_channels: Res<RadioChannels>,
+    mut log_chat_message: EventWriter<LogChatMessage>,
+    languages: Res<Languages>,
+    known_languages: Query<&KnownLanguages>,
+    emote_sounds: Res<EmoteSounds>,
+    mut commands: Commands,
+) {
+    for event in input_emote.iter() {
+        let emote = match emotes.get(&event.emote_id) {
+            Some(emote) => emote,
+            None => {
+                warn!("Received unknown emote {}.", event.emote_id);
+                continue;
+            }
+        };
+
+        let mut target_name_option = None;
+
+        match event.target_entity_option {
+            Some(target_entity) => {
+                if target_entity != event.entity {
+                    match emoters.get(target_entity) {
+                        Ok((target_pawn, _transform, _sensable, _humanoid_option)) => {
+                            target_name_option = Some(target_pawn.name.clone());
+                        }
+                        Err(_rr) => {}
+                    }
+                }
+            }
+            None => {}
+        }
+
+        let text = match (&emote.target_text, target_name_option) {
+            (Some(target_text), Some(target_name)) => target_text.replace("{target}", &target_name),
+            _ => emote.text.clone(),
+        };
+
+        let (pawn_component, transform, sensable_component, humanoid_option) =
+            match emoters.get_mut(event.entity) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
+        new_chat_message(
+            &mut net_new_chat_message_event,
+            &handle_to_entity,
+            &sensable_component.sensed_by,
+            &sensable_component.sensed_by_cached,
+            transform.translation,
+            pawn_component.name.clone(),
+            &pawn_component.job,
+            TALK_SPACE_PROXIMITY_EMOTE_CHATPREFIX.to_string() + " " + &text,
+            Communicator::Standard,
+            false,
+            &radio_pawns,
+            &radio_channels,
+            &languages,
+            &known_languages,
+            &global_listeners,
+            &mut log_chat_message,
+            Some(&event.entity),
+            Some(&mut net_send_entity_updates),
+            &MessagingPlayerState::Alive,
+        );
+
+        // Emote animations only play while standing still outside of combat mode.
+        match (&emote.animation, humanoid_option) {
+            (Some(animation), Some(mut humanoid_component)) => {
+                if !humanoid_component.combat_mode
+                    && !matches!(
+                        humanoid_component.current_lower_animation_state,
+                        CharacterAnimationState::Jogging
+                            | CharacterAnimationState::Sprinting
+                            | CharacterAnimationState::KnockedDown
+                    )
+                {
+                    humanoid_component.current_lower_animation_state =
+                        CharacterAnimationState::Emoting(animation.clone());
+                    humanoid_component.emote_timer =
+                        Timer::from_seconds(emote.animation_duration, false);
+                }
+            }
+            _ => {}
+        }
+
+        // Sounds were checked against EmoteSounds when the emotes got loaded.
+        match emote
+            .sound
+            .as_ref()
+            .and_then(|sound| emote_sounds.builders.get(sound))
+        {
+            Some(builder) => {
+                sfx_builder(&mut commands, *transform, Box::new(*builder));
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use bevy::prelude::{warn, EventWriter, ResMut};
+use bincode::serialize;
+use lz4_flex::compress_prepend_size;
+use api::{
+    entity_updates::{EntityUpdateData, NetSendEntityUpdates},
+    network::{EntityWorldType, ReliableServerMessage, ServerConfigMessage},
+};
+
+// Node paths and parameter names get sent once as ids, entity updates then only carry the ids.
+#[derive(Default)]
+pub struct EntityUpdateIds {
+    ids: HashMap<String, u16>,
+    names: Vec<String>,
+    // How many ids each client handle has received so far.
+    synced: HashMap<u64, usize>,
+    exhausted: bool,
+}
+
+impl EntityUpdateIds {
+    // None once every u16 id is taken, ids are never freed while the server runs.
+    pub fn intern(&mut self, name: &str) -> Option<u16> {
+        match self.ids.get(name) {
+            Some(id) => Some(*id),
+            None => {
+                if self.names.len() > u16::MAX as usize {
+                    if !self.exhausted {
+                        warn!(
+                            "Out of entity update ids, sending updates with new names unbatched."
+                        );
+                        self.exhausted = true;
+                    }
+                    return None;
+                }
+
+                let id = self.names.len() as u16;
+                self.ids.insert(name.to_string(), id);
+                self.names.push(name.to_string());
+                Some(id)
+            }
+        }
+    }
+
+    // The ids this client has not received yet, starting at the first missing id.
+    pub fn get_unsynced(&mut self, handle: u64) -> Option<(u16, Vec<String>)> {
+        let synced = self.synced.entry(handle).or_insert(0);
+
+        if *synced >= self.names.len() {
+            return None;
+        }
+
+        let start = *synced;
+        *synced = self.names.len();
+
+        Some((start as u16, self.names[start..].to_vec()))
+    }
+
+    pub fn remove_handle(&mut self, handle: u64) {
+        self.synced.remove(&handle);
+    }
+}
+
+pub struct EntityUpdateBatching {
+    // Per client handle, the entity bits with their updates this tick.
+    pub batches: HashMap<u64, Vec<(u64, HashMap<String, HashMap<String, EntityUpdateData>>)>>,
+    pub compression: bool,
+    // Batches smaller than this many bytes are not worth compressing.
+    pub compression_threshold: usize,
+}
+
+impl Default for EntityUpdateBatching {
+    fn default() -> Self {
+        Self {
+            batches: HashMap::new(),
+            compression: true,
+            compression_threshold: 256,
+        }
+    }
+}
+
+impl EntityUpdateBatching {
+    pub fn add(
+        &mut self,
+        handle: u64,
+        entity_bits: u64,
+        updates: HashMap<String, HashMap<String, EntityUpdateData>>,
+    ) {
+        self.batches
+            .entry(handle)
+            .or_default()
+            .push((entity_bits, updates));
+    }
+}
+
+pub type EntityUpdateBatch = Vec<(u64, Vec<(u16, Vec<(u16, EntityUpdateData)>)>)>;
+
+// Also returns the updates whose names could not get an id, those go out unbatched.
+pub fn intern_entity_updates(
+    entity_update_ids: &mut EntityUpdateIds,
+    entity_updates: Vec<(u64, HashMap<String, HashMap<String, EntityUpdateData>>)>,
+) -> (
+    EntityUpdateBatch,
+    Vec<(u64, HashMap<String, HashMap<String, EntityUpdateData>>)>,
+) {
+    let mut batch = vec![];
+    let mut unbatched = vec![];
+
+    for (entity_bits, updates) in entity_updates {
+        let mut nodes = vec![];
+        let mut uninterned_nodes: HashMap<String, HashMap<String, EntityUpdateData>> =
+            HashMap::new();
+
+        for (node_path, parameters) in updates {
+            let node_id;
+
+            match entity_update_ids.intern(&node_path) {
+                Some(id) => {
+                    node_id = id;
+                }
+                None => {
+                    uninterned_nodes.insert(node_path, parameters);
+                    continue;
+                }
+            }
+
+            let mut interned_parameters = vec![];
+
+            for (parameter, data) in parameters {
+                match entity_update_ids.intern(&parameter) {
+                    Some(parameter_id) => {
+                        interned_parameters.push((parameter_id, data));
+                    }
+                    None => {
+                        uninterned_nodes
+                            .entry(node_path.clone())
+                            .or_default()
+                            .insert(parameter, data);
+                    }
+                }
+            }
+
+            nodes.push((node_id, interned_parameters));
+        }
+
+        batch.push((entity_bits, nodes));
+
+        if !uninterned_nodes.is_empty() {
+            unbatched.push((entity_bits, uninterned_nodes));
+        }
+    }
+
+    (batch, unbatched)
+}
+
+pub fn encode_entity_update_batch(
+    batch: EntityUpdateBatch,
+    compression: bool,
+    compression_threshold: usize,
+) -> ReliableServerMessage {
+    if compression {
+        match serialize(&batch) {
+            Ok(bytes) => {
+                if bytes.len() >= compression_threshold {
+                    let compressed = compress_prepend_size(&bytes);
+
+                    // Batches of mostly unique strings can come out larger.
+                    if compressed.len() < bytes.len() {
+                        return ReliableServerMessage::CompressedEntityUpdateBatch(compressed);
+                    }
+                }
+            }
+            Err(_rr) => {
+                warn!("Couldn't serialize entity update batch.");
+            }
+        }
+    }
+
+    ReliableServerMessage::EntityUpdateBatch(batch)
+}
+
+pub fn send_entity_update_batches(
+    mut entity_update_batching: ResMut<EntityUpdateBatching>,
+    mut entity_update_ids: ResMut<EntityUpdateIds>,
+    mut net_send_entity_updates: EventWriter<NetSendEntityUpdates>,
+) {
+    let compression = entity_update_batching.compression;
+    let compression_threshold = entity_update_batching.compression_threshold;
+
+    for (handle, entity_updates) in entity_update_batching.batches.drain() {
+        let (batch, unbatched) = intern_entity_updates(&mut entity_update_ids, entity_updates);
+
+        // New ids go out before the batch that uses them, over the same reliable channel.
+        match entity_update_ids.get_unsynced(handle) {
+            Some((start_id, names)) => {
+                net_send_entity_updates.send(NetSendEntityUpdates {
+                    handle,
+                    message: ReliableServerMessage::ConfigMessage(
+                        ServerConfigMessage::EntityUpdateIds(start_id, names),
+                    ),
+                });
+            }
+            None => {}
+        }
+
+        net_send_entity_updates.send(NetSendEntityUpdates {
+            handle,
+            message: encode_entity_update_batch(batch, compression, compression_threshold),
+        });
+
+        for (entity_bits, updates) in unbatched {
+            net_send_entity_updates.send(NetSendEntityUpdates {
+                handle,
+                message: ReliableServerMessage::EntityUpdate(
+                    entity_bits,
+                    updates,
+                    false,
+                    EntityWorldType::Main,
+                ),
+            });
+        }
+    }
+}
@@ -6,10 +6,7 @@ use api::{
     },
     data::HandleToEntity,
     examinable::Examinable,
-    gridmap::{
-        to_doryen_coordinates, GridMapType, GridmapData, GridmapDetails1, GridmapExamineMessages,
-        GridmapMain,
-    },
+    gridmap::{GridMapType, GridmapData, GridmapDetails1, GridmapExamineMessages, GridmapMain},
     health::{Health, HealthContainer},
     network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage},
     sensable::Sensable,
@@ -54,11 +51,7 @@ pub fn examine_map(
 
         let mut examine_text;
 
-        let coords = to_doryen_coordinates(
-            examine_event.gridmap_cell_id.x,
-            examine_event.gridmap_cell_id.z,
-        );
-        if !examiner_senser_component.fov.is_in_fov(coords.0, coords.1) {
+        if !examiner_senser_component.is_cell_in_fov(&examine_event.gridmap_cell_id) {
             examine_text = get_empty_cell_message();
         } else {
             let gridmap_type = &examine_event.gridmap_type;
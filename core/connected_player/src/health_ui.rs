@@ -508,6 +508,7 @@ pub fn health_ui_update(
                     }
                 }
 
+                // Batches only carry Main world updates, this one targets the HealthUI world.
                 if new_update && connected_player_component.connected {
                     net_health_update.send(NetHealthUpdate {
                         handle: connected_player_component.handle,
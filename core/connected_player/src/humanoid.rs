@@ -5,9 +5,7 @@ use bevy::{
     prelude::{Changed, Entity, Query},
 };
 use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Dominance, Friction, LockedAxes};
-use entity::entity_data::{
-    CONSTRUCTION_TOOL_ENTITY_NAME, ENTITY_SPAWN_PARENT, HELMET_SECURITY_ENTITY_NAME,
-};
+use entity::entity_data::ENTITY_SPAWN_PARENT;
 use humanoid::humanoid::{CharacterAnimationState, Humanoid};
 use inventory_item::item::InventoryItem;
 use map::map::Map;
@@ -20,9 +18,8 @@ use rigid_body::spawn::{RigidBodyBundle, RigidBodySummonable};
 use api::{
     combat::{CombatAttackAnimation, CombatStandardAnimation},
     data::{
-        ConnectedPlayer, EntityDataResource, HandleToEntity, NoData, Showcase,
-        HUMAN_DUMMY_ENTITY_NAME, HUMAN_MALE_ENTITY_NAME, JUMPSUIT_SECURITY_ENTITY_NAME,
-        PISTOL_L1_ENTITY_NAME,
+        ConnectedPlayer, EntityDataResource, NoData, Showcase, HUMAN_DUMMY_ENTITY_NAME,
+        HUMAN_MALE_ENTITY_NAME,
     },
     data_link::{DataLink, DataLinkType},
     entity_updates::{get_entity_update_difference, EntityUpdateData, EntityUpdates},
@@ -30,8 +27,8 @@ use api::{
     get_spawn_position::FacingDirection,
     health::{Health, HealthContainer, HumanoidHealth},
     inventory::{Inventory, Slot, SlotType},
-    network::{ReliableServerMessage, ServerConfigMessage},
-    pawn::{PawnDesignation, Spawning},
+    network::ReliableServerMessage,
+    pawn::PawnDesignation,
     senser::Senser,
 };
 use tab_actions::tab_action::get_tab_action;
@@ -169,6 +166,15 @@ pub fn humanoid_update(
                         lower_body_animation_state = "Idle".to_string();
                     }
                 },
+                CharacterAnimationState::KnockedDown => {
+                    upper_body_animation_state = "Knocked Down".to_string();
+                    lower_body_animation_state = "Knocked Down".to_string();
+                }
+                // Emotes are cut short by combat mode, see humanoids.
+                CharacterAnimationState::Emoting(_) => {
+                    upper_body_animation_state = "Idle Heightened".to_string();
+                    lower_body_animation_state = "Idle".to_string();
+                }
                 CharacterAnimationState::Jogging => {
                     // Get active item in hand and check its AnimationEnum type. If StandardMelee its JoggingStrafe, if Pistol it's PistolStrafe.
 
@@ -330,6 +336,14 @@ pub fn humanoid_update(
                     lower_body_animation_state = "Sprinting".to_string();
                     upper_body_animation_state = "Idle Heightened".to_string();
                 }
+                CharacterAnimationState::KnockedDown => {
+                    lower_body_animation_state = "Knocked Down".to_string();
+                    upper_body_animation_state = "Knocked Down".to_string();
+                }
+                CharacterAnimationState::Emoting(ref animation) => {
+                    lower_body_animation_state = "Idle".to_string();
+                    upper_body_animation_state = animation.clone();
+                }
             }
 
             animation_tree1_upper_blend
@@ -415,90 +429,9 @@ pub fn humanoid_update(
     }
 }
 
-use bevy::prelude::{Added, Commands, EventWriter, ResMut};
+use bevy::prelude::{Commands, EventWriter, ResMut};
 use inventory_item::spawn::spawn_held_entity;
 
-pub struct NetHuman {
-    pub handle: u64,
-    pub message: ReliableServerMessage,
-}
-
-pub fn on_spawning(
-    mut net_on_new_player_connection: EventWriter<NetHuman>,
-    query: Query<(Entity, &Spawning, &ConnectedPlayer, &PersistentPlayerData), Added<Spawning>>,
-    mut commands: Commands,
-    mut handle_to_entity: ResMut<HandleToEntity>,
-    mut used_names: ResMut<UsedNames>,
-    mut summon_human_male: EventWriter<SpawnEvent<HumanMaleSummoner>>,
-) {
-    for (
-        entity_id,
-        spawning_component,
-        connected_player_component,
-        persistent_player_data_component,
-    ) in query.iter()
-    {
-        let passed_inventory_setup = vec![
-            (
-                "jumpsuit".to_string(),
-                JUMPSUIT_SECURITY_ENTITY_NAME.to_string(),
-            ),
-            (
-                "helmet".to_string(),
-                HELMET_SECURITY_ENTITY_NAME.to_string(),
-            ),
-            ("holster".to_string(), PISTOL_L1_ENTITY_NAME.to_string()),
-            (
-                "left_hand".to_string(),
-                CONSTRUCTION_TOOL_ENTITY_NAME.to_string(),
-            ),
-        ];
-
-        let new_entity = commands.spawn().id();
-
-        summon_human_male.send(SpawnEvent {
-            spawn_data: SpawnData {
-                entity: new_entity,
-                entity_transform: spawning_component.transform,
-                entity_name: HUMAN_MALE_ENTITY_NAME.to_string(),
-                ..Default::default()
-            },
-            summoner: HumanMaleSummoner {
-                character_name: persistent_player_data_component.character_name.clone(),
-                user_name: persistent_player_data_component.user_name.clone(),
-                spawn_pawn_data: SpawnPawnData {
-                    persistent_player_data: persistent_player_data_component.clone(),
-                    connected_player_option: Some(connected_player_component.clone()),
-                    inventory_setup: passed_inventory_setup,
-                    designation: PawnDesignation::Player,
-                },
-            },
-        });
-
-        let handle = *handle_to_entity.inv_map.get(&entity_id).unwrap();
-
-        handle_to_entity.inv_map.remove(&entity_id);
-        handle_to_entity.inv_map.insert(new_entity, handle);
-
-        handle_to_entity.map.remove(&handle);
-        handle_to_entity.map.insert(handle, new_entity);
-
-        used_names.names.insert(
-            persistent_player_data_component.character_name.clone(),
-            new_entity,
-        );
-
-        commands.entity(entity_id).despawn();
-
-        net_on_new_player_connection.send(NetHuman {
-            handle: handle,
-            message: ReliableServerMessage::ConfigMessage(ServerConfigMessage::EntityId(
-                new_entity.to_bits(),
-            )),
-        });
-    }
-}
-
 use std::collections::BTreeMap;
 
 pub fn get_default_transform() -> Transform {
@@ -608,7 +541,7 @@ pub fn summon_base_human_male<
 }
 use bevy::{
     math::Vec3,
-    prelude::{EventReader, Transform},
+    prelude::{EventReader, Res, Transform},
 };
 use entity::{
     entity_data::NetShowcase,
@@ -617,11 +550,15 @@ use entity::{
         DefaultSpawnEvent, SpawnData, SpawnEvent,
     },
 };
-use pawn::pawn::{ShipAuthorization, ShipAuthorizationEnum, ShipJobsEnum};
+use jobs::jobs::{Jobs, DUMMY_JOB_ID};
+use pawn::pawn::ShipAuthorization;
 
 use crate::{
-    chat::{Radio, RadioChannel},
+    chat::Radio,
     connection::SpawnPawnData,
+    emotes::Emotes,
+    language::{KnownLanguages, Languages},
+    radio::Headset,
 };
 
 pub struct HumanMaleSummoner {
@@ -673,6 +610,8 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
     mut spawn_events: EventReader<SpawnEvent<T>>,
     mut default_spawner: EventWriter<DefaultSpawnEvent>,
     entity_data: ResMut<EntityDataResource>,
+    languages: Res<Languages>,
+    emotes: Res<Emotes>,
 ) {
     for spawn_event in spawn_events.iter() {
         let mut spawner = commands.entity(spawn_event.spawn_data.entity);
@@ -682,7 +621,7 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
         if spawn_event.spawn_data.showcase_data_option.is_none() {
             let mut pawn_component = Pawn {
                 name: spawn_event.summoner.get_character_name().clone(),
-                job: ShipJobsEnum::Security,
+                job: spawn_pawn_data.job.get_ship_job(),
                 ..Default::default()
             };
 
@@ -701,15 +640,24 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
                 None,
                 get_tab_action("actions::inventory/pickup").unwrap(),
             );
+            for (tab_action_id, tab_action) in emotes.tab_actions() {
+                pawn_component.tab_actions_add(&tab_action_id, None, tab_action);
+            }
 
             spawner.insert_bundle((
                 Senser::default(),
+                // Channels are granted by the worn headset, see radio_from_equipment.
                 Radio {
-                    listen_access: vec![RadioChannel::Common, RadioChannel::Security],
-                    speak_access: vec![RadioChannel::Common, RadioChannel::Security],
+                    listen_access: vec![],
+                    speak_access: vec![],
                 },
-                ShipAuthorization {
-                    access: vec![ShipAuthorizationEnum::Security],
+                KnownLanguages {
+                    known: languages.known_by_job(&spawn_pawn_data.job),
+                    default: languages
+                        .known_by_default()
+                        .first()
+                        .cloned()
+                        .unwrap_or_default(),
                 },
                 pawn_component,
                 ControllerInput::default(),
@@ -741,6 +689,10 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
                                     "Atmospherics Pressure".to_string(),
                                     "atmospherics_pressure".to_string(),
                                 ),
+                                (
+                                    "Atmospherics Fire".to_string(),
+                                    "atmospherics_fire".to_string(),
+                                ),
                             ],
                             ..Default::default()
                         },
@@ -771,7 +723,7 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
 
         let mut slot_entities: HashMap<String, Entity> = HashMap::new();
 
-        for (slot_name, item_name) in spawn_pawn_data.inventory_setup.iter() {
+        for (slot_name, item_name) in spawn_pawn_data.job.inventory.iter() {
             let entity_option;
 
             entity_option = spawn_held_entity(
@@ -791,6 +743,24 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
             }
         }
 
+        // The job's access is carried by its ID card, not by the pawn.
+        match slot_entities.get(&"id".to_string()) {
+            Some(entity) => {
+                commands.entity(*entity).insert(ShipAuthorization {
+                    access: spawn_pawn_data.job.access.clone(),
+                });
+            }
+            None => {}
+        }
+        match slot_entities.get(&"headset".to_string()) {
+            Some(entity) => {
+                commands.entity(*entity).insert(Headset {
+                    channels: spawn_pawn_data.job.radio_channels.clone(),
+                });
+            }
+            None => {}
+        }
+
         let mut spawner = commands.entity(spawn_event.spawn_data.entity);
 
         let left_hand_item;
@@ -829,6 +799,24 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
                 jumpsuit_hand_item = None;
             }
         }
+        let id_item;
+        match slot_entities.get(&"id".to_string()) {
+            Some(entity) => {
+                id_item = Some(*entity);
+            }
+            None => {
+                id_item = None;
+            }
+        }
+        let headset_item;
+        match slot_entities.get(&"headset".to_string()) {
+            Some(entity) => {
+                headset_item = Some(*entity);
+            }
+            None => {
+                headset_item = None;
+            }
+        }
         let holster_hand_item;
         match slot_entities.get(&"holster".to_string()) {
             Some(entity) => {
@@ -879,6 +867,18 @@ pub fn summon_human_male<T: HumanMaleSummonable + Send + Sync + 'static>(
                         "Smoothing/pawn/humanMale/rig/holster/Position3D".to_string(),
                     ),
                 },
+                Slot {
+                    slot_type: SlotType::Id,
+                    slot_name: "id".to_string(),
+                    slot_item: id_item,
+                    slot_attachment: None,
+                },
+                Slot {
+                    slot_type: SlotType::Headset,
+                    slot_name: "headset".to_string(),
+                    slot_item: headset_item,
+                    slot_attachment: None,
+                },
             ],
             active_slot: "left_hand".to_string(),
             ..Default::default()
@@ -890,9 +890,21 @@ pub fn default_human_dummy(
     mut default_spawner: EventReader<DefaultSpawnEvent>,
     mut spawner: EventWriter<SpawnEvent<HumanMaleSummoner>>,
     mut used_names: ResMut<UsedNames>,
+    jobs: Res<Jobs>,
 ) {
     for spawn_event in default_spawner.iter() {
         if spawn_event.spawn_data.entity_name == HUMAN_DUMMY_ENTITY_NAME {
+            let dummy_job;
+
+            match jobs.get(DUMMY_JOB_ID) {
+                Some(job) => {
+                    dummy_job = job.clone();
+                }
+                None => {
+                    dummy_job = jobs.get_default().clone();
+                }
+            }
+
             spawner.send(SpawnEvent {
                 spawn_data: spawn_event.spawn_data.clone(),
                 summoner: HumanMaleSummoner {
@@ -901,16 +913,7 @@ pub fn default_human_dummy(
                     spawn_pawn_data: SpawnPawnData {
                         persistent_player_data: PersistentPlayerData::default(),
                         connected_player_option: None,
-                        inventory_setup: vec![
-                            (
-                                "jumpsuit".to_string(),
-                                JUMPSUIT_SECURITY_ENTITY_NAME.to_string(),
-                            ),
-                            (
-                                "helmet".to_string(),
-                                HELMET_SECURITY_ENTITY_NAME.to_string(),
-                            ),
-                        ],
+                        job: dummy_job,
                         designation: PawnDesignation::Dummy,
                     },
                 },
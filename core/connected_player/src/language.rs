@@ -0,0 +1,196 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+use bevy::prelude::{info, warn, Component, ResMut};
+use jobs::jobs::JobData;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone)]
+pub struct LanguageData {
+    pub id: String,
+    pub name: String,
+    // Typed after the talk space prefix, ";,s hello" speaks Sol Creole over the common channel.
+    pub prefix: String,
+    pub syllables: Vec<String>,
+    pub known_by_default: bool,
+}
+
+#[derive(Default)]
+pub struct Languages {
+    pub list: Vec<LanguageData>,
+}
+
+impl Languages {
+    pub fn get(&self, id: &str) -> Option<&LanguageData> {
+        self.list.iter().find(|language| language.id == id)
+    }
+
+    pub fn from_message(&self, message: &str) -> Option<&LanguageData> {
+        self.list
+            .iter()
+            .filter(|language| message.starts_with(&language.prefix))
+            .max_by_key(|language| language.prefix.len())
+    }
+
+    pub fn known_by_default(&self) -> Vec<String> {
+        self.list
+            .iter()
+            .filter(|language| language.known_by_default)
+            .map(|language| language.id.clone())
+            .collect()
+    }
+
+    // Languages nobody knows by default come with the job.
+    pub fn known_by_job(&self, job: &JobData) -> Vec<String> {
+        let mut known = self.known_by_default();
+        for language_id in job.languages.iter() {
+            if self.get(language_id).is_none() {
+                warn!("Job {} speaks unknown language {}.", job.id, language_id);
+                continue;
+            }
+            if !known.contains(language_id) {
+                known.push(language_id.clone());
+            }
+        }
+        known
+    }
+}
+
+#[derive(Component)]
+pub struct KnownLanguages {
+    pub known: Vec<String>,
+    // Spoken when the message has no language prefix.
+    pub default: String,
+}
+
+// Stands in for the spoken words while the chat message is built, so every listener can
+// receive their own version. Control characters never survive escape_bb.
+pub const SPOKEN_MESSAGE_PLACEHOLDER: &str = "\u{1}";
+
+// Proximity speech without line of sight is still heard up to this distance, muffled.
+pub const MUFFLED_HEAR_DISTANCE: f32 = 8.;
+
+pub fn startup_languages(mut languages: ResMut<Languages>) {
+    let languages_json = Path::new("data").join("languages").join("languages.json");
+    let languages_raw_json: String = fs::read_to_string(languages_json)
+        .expect("startup_languages() Error reading languages.json from drive.");
+    languages.list = serde_json::from_str(&languages_raw_json)
+        .expect("startup_languages() Error parsing languages.json String.");
+
+    info!("Loaded {} languages.", languages.list.len());
+}
+
+fn word_hash(word: &str, language_id: &str, listener_seed: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    word.to_lowercase().hash(&mut hasher);
+    language_id.hash(&mut hasher);
+    listener_seed.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Splits leading and trailing punctuation off a word so it can be kept as is.
+fn split_punctuation(word: &str) -> (&str, &str, &str) {
+    let start = word
+        .find(|c: char| c.is_alphanumeric())
+        .unwrap_or(word.len());
+    let end = word
+        .rfind(|c: char| c.is_alphanumeric())
+        .map(|i| i + word[i..].chars().next().unwrap().len_utf8())
+        .unwrap_or(start);
+    (
+        &word[..start],
+        &word[start..end.max(start)],
+        &word[end.max(start)..],
+    )
+}
+
+// The same word always turns into the same gibberish for the same listener,
+// so repeated words stay recognizable without giving the meaning away.
+pub fn scramble(message: &str, language: &LanguageData, listener_seed: u64) -> String {
+    if language.syllables.is_empty() {
+        return "...".to_string();
+    }
+
+    let mut words = vec![];
+
+    for word in message.split_whitespace() {
+        let (leading, core, trailing) = split_punctuation(word);
+
+        if core.is_empty() {
+            words.push(word.to_string());
+            continue;
+        }
+
+        let mut hash = word_hash(core, &language.id, listener_seed);
+        let syllable_count = (core.chars().count() + 2) / 3;
+
+        let mut scrambled = String::new();
+        for _ in 0..syllable_count.max(1) {
+            scrambled
+                .push_str(&language.syllables[(hash % language.syllables.len() as u64) as usize]);
+            hash = hash.rotate_right(7) ^ 0x9e3779b97f4a7c15;
+        }
+
+        if core.chars().next().unwrap().is_uppercase() {
+            let mut chars = scrambled.chars();
+            scrambled = match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => scrambled,
+            };
+        }
+
+        words.push(leading.to_string() + &scrambled + trailing);
+    }
+
+    words.join(" ")
+}
+
+// Heard through a wall roughly half the words get lost.
+pub fn muffle(message: &str, listener_seed: u64) -> String {
+    let mut words = vec![];
+
+    for word in message.split_whitespace() {
+        if word_hash(word, "muffled", listener_seed) % 2 == 0 {
+            words.push(word.to_string());
+        } else if words.last().map(|w: &String| w.as_str()) != Some("...") {
+            words.push("...".to_string());
+        }
+    }
+
+    "[i]".to_string() + &words.join(" ") + "[/i]"
+}
+
+// The version of a spoken message one listener gets to read.
+pub fn heard_message(
+    message: &str,
+    language_option: Option<&LanguageData>,
+    listener_known_option: Option<&KnownLanguages>,
+    muffled: bool,
+    listener_seed: u64,
+) -> String {
+    let mut heard = message.to_string();
+
+    match language_option {
+        Some(language) => {
+            let understood = match listener_known_option {
+                Some(listener_known) => listener_known.known.contains(&language.id),
+                // Listeners without languages (observers) understand everything.
+                None => true,
+            };
+            if !understood {
+                heard = scramble(&heard, language, listener_seed);
+            }
+        }
+        None => {}
+    }
+
+    if muffled {
+        heard = muffle(&heard, listener_seed);
+    }
+
+    heard
+}
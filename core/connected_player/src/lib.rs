@@ -1,17 +1,25 @@
+pub mod admin_help;
 pub mod boarding;
 pub mod broadcast_interpolation_transforms;
 pub mod chat;
+pub mod chat_filter;
 pub mod connection;
 pub mod connection_events;
 pub mod console_commands;
+pub mod emotes;
+pub mod entity_update_batch;
 pub mod examine_events;
 pub mod execute_tab_action;
 pub mod health_ui;
 pub mod humanoid;
 pub mod input;
+pub mod language;
 pub mod name_generator;
 pub mod net;
+pub mod network_stats;
 pub mod plugin;
+pub mod radio;
+pub mod reset;
 pub mod send_entity_update;
 pub mod send_net;
 pub mod setup_ui;
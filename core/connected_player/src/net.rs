@@ -1,5 +1,8 @@
-use bevy::prelude::{
-    Commands, Entity, EventReader, EventWriter, Local, Query, Res, Transform, Without,
+use bevy::{
+    core::Time,
+    prelude::{
+        Commands, Entity, EventReader, EventWriter, Local, Query, Res, ResMut, Transform, Without,
+    },
 };
 use humanoid::humanoid::Humanoid;
 use networking::messages::{InputBuildGraphics, InputMouseDirectionUpdate, InputSceneReady};
@@ -12,6 +15,8 @@ use api::{
     world_environment::WorldEnvironment,
 };
 
+use crate::network_stats::PendingPings;
+
 pub struct NetSendServerTime {
     pub handle: u64,
     pub message: ReliableServerMessage,
@@ -236,15 +241,36 @@ pub fn scene_ready_event(
     }
 }
 
+// Unanswered pings beyond this are dropped, clients that never answer don't grow the queue.
+const MAX_PENDING_PINGS: usize = 8;
+
 pub fn send_server_time(
     mut event_writer: EventWriter<NetSendServerTime>,
     connected_players: Query<&ConnectedPlayer>,
+    mut pending_pings: ResMut<PendingPings>,
+    time: Res<Time>,
 ) {
+    // Forget clients that left.
+    pending_pings.sent.retain(|handle, _| {
+        connected_players.iter().any(|connected_player_component| {
+            connected_player_component.connected && connected_player_component.handle == *handle
+        })
+    });
+
     for connected_player_component in connected_players.iter() {
         if !connected_player_component.connected {
             continue;
         }
 
+        let sent = pending_pings
+            .sent
+            .entry(connected_player_component.handle)
+            .or_default();
+        sent.push_back(time.seconds_since_startup());
+        if sent.len() > MAX_PENDING_PINGS {
+            sent.pop_front();
+        }
+
         event_writer.send(NetSendServerTime {
             handle: connected_player_component.handle,
             message: ReliableServerMessage::ConfigMessage(ServerConfigMessage::ServerTime),
@@ -0,0 +1,281 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::{
+    core::Time,
+    prelude::{info, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut},
+};
+use bevy_renet::renet::RenetServer;
+use console_commands::commands::{
+    player_selector_to_entities, AllConsoleCommands, NetConsoleCommands, NetEntityConsole,
+};
+use networking::messages::{InputConsoleCommand, InputHeartBeat};
+use pawn::pawn::{PersistentPlayerData, UsedNames};
+use api::{
+    chat::escape_bb,
+    connected_player::NetworkStats,
+    console_commands::{
+        ConsoleCommandVariant, ConsoleCommandVariantValues, CONSOLE_ERROR_COLOR,
+        CONSOLE_SUCCESS_COLOR,
+    },
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+};
+
+// Seconds since startup each unanswered ServerTime got sent at, per client handle.
+// Kept by handle so they survive the NetworkStats reset when boarding moves a player.
+#[derive(Default)]
+pub struct PendingPings {
+    pub sent: HashMap<u64, VecDeque<f64>>,
+}
+
+// Kicks on the renet rtt, pings are only matched to heart beats by order and can drift.
+pub struct AutoKick {
+    // Milliseconds, 0 disables auto kicking.
+    pub max_ping: f32,
+    // Seconds the rtt has to stay over max_ping before the client gets kicked.
+    pub duration: f32,
+}
+
+impl Default for AutoKick {
+    fn default() -> Self {
+        Self {
+            max_ping: 0.,
+            duration: 30.,
+        }
+    }
+}
+
+pub fn update_network_stats(
+    mut net: ResMut<RenetServer>,
+    time: Res<Time>,
+    mut heart_beats: EventReader<InputHeartBeat>,
+    mut connected_players: Query<(Entity, &ConnectedPlayer, Option<&mut NetworkStats>)>,
+    mut pending_pings: ResMut<PendingPings>,
+    auto_kick: Res<AutoKick>,
+    mut commands: Commands,
+) {
+    let now = time.seconds_since_startup();
+
+    for heart_beat in heart_beats.iter() {
+        match connected_players.get_mut(heart_beat.entity) {
+            Ok((_entity, connected_player_component, network_stats_option)) => {
+                let sent_at;
+
+                match pending_pings
+                    .sent
+                    .get_mut(&connected_player_component.handle)
+                    .and_then(|sent| sent.pop_front())
+                {
+                    Some(s) => {
+                        sent_at = s;
+                    }
+                    None => {
+                        continue;
+                    }
+                }
+
+                match network_stats_option {
+                    Some(mut network_stats) => {
+                        network_stats.add_ping(((now - sent_at) * 1000.) as f32);
+                    }
+                    None => {}
+                }
+            }
+            Err(_rr) => {}
+        }
+    }
+
+    for (entity, connected_player_component, network_stats_option) in connected_players.iter_mut() {
+        if !connected_player_component.connected {
+            continue;
+        }
+
+        let mut network_stats;
+        match network_stats_option {
+            Some(n) => {
+                network_stats = n;
+            }
+            None => {
+                commands.entity(entity).insert(NetworkStats::default());
+                continue;
+            }
+        }
+
+        let handle = connected_player_component.handle;
+
+        match net.network_info(handle) {
+            Some(network_info) => {
+                network_stats.rtt = network_info.rtt as f32;
+                network_stats.packet_loss = network_info.packet_loss as f32;
+                network_stats.sent_kbps = network_info.sent_kbps as f32;
+                network_stats.received_kbps = network_info.received_kbps as f32;
+            }
+            None => {}
+        }
+
+        if auto_kick.max_ping <= 0. || network_stats.rtt <= auto_kick.max_ping {
+            network_stats.excessive_ping_duration = 0.;
+            continue;
+        }
+
+        network_stats.excessive_ping_duration += time.delta_seconds();
+
+        if network_stats.excessive_ping_duration > auto_kick.duration {
+            info!(
+                "Kicking [{}] for a round trip time of {}ms over {} seconds.",
+                handle, network_stats.rtt, auto_kick.duration
+            );
+            network_stats.excessive_ping_duration = 0.;
+            net.disconnect(handle);
+        }
+    }
+}
+
+pub fn initialize_network_stats_console_commands(mut commands: ResMut<AllConsoleCommands>) {
+    commands.list.push((
+        "netstats".to_string(),
+        "Show the round trip time, ping, jitter, packet loss and bandwidth of players.".to_string(),
+        vec![("player_selector".to_string(), ConsoleCommandVariant::String)],
+    ));
+    commands.list.push((
+        "autoKick".to_string(),
+        "Kick players with an rtt over maxPing milliseconds for the given seconds, 0 disables it."
+            .to_string(),
+        vec![
+            ("maxPing".to_string(), ConsoleCommandVariant::Int),
+            ("seconds".to_string(), ConsoleCommandVariant::Int),
+        ],
+    ));
+}
+
+pub fn network_stats_console_commands(
+    mut queue: EventReader<InputConsoleCommand>,
+    connected_players: Query<(
+        &ConnectedPlayer,
+        &PersistentPlayerData,
+        Option<&NetworkStats>,
+    )>,
+    mut used_names: ResMut<UsedNames>,
+    mut auto_kick: ResMut<AutoKick>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+    mut net_entity_console: EventWriter<NetEntityConsole>,
+) {
+    for console_command_event in queue.iter() {
+        if console_command_event.command_name != "netstats"
+            && console_command_event.command_name != "autoKick"
+        {
+            continue;
+        }
+
+        let handle;
+        match console_command_event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        match connected_players.get(console_command_event.entity) {
+            Ok((connected_player, _persistent_player_data, _network_stats_option)) => {
+                if !connected_player.rcon {
+                    net_console_commands.send(NetConsoleCommands {
+                        handle,
+                        message: ReliableServerMessage::ConsoleWriteLine(
+                            "[color=".to_string()
+                                + CONSOLE_ERROR_COLOR
+                                + "]RCON status denied.[/color]",
+                        ),
+                    });
+                    continue;
+                }
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        if console_command_event.command_name == "autoKick" {
+            let (max_ping, seconds) = match (
+                console_command_event.command_arguments.get(0),
+                console_command_event.command_arguments.get(1),
+            ) {
+                (
+                    Some(ConsoleCommandVariantValues::Int(max_ping)),
+                    Some(ConsoleCommandVariantValues::Int(seconds)),
+                ) => (*max_ping, *seconds),
+                _ => {
+                    continue;
+                }
+            };
+
+            auto_kick.max_ping = max_ping.max(0) as f32;
+            auto_kick.duration = seconds.max(0) as f32;
+
+            let reply = if max_ping > 0 {
+                format!(
+                    "Players with a round trip time over {}ms for {} seconds get kicked.",
+                    max_ping, seconds
+                )
+            } else {
+                "Auto kicking is disabled.".to_string()
+            };
+
+            net_console_commands.send(NetConsoleCommands {
+                handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string() + CONSOLE_SUCCESS_COLOR + "]" + &reply + "[/color]",
+                ),
+            });
+            continue;
+        }
+
+        let player_selector;
+        match console_command_event.command_arguments.get(0) {
+            Some(ConsoleCommandVariantValues::String(value)) => {
+                player_selector = value.clone();
+            }
+            _ => {
+                continue;
+            }
+        }
+
+        for target_entity in player_selector_to_entities(
+            console_command_event.entity,
+            Some(handle),
+            &player_selector,
+            &mut used_names,
+            &mut net_entity_console,
+        ) {
+            let (persistent_player_data, network_stats) = match connected_players.get(target_entity)
+            {
+                Ok((_connected_player, persistent_player_data, Some(network_stats))) => {
+                    (persistent_player_data, network_stats)
+                }
+                _ => {
+                    continue;
+                }
+            };
+
+            let ping = match network_stats.ping {
+                Some(ping) => format!("{:.0}ms", ping),
+                None => "unknown".to_string(),
+            };
+
+            net_console_commands.send(NetConsoleCommands {
+                handle,
+                message: ReliableServerMessage::ConsoleWriteLine(format!(
+                    "{}: rtt {:.0}ms, ping {}, jitter {:.0}ms, loss {:.1}%, sent {:.1}kbps, received {:.1}kbps",
+                    escape_bb(persistent_player_data.user_name.clone(), true, true),
+                    network_stats.rtt,
+                    ping,
+                    network_stats.jitter,
+                    network_stats.packet_loss * 100.,
+                    network_stats.sent_kbps,
+                    network_stats.received_kbps,
+                )),
+            });
+        }
+    }
+}
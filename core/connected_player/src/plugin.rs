@@ -7,17 +7,23 @@ use networking::{
     plugin::NetTabData,
 };
 use api::{
+    chat::ChatLabels,
+    console_commands::ConsoleCommandsLabels,
     data::{
-        HandleToEntity, PostUpdateLabels, PreUpdateLabels, ServerId, SummoningLabels, UpdateLabels,
+        HandleToEntity, PostUpdateLabels, PreUpdateLabels, ServerId, StartupLabels,
+        SummoningLabels, UpdateLabels,
     },
     examinable::ExamineLabels,
     gridmap::GridmapExamineMessages,
     network::{InputUIInput, InputUIInputTransmitText},
+    rounds::RoundsLabels,
     tab_actions::TabActionsQueueLabels,
 };
 
 use super::{
-    boarding::{done_boarding, on_boarding, ui_input_transmit_data_event, BoardingPlayer},
+    boarding::{
+        done_boarding, on_boarding, ui_input_transmit_data_event, BoardingPlayer, BoardingQueue,
+    },
     input::{controller_input, player_input_event, text_tree_input_selection},
     net::{
         build_graphics_event, mouse_direction_update, scene_ready_event, send_server_time,
@@ -28,17 +34,37 @@ use super::{
     setup_ui::ui_input_event,
 };
 use crate::{
-    boarding::{on_spawning, BoardingAnnouncements},
-    broadcast_interpolation_transforms::broadcast_interpolation_transforms,
+    admin_help::{chat_commands, AdminHelpTickets},
+    boarding::{on_spawning, BoardingAnnouncements, NewArrival},
+    broadcast_interpolation_transforms::{
+        broadcast_interpolation_transforms, TransformBroadcastBudget, TransformBroadcastState,
+    },
     chat::{chat_message_input_event, NetChatMessage},
+    chat_filter::{
+        filter_chat_messages, flush_chat_log, initialize_console_commands, mute_console_commands,
+        startup_chat_filter, write_chat_log, ChatFilter, ChatLogBuffer, ChatMutes,
+        FilteredChatMessage, LogChatMessage,
+    },
     connection::{connections, AuthidI},
     console_commands::{entity_console_commands, inventory_item_console_commands},
+    emotes::{
+        emote_chat_commands, emote_events, emote_tab_actions, startup_emotes, EmoteSounds, Emotes,
+        InputEmote,
+    },
     examine_events::{
         examine_entity, examine_map, finalize_examine_entity, finalize_examine_map, NetConnExamine,
     },
     execute_tab_action::execute_tab_actions,
     health_ui::{health_ui_update, ClientHealthUICache},
     humanoid::humanoid_update,
+    language::{startup_languages, Languages},
+    network_stats::{
+        initialize_network_stats_console_commands, network_stats_console_commands,
+        update_network_stats, AutoKick, PendingPings,
+    },
+    radio::{radio_from_equipment, startup_radio_channels, NetRadio, RadioChannels},
+    reset::reset_connected_players,
+    entity_update_batch::{send_entity_update_batches, EntityUpdateBatching, EntityUpdateIds},
     send_entity_update::send_entity_updates,
     send_net::process_net,
     setup_ui::on_setupui,
@@ -76,6 +102,15 @@ impl Plugin for ConnectedPlayerPlugin {
                     .with_run_criteria(FixedTimestep::step(2.))
                     .with_system(send_server_time),
             )
+            .init_resource::<AutoKick>()
+            .init_resource::<PendingPings>()
+            .add_system(update_network_stats.before(UpdateLabels::StandardCharacters))
+            .add_startup_system(
+                initialize_network_stats_console_commands
+                    .before(ConsoleCommandsLabels::Finalize)
+                    .label(StartupLabels::ConsoleCommands),
+            )
+            .add_system(network_stats_console_commands)
             .add_system(ui_input_transmit_data_event)
             .add_system(on_boarding)
             .add_system(text_tree_input_selection.label(UpdateLabels::TextTreeInputSelection))
@@ -103,6 +138,8 @@ impl Plugin for ConnectedPlayerPlugin {
             .add_event::<InputUIInput>()
             .init_resource::<AuthidI>()
             .add_event::<NetConnExamine>()
+            .init_resource::<TransformBroadcastBudget>()
+            .init_resource::<TransformBroadcastState>()
             .add_system_to_stage(CoreStage::Update, broadcast_interpolation_transforms)
             .add_system(execute_tab_actions.label(TabActionsQueueLabels::TabAction))
             .add_system_set_to_stage(
@@ -138,7 +175,8 @@ impl Plugin for ConnectedPlayerPlugin {
                     .with_system(net_system::<NetSendServerTime>)
                     .with_system(net_system::<NetUpdatePlayerCount>)
                     .with_system(net_system::<NetConnExamine>)
-                    .with_system(net_system::<NetChatMessage>),
+                    .with_system(net_system::<NetChatMessage>)
+                    .with_system(net_system::<NetRadio>),
             )
             .add_system_to_stage(
                 PostUpdate,
@@ -146,6 +184,14 @@ impl Plugin for ConnectedPlayerPlugin {
                     .after(PostUpdateLabels::EntityUpdate)
                     .label(PostUpdateLabels::SendEntityUpdates),
             )
+            .init_resource::<EntityUpdateIds>()
+            .init_resource::<EntityUpdateBatching>()
+            .add_system_to_stage(
+                PostUpdate,
+                send_entity_update_batches
+                    .after(PostUpdateLabels::SendEntityUpdates)
+                    .before(PostUpdateLabels::Net),
+            )
             .add_system(console_commands)
             .add_system(
                 inventory_item_console_commands
@@ -153,7 +199,7 @@ impl Plugin for ConnectedPlayerPlugin {
                     .label(SummoningLabels::NormalSummon),
             )
             .add_system(entity_console_commands.after(SummoningLabels::DefaultSummon))
-            .add_system(chat_message_input_event)
+            .add_system(chat_message_input_event.after(ChatLabels::Filter))
             .add_event::<NetChatMessage>()
             .add_system_to_stage(PostUpdate, process_net.after(PostUpdateLabels::Net))
             .add_system(
@@ -163,7 +209,53 @@ impl Plugin for ConnectedPlayerPlugin {
             )
             .init_resource::<ClientHealthUICache>()
             .init_resource::<BoardingAnnouncements>()
-            .init_resource::<ServerId>();
+            .init_resource::<BoardingQueue>()
+            .add_event::<NewArrival>()
+            .init_resource::<ServerId>()
+            .init_resource::<RadioChannels>()
+            .add_startup_system(startup_radio_channels.label(StartupLabels::MiscResources))
+            .add_system(radio_from_equipment)
+            .add_event::<NetRadio>()
+            .init_resource::<AdminHelpTickets>()
+            .add_system(chat_commands.after(ChatLabels::Filter))
+            .init_resource::<ChatFilter>()
+            .init_resource::<ChatMutes>()
+            .add_event::<FilteredChatMessage>()
+            .add_event::<LogChatMessage>()
+            .add_startup_system(startup_chat_filter.label(StartupLabels::MiscResources))
+            .add_startup_system(
+                initialize_console_commands
+                    .before(ConsoleCommandsLabels::Finalize)
+                    .label(StartupLabels::ConsoleCommands),
+            )
+            .add_system(filter_chat_messages.label(ChatLabels::Filter))
+            .add_system(mute_console_commands)
+            .init_resource::<ChatLogBuffer>()
+            .add_system_to_stage(PostUpdate, write_chat_log)
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(5.))
+                    .with_system(flush_chat_log),
+            )
+            .init_resource::<Languages>()
+            .add_startup_system(startup_languages.label(StartupLabels::MiscResources))
+            .init_resource::<Emotes>()
+            .init_resource::<EmoteSounds>()
+            .add_event::<InputEmote>()
+            .add_startup_system(startup_emotes.label(StartupLabels::MiscResources))
+            .add_system(emote_chat_commands.after(ChatLabels::Filter))
+            .add_system(emote_tab_actions.after(TabActionsQueueLabels::TabAction))
+            .add_system(
+                emote_events
+                    .after(UpdateLabels::StandardCharacters)
+                    .after(ChatLabels::Filter),
+            )
+            .add_system(
+                reset_connected_players
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            );
     }
 }
 use crate::console_commands::console_commands;
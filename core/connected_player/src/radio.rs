@@ -0,0 +1,159 @@
+use std::{fs, path::Path};
+
+use bevy::prelude::{info, warn, Changed, Component, EventWriter, Query, Res, ResMut};
+use pawn::pawn::{get_id_card_access, ShipAuthorization, ShipAuthorizationEnum};
+use serde::Deserialize;
+use api::{
+    chat::get_talk_spaces,
+    data::ConnectedPlayer,
+    inventory::{Inventory, SlotType},
+    network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage, ServerConfigMessage},
+};
+
+use crate::chat::{Radio, RadioChannel};
+
+#[derive(Deserialize, Clone)]
+pub struct RadioChannelData {
+    pub id: String,
+    pub name: String,
+    pub prefix: String,
+    pub color: String,
+    pub message_color: String,
+    // Any of these on the wearer's ID card unlocks the channel, empty means everyone.
+    pub access: Vec<ShipAuthorizationEnum>,
+}
+
+#[derive(Default)]
+pub struct RadioChannels {
+    pub list: Vec<RadioChannelData>,
+}
+
+impl RadioChannels {
+    pub fn get(&self, id: &str) -> Option<&RadioChannelData> {
+        self.list.iter().find(|channel| channel.id == id)
+    }
+
+    // Longest prefix first so that channels may share a leading character.
+    pub fn from_message(&self, message: &str) -> Option<&RadioChannelData> {
+        self.list
+            .iter()
+            .filter(|channel| message.starts_with(&channel.prefix))
+            .max_by_key(|channel| channel.prefix.len())
+    }
+}
+
+// Worn in the headset slot, lists the radio channels the headset is able to tune into.
+#[derive(Component)]
+pub struct Headset {
+    pub channels: Vec<String>,
+}
+
+pub struct NetRadio {
+    pub handle: u64,
+    pub message: ReliableServerMessage,
+}
+impl PendingMessage for NetRadio {
+    fn get_message(&self) -> PendingNetworkMessage {
+        PendingNetworkMessage {
+            handle: self.handle,
+            message: self.message.clone(),
+        }
+    }
+}
+
+pub fn startup_radio_channels(mut radio_channels: ResMut<RadioChannels>) {
+    let channels_json = Path::new("data").join("radio").join("channels.json");
+    let channels_raw_json: String = fs::read_to_string(channels_json)
+        .expect("startup_radio_channels() Error reading channels.json from drive.");
+    radio_channels.list = serde_json::from_str(&channels_raw_json)
+        .expect("startup_radio_channels() Error parsing channels.json String.");
+
+    info!("Loaded {} radio channels.", radio_channels.list.len());
+}
+
+pub fn radio_talk_spaces(radio: &Radio, radio_channels: &RadioChannels) -> Vec<(String, String)> {
+    let mut talk_spaces = vec![];
+
+    for radio_channel in radio.speak_access.iter() {
+        match radio_channel {
+            RadioChannel::Radio(id) => match radio_channels.get(id) {
+                Some(channel) => {
+                    talk_spaces.push((channel.name.clone(), channel.prefix.clone()));
+                }
+                None => {}
+            },
+            _ => (),
+        }
+    }
+
+    talk_spaces
+}
+
+// Radio access follows the worn headset and ID card, so swapping either changes the channels.
+pub fn radio_from_equipment(
+    mut pawns: Query<(&Inventory, &mut Radio, Option<&ConnectedPlayer>), Changed<Inventory>>,
+    headsets: Query<&Headset>,
+    id_cards: Query<&ShipAuthorization>,
+    radio_channels: Res<RadioChannels>,
+    mut net_radio: EventWriter<NetRadio>,
+) {
+    for (inventory, mut radio, connected_player_option) in pawns.iter_mut() {
+        let mut headset_channels: Vec<String> = vec![];
+
+        for slot in inventory.slots.iter() {
+            if slot.slot_type != SlotType::Headset {
+                continue;
+            }
+            match slot.slot_item {
+                Some(item) => match headsets.get(item) {
+                    Ok(headset) => {
+                        headset_channels = headset.channels.clone();
+                    }
+                    Err(_rr) => {}
+                },
+                None => {}
+            }
+        }
+
+        let access = get_id_card_access(inventory, &id_cards);
+
+        let mut channels = vec![];
+
+        for channel_id in headset_channels.iter() {
+            match radio_channels.get(channel_id) {
+                Some(channel) => {
+                    if channel.access.is_empty()
+                        || channel.access.iter().any(|a| access.contains(a))
+                    {
+                        channels.push(RadioChannel::Radio(channel.id.clone()));
+                    }
+                }
+                None => {
+                    warn!("Headset has unknown radio channel {}.", channel_id);
+                }
+            }
+        }
+
+        if radio.speak_access == channels {
+            continue;
+        }
+
+        radio.listen_access = channels.clone();
+        radio.speak_access = channels;
+
+        match connected_player_option {
+            Some(connected_player) => {
+                if !connected_player.connected {
+                    continue;
+                }
+                net_radio.send(NetRadio {
+                    handle: connected_player.handle,
+                    message: ReliableServerMessage::ConfigMessage(ServerConfigMessage::TalkSpaces(
+                        get_talk_spaces(radio_talk_spaces(&radio, &radio_channels)),
+                    )),
+                });
+            }
+            None => {}
+        }
+    }
+}
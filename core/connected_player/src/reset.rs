@@ -0,0 +1,40 @@
+use bevy::{
+    core::Time,
+    prelude::{EventReader, Res, ResMut},
+};
+use api::rounds::ResetWorld;
+
+use crate::{
+    boarding::{BoardingAnnouncements, BoardingQueue},
+    broadcast_interpolation_transforms::TransformBroadcastState,
+    chat_filter::ChatMutes,
+    health_ui::ClientHealthUICache,
+};
+
+// Round state of connected players, the players themselves stay connected.
+// Running mutes carry over into the next round.
+pub fn reset_connected_players(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut boarding_announcements: ResMut<BoardingAnnouncements>,
+    mut boarding_queue: ResMut<BoardingQueue>,
+    mut transform_broadcast_state: ResMut<TransformBroadcastState>,
+    mut client_health_ui_cache: ResMut<ClientHealthUICache>,
+    mut chat_mutes: ResMut<ChatMutes>,
+    time: Res<Time>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    boarding_announcements.announcements.clear();
+    boarding_queue.players.clear();
+    transform_broadcast_state.last_sent.clear();
+    transform_broadcast_state.skipped.clear();
+    client_health_ui_cache.cache.clear();
+
+    let now = time.seconds_since_startup();
+    chat_mutes.muted.retain(|_, until| match until {
+        Some(until) => *until > now,
+        None => true,
+    });
+}
@@ -1,12 +1,13 @@
-use bevy::prelude::{Changed, Entity, EventWriter, Query, Res};
+use bevy::prelude::{Changed, Entity, Query, Res, ResMut};
 
 use api::{
     data::{ConnectedPlayer, HandleToEntity, Showcase},
-    entity_updates::{personalise, EntityUpdates, NetSendEntityUpdates},
-    network::{EntityWorldType, ReliableServerMessage},
+    entity_updates::{personalise, EntityUpdates},
     sensable::Sensable,
 };
 
+use crate::entity_update_batch::EntityUpdateBatching;
+
 pub fn send_entity_updates(
     mut updated_entity_updates: Query<
         (
@@ -18,7 +19,7 @@ pub fn send_entity_updates(
         ),
         Changed<EntityUpdates>,
     >,
-    mut net_send_entity_updates: EventWriter<NetSendEntityUpdates>,
+    mut entity_update_batching: ResMut<EntityUpdateBatching>,
     handle_to_entity: Res<HandleToEntity>,
 ) {
     for (
@@ -61,15 +62,11 @@ pub fn send_entity_updates(
 
                         match handle_to_entity.inv_map.get(&sensed_by_entity) {
                             Some(handle) => {
-                                net_send_entity_updates.send(NetSendEntityUpdates {
-                                    handle: *handle,
-                                    message: ReliableServerMessage::EntityUpdate(
-                                        visible_entity.to_bits(),
-                                        updates_data.clone(),
-                                        false,
-                                        EntityWorldType::Main,
-                                    ),
-                                });
+                                entity_update_batching.add(
+                                    *handle,
+                                    visible_entity.to_bits(),
+                                    updates_data.clone(),
+                                );
                             }
                             None => {}
                         }
@@ -100,15 +97,11 @@ pub fn send_entity_updates(
                     continue;
                 }
 
-                net_send_entity_updates.send(NetSendEntityUpdates {
-                    handle: showcase_component.handle,
-                    message: ReliableServerMessage::EntityUpdate(
-                        visible_entity.to_bits(),
-                        updates_data,
-                        false,
-                        EntityWorldType::Main,
-                    ),
-                });
+                entity_update_batching.add(
+                    showcase_component.handle,
+                    visible_entity.to_bits(),
+                    updates_data,
+                );
             }
             None => {}
         }
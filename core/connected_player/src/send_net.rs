@@ -4,6 +4,7 @@ use bincode::serialize;
 use networking::plugin::{NetEvent, RENET_RELIABLE_CHANNEL_ID};
 use api::{
     data::{ConnectedPlayer, HandleToEntity},
+    metrics::Metrics,
     network::{PendingNetworkMessage, ReliableServerMessage},
 };
 
@@ -12,6 +13,7 @@ pub fn send_net(
     connected_players: &Query<&ConnectedPlayer>,
     handle_to_entity: &Res<HandleToEntity>,
     new_event: &NetEvent,
+    metrics: &Res<Metrics>,
 ) {
     let mut connected = false;
 
@@ -40,11 +42,9 @@ pub fn send_net(
     if !connected {
         return;
     }
-    net.send_message(
-        new_event.handle,
-        RENET_RELIABLE_CHANNEL_ID,
-        serialize::<ReliableServerMessage>(&new_event.message).unwrap(),
-    );
+    let bytes = serialize::<ReliableServerMessage>(&new_event.message).unwrap();
+    metrics.add_sent(new_event.message.get_type_name(), bytes.len());
+    net.send_message(new_event.handle, RENET_RELIABLE_CHANNEL_ID, bytes);
 }
 
 pub fn process_net(
@@ -52,6 +52,7 @@ pub fn process_net(
     connected_players: Query<&ConnectedPlayer>,
     mut net: ResMut<RenetServer>,
     handle_to_entity: Res<HandleToEntity>,
+    metrics: Res<Metrics>,
 ) {
     for p in pending_network_message.iter() {
         send_net(
@@ -62,6 +63,7 @@ pub fn process_net(
                 handle: p.handle,
                 message: p.message.clone(),
             },
+            &metrics,
         );
     }
 }
@@ -3,6 +3,8 @@ pub fn ui_input_event(
     handle_to_entity: Res<HandleToEntity>,
     criteria_query: Query<&SoftPlayer>,
     mut commands: Commands,
+    round_status: Res<RoundStatus>,
+    mut net_on_setupui: EventWriter<NetOnSetupUI>,
 ) {
     for new_event in event.iter() {
         let player_entity = handle_to_entity.map.get(&new_event.handle).expect(
@@ -22,6 +24,17 @@ pub fn ui_input_event(
                 && matches!(new_event.node_class, UIInputNodeClass::Button)
                 && matches!(new_event.action, UIInputAction::Pressed)
             {
+                if !round_status.boarding_allowed() && !round_status.boarding_queued() {
+                    net_on_setupui.send(NetOnSetupUI {
+                        handle: new_event.handle,
+                        message: ReliableServerMessage::ConsoleWriteLine(
+                            "[color=".to_string()
+                                + CONSOLE_ERROR_COLOR
+                                + "]Boarding reopens once the next round is being prepared.[/color]",
+                        ),
+                    });
+                    continue;
+                }
                 commands.entity(*player_entity).insert(Boarding);
             }
         }
@@ -32,19 +45,19 @@ use std::collections::HashMap;
 
 use bevy::prelude::{Added, Commands, EventReader, EventWriter, Query, Res};
 use entity::spawn::{SpawnData, SpawnEvent};
+use jobs::jobs::Jobs;
 use pawn::pawn::{PersistentPlayerData, UsedNames};
 use api::{
     chat::MOTD,
     connected_player::SoftPlayer,
-    data::{
-        ConnectedPlayer, HandleToEntity, ServerId, ShowcaseData, HUMAN_MALE_ENTITY_NAME,
-        JUMPSUIT_SECURITY_ENTITY_NAME, PISTOL_L1_ENTITY_NAME,
-    },
+    console_commands::CONSOLE_ERROR_COLOR,
+    data::{ConnectedPlayer, HandleToEntity, ServerId, ShowcaseData, HUMAN_MALE_ENTITY_NAME},
     entity_updates::EntityUpdateData,
     network::{
         EntityWorldType, InputUIInput, ReliableServerMessage, UIInputAction, UIInputNodeClass,
     },
     pawn::PawnDesignation,
+    rounds::RoundStatus,
 };
 
 use crate::{connection::SpawnPawnData, humanoid::HumanMaleSummoner};
@@ -57,6 +70,8 @@ use super::{
 
 pub const INPUT_NAME_PATH_FULL : &str = "setupUI::ColorRect/background/VBoxContainer/HBoxContainer/characterSettingsPopup/Control/TabContainer/Boarding Configuration/VBoxContainer/vBoxNameInput/Control/inputName";
 pub const INPUT_NAME_PATH : &str = "ColorRect/background/VBoxContainer/HBoxContainer/characterSettingsPopup/Control/TabContainer/Boarding Configuration/VBoxContainer/vBoxNameInput/Control/inputName";
+pub const INPUT_JOB_PATH_FULL : &str = "setupUI::ColorRect/background/VBoxContainer/HBoxContainer/characterSettingsPopup/Control/TabContainer/Boarding Configuration/VBoxContainer/vBoxJobInput/Control/inputJob";
+pub const INPUT_JOB_PATH : &str = "ColorRect/background/VBoxContainer/HBoxContainer/characterSettingsPopup/Control/TabContainer/Boarding Configuration/VBoxContainer/vBoxJobInput/Control/inputJob";
 
 pub fn on_setupui(
     used_names: Res<UsedNames>,
@@ -66,6 +81,7 @@ pub fn on_setupui(
     mut summon_human_male: EventWriter<SpawnEvent<HumanMaleSummoner>>,
     mut commands: Commands,
     motd: Res<MOTD>,
+    jobs: Res<Jobs>,
 ) {
     for (connected_player_component, persistent_player_data_component) in query.iter() {
         let suggested_name = get_full_name(true, true, &used_names);
@@ -81,6 +97,30 @@ pub fn on_setupui(
 
         hash_map_path.insert(INPUT_NAME_PATH_FULL.to_string(), hash_map_data);
 
+        let mut job_ids = vec![];
+        let mut job_texts = vec![];
+
+        for job in jobs.get_selectable() {
+            job_ids.push(job.id.clone());
+            job_texts.push(job.title.clone());
+        }
+
+        let mut hash_map_job_data = HashMap::new();
+
+        hash_map_job_data.insert("item_ids".to_string(), EntityUpdateData::StringVec(job_ids));
+        hash_map_job_data.insert(
+            "item_texts".to_string(),
+            EntityUpdateData::StringVec(job_texts),
+        );
+        hash_map_job_data.insert(
+            "selected_id".to_string(),
+            EntityUpdateData::String(jobs.default_job.clone()),
+        );
+
+        hash_map_path.insert(INPUT_JOB_PATH_FULL.to_string(), hash_map_job_data);
+
+        // Goes out with the rest of the setup UI messages instead of the end of tick batch,
+        // so the job list is filled in as the scene loads.
         net_on_setupui.send(NetOnSetupUI {
             handle: connected_player_component.handle,
             message: ReliableServerMessage::EntityUpdate(
@@ -96,13 +136,22 @@ pub fn on_setupui(
             message: ReliableServerMessage::ChatMessage(motd.message.clone()),
         });
 
-        let passed_inventory_setup = vec![
-            (
-                "jumpsuit".to_string(),
-                JUMPSUIT_SECURITY_ENTITY_NAME.to_string(),
-            ),
-            ("holster".to_string(), PISTOL_L1_ENTITY_NAME.to_string()),
-        ];
+        // Showcase the outfit of the preferred job.
+        let showcase_job;
+
+        match &persistent_player_data_component.job_preference {
+            Some(job_id) => match jobs.get(job_id) {
+                Some(job) => {
+                    showcase_job = job.clone();
+                }
+                None => {
+                    showcase_job = jobs.get_default().clone();
+                }
+            },
+            None => {
+                showcase_job = jobs.get_default().clone();
+            }
+        }
 
         let human_male_entity = commands.spawn().id();
 
@@ -121,7 +170,7 @@ pub fn on_setupui(
                 spawn_pawn_data: SpawnPawnData {
                     persistent_player_data: persistent_player_data_component.clone(),
                     connected_player_option: Some(connected_player_component.clone()),
-                    inventory_setup: passed_inventory_setup,
+                    job: showcase_job,
                     designation: PawnDesignation::Showcase,
                 },
             },
@@ -6,6 +6,7 @@ use networking::plugin::RENET_UNRELIABLE_CHANNEL_ID;
 use serde::Deserialize;
 use api::{
     data::{EntityDataProperties, EntityDataResource, HandleToEntity},
+    metrics::Metrics,
     network::{
         PendingMessage, PendingNetworkMessage, ReliableServerMessage, UnreliableServerMessage,
     },
@@ -42,6 +43,7 @@ pub fn broadcast_position_updates(
         &Transform,
         &mut CachedBroadcastTransform,
     )>,
+    metrics: Res<Metrics>,
 ) {
     let current_time_stamp = time.time_since_startup().as_millis();
 
@@ -49,6 +51,7 @@ pub fn broadcast_position_updates(
         .get(INTERPOLATION_LABEL1)
         .unwrap()
         .overstep_percentage();
+    metrics.record_overstep(INTERPOLATION_LABEL1, overstep_percentage);
     if overstep_percentage > 5. {
         if current_time_stamp > 60000 {
             warn!("overstep_percentage: {}", overstep_percentage);
@@ -76,18 +79,16 @@ pub fn broadcast_position_updates(
 
             match player_handle_option {
                 Some(handle) => {
-                    net.send_message(
-                        *handle,
-                        RENET_UNRELIABLE_CHANNEL_ID,
-                        serialize::<UnreliableServerMessage>(
-                            &UnreliableServerMessage::PositionUpdate(
-                                entity.to_bits(),
-                                new_position,
-                                current_time_stamp as u64,
-                            ),
-                        )
-                        .unwrap(),
-                    );
+                    let bytes = serialize::<UnreliableServerMessage>(
+                        &UnreliableServerMessage::PositionUpdate(
+                            entity.to_bits(),
+                            new_position,
+                            current_time_stamp as u64,
+                        ),
+                    )
+                    .unwrap();
+                    metrics.add_sent("PositionUpdate", bytes.len());
+                    net.send_message(*handle, RENET_UNRELIABLE_CHANNEL_ID, bytes);
                 }
                 None => {
                     continue;
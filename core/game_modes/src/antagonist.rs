@@ -0,0 +1,60 @@
+use bevy::prelude::{Component, Entity};
+
+#[derive(Clone)]
+pub enum ObjectiveKind {
+    Steal {
+        entity_name: String,
+        item_name: String,
+    },
+    Eliminate {
+        target: Entity,
+        target_name: String,
+    },
+    EscapeAlive,
+}
+
+#[derive(Clone)]
+pub struct Objective {
+    pub kind: ObjectiveKind,
+    pub completed: bool,
+}
+
+impl Objective {
+    pub fn new(kind: ObjectiveKind) -> Self {
+        Self {
+            kind,
+            completed: false,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match &self.kind {
+            ObjectiveKind::Steal { item_name, .. } => {
+                format!("Steal {} and keep it on you.", item_name)
+            }
+            ObjectiveKind::Eliminate { target_name, .. } => {
+                format!("Eliminate {}.", target_name)
+            }
+            ObjectiveKind::EscapeAlive => "Escape alive.".to_string(),
+        }
+    }
+}
+
+// Players picked by a game mode, the objectives are only known to them until the round ends.
+#[derive(Component)]
+pub struct Antagonist {
+    pub role: String,
+    pub objectives: Vec<Objective>,
+}
+
+impl Antagonist {
+    pub fn objectives_text(&self) -> Vec<String> {
+        let mut lines = vec![format!("You are a {}. Your objectives:", self.role)];
+
+        for (i, objective) in self.objectives.iter().enumerate() {
+            lines.push(format!("{}. {}", i + 1, objective.description()));
+        }
+
+        lines
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::{EventReader, EventWriter, Query, ResMut};
+use console_commands::commands::{AllConsoleCommands, NetConsoleCommands};
+use networking::messages::InputConsoleCommand;
+use api::{chat::HEALTHY_COLOR, console_commands::CONSOLE_ERROR_COLOR, network::ReliableServerMessage};
+
+use crate::antagonist::Antagonist;
+
+pub fn initialize_console_commands(mut commands: ResMut<AllConsoleCommands>) {
+    commands.list.push((
+        "objectives".to_string(),
+        "Show the objectives you were given this round.".to_string(),
+        vec![],
+    ));
+}
+
+pub fn game_modes_console_commands(
+    mut queue: EventReader<InputConsoleCommand>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+    antagonists: Query<&Antagonist>,
+) {
+    for console_command_event in queue.iter() {
+        if console_command_event.command_name != "objectives" {
+            continue;
+        }
+
+        let handle;
+        match console_command_event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let reply = match antagonists.get(console_command_event.entity) {
+            Ok(antagonist) => {
+                let mut lines = antagonist.objectives_text();
+                for (i, objective) in antagonist.objectives.iter().enumerate() {
+                    if objective.completed {
+                        lines[i + 1] =
+                            lines[i + 1].clone() + " [color=" + HEALTHY_COLOR + "](done)[/color]";
+                    }
+                }
+                lines.join("\n")
+            }
+            Err(_rr) => {
+                "[color=".to_string() + CONSOLE_ERROR_COLOR + "]You have no objectives.[/color]"
+            }
+        };
+
+        net_console_commands.send(NetConsoleCommands {
+            handle,
+            message: ReliableServerMessage::ConsoleWriteLine(reply),
+        });
+    }
+}
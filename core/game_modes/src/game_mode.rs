@@ -0,0 +1,90 @@
+use std::{fs, path::Path};
+
+use bevy::prelude::{info, warn, EventReader, EventWriter, Query, Res, ResMut};
+use rand::{prelude::SliceRandom, thread_rng};
+use serde::Deserialize;
+use api::{
+    data::ConnectedPlayer,
+    rounds::{RoundState, RoundStateChanged},
+};
+
+#[derive(Deserialize, Clone)]
+pub struct GameModeData {
+    pub id: String,
+    pub name: String,
+    // Modes are only picked when at least this many players are connected at round start.
+    pub min_players: u16,
+    pub weight: u16,
+}
+
+#[derive(Default)]
+pub struct GameModes {
+    pub list: Vec<GameModeData>,
+}
+
+// The mode of the current round, game mode plugins check the id to see if they are active.
+#[derive(Default)]
+pub struct ActiveGameMode {
+    pub id: Option<String>,
+}
+
+impl ActiveGameMode {
+    pub fn is(&self, id: &str) -> bool {
+        self.id.as_deref() == Some(id)
+    }
+}
+
+pub struct GameModeStarted {
+    pub id: String,
+}
+
+pub const EXTENDED_GAME_MODE_ID: &str = "extended";
+
+pub fn startup_game_modes(mut game_modes: ResMut<GameModes>) {
+    let game_modes_json = Path::new("data").join("game_modes").join("game_modes.json");
+    let game_modes_raw_json: String = fs::read_to_string(game_modes_json)
+        .expect("startup_game_modes() Error reading game_modes.json from drive.");
+    game_modes.list = serde_json::from_str(&game_modes_raw_json)
+        .expect("startup_game_modes() Error parsing game_modes.json String.");
+
+    info!("Loaded {} game modes.", game_modes.list.len());
+}
+
+pub fn select_game_mode(
+    mut round_state_changed: EventReader<RoundStateChanged>,
+    game_modes: Res<GameModes>,
+    mut active_game_mode: ResMut<ActiveGameMode>,
+    mut game_mode_started: EventWriter<GameModeStarted>,
+    connected_players: Query<&ConnectedPlayer>,
+) {
+    for state_changed in round_state_changed.iter() {
+        match state_changed.new {
+            RoundState::InProgress => {
+                let player_count = connected_players.iter().filter(|p| p.connected).count();
+
+                let candidates: Vec<&GameModeData> = game_modes
+                    .list
+                    .iter()
+                    .filter(|mode| mode.min_players as usize <= player_count)
+                    .collect();
+
+                let id = match candidates.choose_weighted(&mut thread_rng(), |mode| mode.weight) {
+                    Ok(mode) => mode.id.clone(),
+                    Err(_rr) => {
+                        warn!("No game mode is available for {} players.", player_count);
+                        EXTENDED_GAME_MODE_ID.to_string()
+                    }
+                };
+
+                info!("Selected game mode {}.", id);
+
+                active_game_mode.id = Some(id.clone());
+                game_mode_started.send(GameModeStarted { id });
+            }
+            RoundState::Lobby => {
+                active_game_mode.id = None;
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod antagonist;
+pub mod console_commands;
+pub mod game_mode;
+pub mod net;
+pub mod objectives;
+pub mod plugin;
+pub mod traitor;
@@ -0,0 +1,201 @@
+use bevy::{
+    math::Vec3,
+    prelude::{Added, Changed, EventReader, EventWriter, Or, Query, Res, ResMut, Transform},
+};
+use pawn::pawn::{Pawn, PersistentPlayerData};
+use serde::Deserialize;
+use api::{
+    chat::{HEALTHY_COLOR, UNHEALTHY_COLOR},
+    data::ConnectedPlayer,
+    entity_updates::EntityData,
+    health::Health,
+    inventory::Inventory,
+    network::ReliableServerMessage,
+    rounds::{RoundEndSummary, RoundState, RoundStateChanged},
+};
+
+use crate::{
+    antagonist::{Antagonist, ObjectiveKind},
+    net::NetGameModes,
+};
+
+#[derive(Deserialize, Clone)]
+pub struct EscapeArea {
+    pub center: [f32; 3],
+    pub radius: f32,
+}
+
+// Places antagonists have to reach for the escape objective, provided by the game mode.
+#[derive(Default)]
+pub struct EscapeAreas {
+    pub areas: Vec<EscapeArea>,
+}
+
+impl EscapeAreas {
+    pub fn contains(&self, position: Vec3) -> bool {
+        self.areas
+            .iter()
+            .any(|area| position.distance(Vec3::from(area.center)) <= area.radius)
+    }
+}
+
+fn holds_item(inventory: &Inventory, items: &Query<&EntityData>, entity_name: &str) -> bool {
+    inventory.slots.iter().any(|slot| match slot.slot_item {
+        Some(item) => match items.get(item) {
+            Ok(entity_data) => entity_data.entity_name == entity_name,
+            Err(_rr) => false,
+        },
+        None => false,
+    })
+}
+
+pub fn send_objective_message(
+    net_game_modes: &mut EventWriter<NetGameModes>,
+    connected_player_option: Option<&ConnectedPlayer>,
+    message: String,
+) {
+    match connected_player_option {
+        Some(connected_player) => {
+            if !connected_player.connected {
+                return;
+            }
+            net_game_modes.send(NetGameModes {
+                handle: connected_player.handle,
+                message: ReliableServerMessage::ChatMessage(message),
+            });
+        }
+        None => {}
+    }
+}
+
+// New antagonists get checked right away, they may already hold their target.
+pub fn steal_objectives(
+    mut antagonists: Query<
+        (&Inventory, &mut Antagonist, Option<&ConnectedPlayer>),
+        Or<(Changed<Inventory>, Added<Antagonist>)>,
+    >,
+    items: Query<&EntityData>,
+    mut net_game_modes: EventWriter<NetGameModes>,
+) {
+    for (inventory, mut antagonist, connected_player_option) in antagonists.iter_mut() {
+        for objective in antagonist.objectives.iter_mut() {
+            if objective.completed {
+                continue;
+            }
+            match &objective.kind {
+                ObjectiveKind::Steal { entity_name, .. } => {
+                    if holds_item(inventory, &items, entity_name) {
+                        objective.completed = true;
+                        send_objective_message(
+                            &mut net_game_modes,
+                            connected_player_option,
+                            "[color=".to_string()
+                                + HEALTHY_COLOR
+                                + "]Objective complete: "
+                                + &objective.description()
+                                + "[/color]",
+                        );
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+pub fn eliminate_objectives(
+    mut antagonists: Query<(&mut Antagonist, Option<&ConnectedPlayer>)>,
+    healths: Query<&Health>,
+    mut net_game_modes: EventWriter<NetGameModes>,
+) {
+    for (mut antagonist, connected_player_option) in antagonists.iter_mut() {
+        for objective in antagonist.objectives.iter_mut() {
+            if objective.completed {
+                continue;
+            }
+            match &objective.kind {
+                ObjectiveKind::Eliminate { target, .. } => {
+                    // A despawned target is gone for good too.
+                    let eliminated = match healths.get(*target) {
+                        Ok(health) => health.is_eliminated(),
+                        Err(_rr) => true,
+                    };
+                    if eliminated {
+                        objective.completed = true;
+                        send_objective_message(
+                            &mut net_game_modes,
+                            connected_player_option,
+                            "[color=".to_string()
+                                + HEALTHY_COLOR
+                                + "]Objective complete: "
+                                + &objective.description()
+                                + "[/color]",
+                        );
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+pub fn report_objectives(
+    mut round_state_changed: EventReader<RoundStateChanged>,
+    mut antagonists: Query<(
+        &mut Antagonist,
+        &Pawn,
+        &Inventory,
+        &Transform,
+        &Health,
+        Option<&PersistentPlayerData>,
+    )>,
+    items: Query<&EntityData>,
+    escape_areas: Res<EscapeAreas>,
+    mut round_end_summary: ResMut<RoundEndSummary>,
+) {
+    for state_changed in round_state_changed.iter() {
+        if state_changed.new != RoundState::Ending {
+            continue;
+        }
+
+        for (mut antagonist, pawn, inventory, transform, health, persistent_player_data_option) in
+            antagonists.iter_mut()
+        {
+            let alive = !health.is_eliminated();
+
+            // Stolen items have to be held and escapes made when the round ends.
+            for objective in antagonist.objectives.iter_mut() {
+                match &objective.kind {
+                    ObjectiveKind::Steal { entity_name, .. } => {
+                        objective.completed = holds_item(inventory, &items, entity_name);
+                    }
+                    ObjectiveKind::EscapeAlive => {
+                        objective.completed = alive && escape_areas.contains(transform.translation);
+                    }
+                    ObjectiveKind::Eliminate { .. } => {}
+                }
+            }
+
+            let user_name = match persistent_player_data_option {
+                Some(persistent_player_data) => persistent_player_data.user_name.clone(),
+                None => "unknown".to_string(),
+            };
+
+            round_end_summary.lines.push(format!(
+                "{} ({}) was a {}.",
+                pawn.name, user_name, antagonist.role
+            ));
+
+            for objective in antagonist.objectives.iter() {
+                let result = if objective.completed {
+                    "[color=".to_string() + HEALTHY_COLOR + "]Success[/color]"
+                } else {
+                    "[color=".to_string() + UNHEALTHY_COLOR + "]Failed[/color]"
+                };
+                round_end_summary
+                    .lines
+                    .push(format!("- {} {}", objective.description(), result));
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, SystemSet};
+use networking::messages::net_system;
+use api::{
+    console_commands::ConsoleCommandsLabels,
+    data::{PostUpdateLabels, StartupLabels},
+    rounds::RoundsLabels,
+};
+
+use crate::{
+    console_commands::{game_modes_console_commands, initialize_console_commands},
+    game_mode::{select_game_mode, startup_game_modes, ActiveGameMode, GameModeStarted, GameModes},
+    net::NetGameModes,
+    objectives::{eliminate_objectives, report_objectives, steal_objectives, EscapeAreas},
+    traitor::{
+        pick_traitors, start_traitor_mode, startup_traitor_config, TraitorConfig, TraitorSelection,
+    },
+};
+use bevy::app::CoreStage::PostUpdate;
+
+pub struct GameModesPlugin;
+
+impl Plugin for GameModesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameModes>()
+            .init_resource::<ActiveGameMode>()
+            .init_resource::<EscapeAreas>()
+            .add_event::<GameModeStarted>()
+            .add_event::<NetGameModes>()
+            .add_startup_system(startup_game_modes.label(StartupLabels::MiscResources))
+            .add_startup_system(
+                initialize_console_commands
+                    .before(ConsoleCommandsLabels::Finalize)
+                    .label(StartupLabels::ConsoleCommands),
+            )
+            .add_system(select_game_mode.after(RoundsLabels::Controller))
+            .add_system(game_modes_console_commands)
+            .add_system(steal_objectives)
+            .add_system(eliminate_objectives)
+            .add_system(
+                report_objectives
+                    .label(RoundsLabels::Summary)
+                    .after(RoundsLabels::Controller),
+            )
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetGameModes>),
+            );
+    }
+}
+
+pub struct TraitorModePlugin;
+
+impl Plugin for TraitorModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TraitorConfig>()
+            .init_resource::<TraitorSelection>()
+            .add_startup_system(startup_traitor_config.label(StartupLabels::MiscResources))
+            .add_system(start_traitor_mode)
+            .add_system(pick_traitors);
+    }
+}
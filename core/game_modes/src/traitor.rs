@@ -0,0 +1,200 @@
+use std::{fs, path::Path};
+
+use bevy::{
+    core::{Time, Timer},
+    prelude::{info, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, Without},
+};
+use pawn::pawn::Pawn;
+use rand::{prelude::SliceRandom, thread_rng, Rng};
+use serde::Deserialize;
+use api::{
+    chat::UNHEALTHY_COLOR,
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+    rounds::{RoundState, RoundStateChanged},
+};
+
+use crate::{
+    antagonist::{Antagonist, Objective, ObjectiveKind},
+    game_mode::GameModeStarted,
+    net::NetGameModes,
+    objectives::{send_objective_message, EscapeArea, EscapeAreas},
+};
+
+pub const TRAITOR_GAME_MODE_ID: &str = "traitor";
+
+#[derive(Deserialize, Clone)]
+pub struct StealTarget {
+    pub entity_name: String,
+    pub item_name: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TraitorConfig {
+    // Seconds after the round started, so that players had time to board.
+    pub selection_delay: f32,
+    pub players_per_traitor: u16,
+    pub max_traitors: u16,
+    pub objective_count: u8,
+    pub escape_objective: bool,
+    // Job ids that are never picked as traitor.
+    #[serde(default)]
+    pub protected_jobs: Vec<String>,
+    pub steal_targets: Vec<StealTarget>,
+    pub escape_areas: Vec<EscapeArea>,
+}
+
+#[derive(Default)]
+pub struct TraitorSelection {
+    pub timer: Option<Timer>,
+}
+
+pub fn startup_traitor_config(
+    mut traitor_config: ResMut<TraitorConfig>,
+    mut escape_areas: ResMut<EscapeAreas>,
+) {
+    let traitor_json = Path::new("data").join("game_modes").join("traitor.json");
+    let traitor_raw_json: String = fs::read_to_string(traitor_json)
+        .expect("startup_traitor_config() Error reading traitor.json from drive.");
+    *traitor_config = serde_json::from_str(&traitor_raw_json)
+        .expect("startup_traitor_config() Error parsing traitor.json String.");
+
+    escape_areas.areas = traitor_config.escape_areas.clone();
+}
+
+pub fn start_traitor_mode(
+    mut game_mode_started: EventReader<GameModeStarted>,
+    mut round_state_changed: EventReader<RoundStateChanged>,
+    traitor_config: Res<TraitorConfig>,
+    mut traitor_selection: ResMut<TraitorSelection>,
+) {
+    for started in game_mode_started.iter() {
+        if started.id != TRAITOR_GAME_MODE_ID {
+            continue;
+        }
+        traitor_selection.timer = Some(Timer::from_seconds(traitor_config.selection_delay, false));
+    }
+    for state_changed in round_state_changed.iter() {
+        if state_changed.new != RoundState::InProgress {
+            traitor_selection.timer = None;
+        }
+    }
+}
+
+pub fn pick_traitors(
+    time: Res<Time>,
+    traitor_config: Res<TraitorConfig>,
+    mut traitor_selection: ResMut<TraitorSelection>,
+    crew: Query<(Entity, &Pawn, &ConnectedPlayer), Without<Antagonist>>,
+    mut commands: Commands,
+    mut net_game_modes: EventWriter<NetGameModes>,
+) {
+    match traitor_selection.timer.as_mut() {
+        Some(timer) => {
+            timer.tick(time.delta());
+            if !timer.finished() {
+                return;
+            }
+        }
+        None => {
+            return;
+        }
+    }
+    traitor_selection.timer = None;
+
+    let mut rng = thread_rng();
+
+    let crew_members: Vec<(Entity, &Pawn, &ConnectedPlayer)> = crew.iter().collect();
+
+    let mut candidates: Vec<&(Entity, &Pawn, &ConnectedPlayer)> = crew_members
+        .iter()
+        .filter(|(_, pawn, connected_player)| {
+            connected_player.connected && !traitor_config.protected_jobs.contains(&pawn.job.id)
+        })
+        .collect();
+    candidates.shuffle(&mut rng);
+
+    let traitor_count = (crew_members.len() / traitor_config.players_per_traitor.max(1) as usize)
+        .max(1)
+        .min(traitor_config.max_traitors as usize)
+        .min(candidates.len());
+
+    let traitors: Vec<Entity> = candidates[..traitor_count]
+        .iter()
+        .map(|(entity, _, _)| *entity)
+        .collect();
+
+    for (traitor_entity, _, connected_player) in candidates[..traitor_count].iter() {
+        let targets: Vec<&(Entity, &Pawn, &ConnectedPlayer)> = crew_members
+            .iter()
+            .filter(|(entity, _, _)| !traitors.contains(entity))
+            .collect();
+
+        let mut objectives: Vec<Objective> = vec![];
+
+        for _ in 0..traitor_config.objective_count {
+            let steal = targets.is_empty() || rng.gen_bool(0.5);
+
+            let kind = if steal {
+                match traitor_config.steal_targets.choose(&mut rng) {
+                    Some(target) => ObjectiveKind::Steal {
+                        entity_name: target.entity_name.clone(),
+                        item_name: target.item_name.clone(),
+                    },
+                    None => {
+                        continue;
+                    }
+                }
+            } else {
+                let (target, target_pawn, _) = targets.choose(&mut rng).unwrap();
+                ObjectiveKind::Eliminate {
+                    target: *target,
+                    target_name: target_pawn.name.clone() + ", the " + &target_pawn.job.name,
+                }
+            };
+
+            let objective = Objective::new(kind);
+
+            // Rolling the same objective twice does not make for a second one.
+            if objectives
+                .iter()
+                .any(|o| o.description() == objective.description())
+            {
+                continue;
+            }
+            objectives.push(objective);
+        }
+
+        if traitor_config.escape_objective {
+            objectives.push(Objective::new(ObjectiveKind::EscapeAlive));
+        }
+
+        let antagonist = Antagonist {
+            role: "traitor".to_string(),
+            objectives,
+        };
+
+        for line in antagonist.objectives_text() {
+            send_objective_message(
+                &mut net_game_modes,
+                Some(*connected_player),
+                "[color=".to_string() + UNHEALTHY_COLOR + "]" + &line + "[/color]",
+            );
+        }
+        send_objective_message(
+            &mut net_game_modes,
+            Some(*connected_player),
+            "Use the \"objectives\" console command to see them again.".to_string(),
+        );
+        net_game_modes.send(NetGameModes {
+            handle: connected_player.handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                antagonist.objectives_text().join("\n"),
+            ),
+        });
+
+        commands.entity(*traitor_entity).insert(antagonist);
+    }
+
+    info!("Picked {} traitors.", traitor_count);
+}
@@ -3,16 +3,14 @@ use bevy::{
     math::Vec3,
     prelude::{warn, Commands, Entity, GlobalTransform, ResMut, Transform},
 };
-use bevy_rapier3d::prelude::{
-    CoefficientCombineRule, Collider, CollisionGroups, Friction, RigidBody,
-};
+use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, CollisionGroups, Friction, RigidBody};
 use data_converters::converters::string_vec3_to_vec3;
 use physics::physics::{get_bit_masks, ColliderGroup, CHARACTER_FLOOR_FRICTION};
 use api::{
     data::Vec3Int,
     gridmap::{
-        cell_id_to_world, to_doryen_coordinates, CellData, GridmapData, GridmapDetails1,
-        GridmapMain,
+        cell_id_to_world, get_deck, get_deck_floor_height, is_wall_layer, to_doryen_coordinates,
+        CellData, GridmapData, GridmapDetails1, GridmapMain, MAX_DECKS,
     },
     health::{HealthFlag, StructureHealth},
 };
@@ -20,45 +18,36 @@ use api::{
 pub fn build_gridmap_floor(commands: &mut Commands) {
     let masks = get_bit_masks(ColliderGroup::Standard);
 
-    //Floor
+    // One floor slab per deck, the slab above the top deck is the roof.
 
-    let mut friction_component = Friction::coefficient(CHARACTER_FLOOR_FRICTION);
-    friction_component.combine_rule = CoefficientCombineRule::Average;
+    for deck in 0..MAX_DECKS + 1 {
+        let mut friction_component = Friction::coefficient(CHARACTER_FLOOR_FRICTION);
 
-    commands
-        .spawn()
-        .insert(RigidBody::Fixed)
-        .insert(Transform::from_translation(Vec3::new(0., -1., 0.)))
-        .insert(GlobalTransform::default())
-        .with_children(|children| {
-            children
-                .spawn()
-                .insert(Collider::cuboid(500., 1., 500.))
-                .insert(friction_component)
-                .insert(CollisionGroups::new(masks.0, masks.1))
-                .insert(Transform::default())
-                .insert(GlobalTransform::default());
-        });
-
-    //Roof
-
-    let mut friction_component = Friction::coefficient(CHARACTER_FLOOR_FRICTION);
-    friction_component.combine_rule = CoefficientCombineRule::Min;
-
-    commands
-        .spawn()
-        .insert(RigidBody::Fixed)
-        .insert(Transform::from_translation(Vec3::new(0., 3., 0.)))
-        .insert(GlobalTransform::default())
-        .with_children(|children| {
-            children
-                .spawn()
-                .insert(Collider::cuboid(500., 1., 500.))
-                .insert(friction_component)
-                .insert(CollisionGroups::new(masks.0, masks.1))
-                .insert(Transform::default())
-                .insert(GlobalTransform::default());
-        });
+        if deck < MAX_DECKS {
+            friction_component.combine_rule = CoefficientCombineRule::Average;
+        } else {
+            friction_component.combine_rule = CoefficientCombineRule::Min;
+        }
+
+        commands
+            .spawn()
+            .insert(RigidBody::Fixed)
+            .insert(Transform::from_translation(Vec3::new(
+                0.,
+                get_deck_floor_height(deck) - 1.,
+                0.,
+            )))
+            .insert(GlobalTransform::default())
+            .with_children(|children| {
+                children
+                    .spawn()
+                    .insert(Collider::cuboid(500., 1., 500.))
+                    .insert(friction_component)
+                    .insert(CollisionGroups::new(masks.0, masks.1))
+                    .insert(Transform::default())
+                    .insert(GlobalTransform::default());
+            });
+    }
 }
 
 use std::collections::HashMap;
@@ -98,7 +87,9 @@ pub fn build_main_gridmap(
             }
         };
 
-        if cell_id_int.y == 0 {
+        gridmap_main.decks = gridmap_main.decks.max(get_deck(cell_id_int.y) + 1);
+
+        if is_wall_layer(cell_id_int.y) {
             // Wall
 
             if !gridmap_data
@@ -106,7 +97,7 @@ pub fn build_main_gridmap(
                 .contains(&cell_item_id)
             {
                 let coords = to_doryen_coordinates(cell_id_int.x, cell_id_int.z);
-                fov_map.map.set_transparent(coords.0, coords.1, false);
+                fov_map.decks[get_deck(cell_id_int.y)].set_transparent(coords.0, coords.1, false);
             }
         } else {
             // Floor cells dont have collision. Don't need to be an entity at this moment either.
@@ -11,7 +11,7 @@ use bevy_rapier3d::{
 };
 use physics::physics::{get_bit_masks, ColliderGroup, ReachResult};
 use api::{
-    gridmap::{GridmapData, GridmapMain},
+    gridmap::{is_wall_layer, GridmapData, GridmapMain},
     health::Health,
     pawn::REACH_DISTANCE,
 };
@@ -126,7 +126,7 @@ pub fn can_reach_entity(
                 match reach_result.hit_cell {
                     Some(cell_id) => {
                         // Assume all gridmap main wall items are blockers, work with _world_cells and _gridmap_data if you want to change this.
-                        if cell_id.y == 0 {
+                        if is_wall_layer(cell_id.y) {
                             if no_result_is_valid && collided_entities_length - 1 == this_i {
                                 in_reach = true;
                             }
@@ -5,11 +5,10 @@ pub fn gridmap_updates(
     mut net_gridmap_updates: EventWriter<NetGridmapUpdates>,
 ) {
     for (cell_id, cell_update) in gridmap_main.updates.iter_mut() {
-        let cell_coords = to_doryen_coordinates(cell_id.x, cell_id.z);
         for (senser_entity, senser_component, connected_player_component) in sensers.iter() {
             if connected_player_component.connected
                 && !cell_update.entities_received.contains(&senser_entity)
-                && senser_component.fov.is_in_fov(cell_coords.0, cell_coords.1)
+                && senser_component.is_cell_in_fov(cell_id)
             {
                 cell_update.entities_received.push(senser_entity);
                 if cell_update.cell_data.item != -1 {
@@ -40,12 +39,10 @@ pub fn gridmap_updates(
     }
 
     for (cell_id, cell_update) in gridmap_details1.updates.iter_mut() {
-        let cell_coords = to_doryen_coordinates(cell_id.x, cell_id.z);
-
         for (senser_entity, senser_component, connected_player_component) in sensers.iter() {
             if connected_player_component.connected
                 && !cell_update.entities_received.contains(&senser_entity)
-                && senser_component.fov.is_in_fov(cell_coords.0, cell_coords.1)
+                && senser_component.is_cell_in_fov(cell_id)
             {
                 cell_update.entities_received.push(senser_entity);
                 if cell_update.cell_data.item != -1 {
@@ -87,8 +84,8 @@ use api::{
     chat::{ASTRIX, EXAMINATION_EMPTY, FURTHER_ITALIC_FONT, FURTHER_NORMAL_FONT},
     data::{ConnectedPlayer, Vec3Int},
     gridmap::{
-        to_doryen_coordinates, CellData, GridMapType, GridmapData, GridmapDetails1, GridmapMain,
-        RemoveCell,
+        get_deck, is_wall_layer, to_doryen_coordinates, CellData, GridMapType, GridmapData,
+        GridmapDetails1, GridmapMain, RemoveCell,
     },
     health::{CellUpdate, StructureHealth},
     network::ReliableServerMessage,
@@ -143,7 +140,6 @@ pub fn get_empty_cell_message() -> String {
 }
 
 use bevy_rapier3d::prelude::RigidBody;
-use doryen_fov::FovAlgorithm;
 
 pub fn remove_cell(
     mut deconstruct_cell_events: EventReader<RemoveCell>,
@@ -158,8 +154,9 @@ pub fn remove_cell(
         match event.gridmap_type {
             GridMapType::Main => {
                 let coords = to_doryen_coordinates(event.id.x, event.id.z);
+                let deck = get_deck(event.id.y);
 
-                if event.id.y == 0 {
+                if is_wall_layer(event.id.y) {
                     // Wall
                     let cell_entity = gridmap_main
                         .grid_data
@@ -180,7 +177,7 @@ pub fn remove_cell(
                     }
 
                     commands.entity(cell_entity).despawn();
-                    fov_map.map.set_transparent(coords.0, coords.1, true);
+                    fov_map.decks[deck].set_transparent(coords.0, coords.1, true);
                 }
 
                 match gridmap_details1.data.get(&event.id) {
@@ -200,20 +197,10 @@ pub fn remove_cell(
                 }
 
                 for (mut senser_component, _connected_player_component) in sensers.iter_mut() {
-                    if senser_component.fov.is_in_fov(coords.0, coords.1) {
-                        senser_component.fov.clear_fov();
-                        let coords = to_doryen_coordinates(
-                            senser_component.cell_id.x,
-                            senser_component.cell_id.y,
-                        );
-                        senser_component.fov.compute_fov(
-                            &mut fov_map.map,
-                            coords.0,
-                            coords.1,
-                            FOV_DISTANCE,
-                            true,
-                        );
-
+                    if senser_component.is_cell_in_fov_window(&event.id) {
+                        senser_component.fov.invalidated = true;
+                    }
+                    if senser_component.is_cell_in_fov(&event.id) {
                         gridmap_main.updates.insert(
                             event.id,
                             CellUpdate {
@@ -242,14 +229,19 @@ pub fn remove_cell(
             }
         }
     }
+
+    // Recompute once per senser no matter how many cells changed in its window.
+    for (mut senser_component, _connected_player_component) in sensers.iter_mut() {
+        if senser_component.fov.invalidated {
+            let deck = senser_component.deck;
+            senser_component.update_fov(&fov_map.decks[deck]);
+        }
+    }
 }
 
 use serde::Deserialize;
 
-use super::{
-    fov::{DoryenMap, FOV_DISTANCE},
-    net::NetGridmapUpdates,
-};
+use super::{fov::DoryenMap, net::NetGridmapUpdates};
 
 #[derive(Component)]
 pub struct Cell {
@@ -13,46 +13,42 @@ pub fn projectile_fov(
     let mut projectiles_i: usize = 0;
 
     for event in projectile_fov_events.iter() {
-        match event.laser_projectile {
-            NetProjectileType::Laser(
-                laser_color,
-                laser_height,
-                laser_radius,
-                start_pos,
-                end_pos,
-            ) => {
-                let direction = (end_pos - start_pos).normalize();
-                let distance = start_pos.distance(end_pos);
-
-                let mut iterated_distance: f32 = 0.;
-
-                projectiles.insert(
-                    projectiles_i,
-                    (laser_color, laser_height, laser_radius, direction),
-                );
-
-                while iterated_distance < distance {
-                    let point = start_pos + (direction * iterated_distance);
-
-                    let cell_id = world_to_cell_id(point);
-
-                    match cell_ids_with_projectiles.get_mut(&cell_id) {
-                        Some(list) => {
-                            list.push((projectiles_i, point, distance, start_pos));
-                        }
-                        None => {
-                            cell_ids_with_projectiles
-                                .insert(cell_id, vec![(projectiles_i, point, distance, start_pos)]);
-                        }
-                    }
+        let (start_pos, end_pos) = match event.laser_projectile {
+            NetProjectileType::Laser(_, _, _, start_pos, end_pos) => (start_pos, end_pos),
+            NetProjectileType::Ballistic(_, start_pos, end_pos) => (start_pos, end_pos),
+        };
 
-                    iterated_distance += 2.;
-                }
+        let distance = start_pos.distance(end_pos);
+
+        if distance == 0. {
+            continue;
+        }
 
-                projectiles_i += 1;
+        let direction = (end_pos - start_pos).normalize();
+
+        let mut iterated_distance: f32 = 0.;
+
+        projectiles.insert(projectiles_i, (event.laser_projectile.clone(), direction));
+
+        while iterated_distance < distance {
+            let point = start_pos + (direction * iterated_distance);
+
+            let cell_id = world_to_cell_id(point);
+
+            match cell_ids_with_projectiles.get_mut(&cell_id) {
+                Some(list) => {
+                    list.push((projectiles_i, point, distance, start_pos));
+                }
+                None => {
+                    cell_ids_with_projectiles
+                        .insert(cell_id, vec![(projectiles_i, point, distance, start_pos)]);
+                }
             }
-            NetProjectileType::Ballistic => {}
+
+            iterated_distance += 2.;
         }
+
+        projectiles_i += 1;
     }
 
     if cell_ids_with_projectiles.len() == 0 {
@@ -63,9 +59,7 @@ pub fn projectile_fov(
         let mut used_projectiles_i = vec![];
 
         for (cell_id, projectiles_i_list) in cell_ids_with_projectiles.iter() {
-            let coords = to_doryen_coordinates(cell_id.x, cell_id.z);
-
-            match senser_component.fov.is_in_fov(coords.0, coords.1) {
+            match senser_component.is_cell_in_fov(cell_id) {
                 true => {
                     for (projectile_i, point, distance, start_pos) in projectiles_i_list.iter() {
                         if used_projectiles_i.contains(projectile_i) {
@@ -74,8 +68,7 @@ pub fn projectile_fov(
 
                         used_projectiles_i.push(*projectile_i);
 
-                        let (laser_color, laser_height, laser_radius, direction) =
-                            projectiles.get(*projectile_i).unwrap();
+                        let (projectile_type, direction) = projectiles.get(*projectile_i).unwrap();
 
                         let mut iterated_distance: f32 = 0.;
 
@@ -98,7 +91,6 @@ pub fn projectile_fov(
                             let mut cell_is_blocked = true;
                             let new_point = *start_pos + (iterated_distance * *direction);
                             let cell_id = world_to_cell_id(new_point);
-                            let coords = to_doryen_coordinates(cell_id.x, cell_id.z);
 
                             if !too_far {
                                 match gridmap_main.grid_data.get(&cell_id) {
@@ -119,8 +111,7 @@ pub fn projectile_fov(
                             }
 
                             match (!too_far)
-                                && (!cell_is_blocked
-                                    && senser_component.fov.is_in_fov(coords.0, coords.1))
+                                && (!cell_is_blocked && senser_component.is_cell_in_fov(&cell_id))
                             {
                                 true => {
                                     if negative_distance {
@@ -148,17 +139,32 @@ pub fn projectile_fov(
                             continue;
                         }
 
-                        net_projectile_fov.send(NetProjectileFOV {
-                            handle: connected_player_component.handle,
-                            message: ReliableServerMessage::FireProjectile(
-                                NetProjectileType::Laser(
-                                    *laser_color,
-                                    *laser_height,
-                                    *laser_radius,
+                        let net_projectile_type = match projectile_type {
+                            NetProjectileType::Laser(
+                                laser_color,
+                                laser_height,
+                                laser_radius,
+                                _,
+                                _,
+                            ) => NetProjectileType::Laser(
+                                *laser_color,
+                                *laser_height,
+                                *laser_radius,
+                                adjusted_start_pos,
+                                adjusted_end_pos,
+                            ),
+                            NetProjectileType::Ballistic(speed, _, _) => {
+                                NetProjectileType::Ballistic(
+                                    *speed,
                                     adjusted_start_pos,
                                     adjusted_end_pos,
-                                ),
-                            ),
+                                )
+                            }
+                        };
+
+                        net_projectile_fov.send(NetProjectileFOV {
+                            handle: connected_player_component.handle,
+                            message: ReliableServerMessage::FireProjectile(net_projectile_type),
                         });
                     }
                 }
@@ -168,38 +174,39 @@ pub fn projectile_fov(
     }
 }
 
+// One occlusion map per deck, shared by the sensers on it.
 pub struct DoryenMap {
-    pub map: MapData,
+    pub decks: Vec<OcclusionMap>,
 }
 
 impl Default for DoryenMap {
     fn default() -> Self {
         DoryenMap {
-            map: MapData::new(FOV_MAP_WIDTH, FOV_MAP_WIDTH),
+            decks: (0..MAX_DECKS)
+                .map(|_| OcclusionMap::new(FOV_MAP_WIDTH, FOV_MAP_WIDTH))
+                .collect(),
         }
     }
 }
 
 use bevy::{
     math::Vec3,
-    prelude::{EventReader, EventWriter, Query, Res, ResMut, Transform},
+    prelude::{EventReader, EventWriter, Query, Res, Transform},
 };
-use doryen_fov::{FovAlgorithm, MapData};
 use api::{
     combat::{NetProjectileType, ProjectileFOV},
     data::{ConnectedPlayer, Vec2Int, Vec3Int},
-    gridmap::{to_doryen_coordinates, world_to_cell_id, GridmapData, GridmapMain, FOV_MAP_WIDTH},
+    fov::OcclusionMap,
+    gridmap::{get_deck, world_to_cell_id, GridmapData, GridmapMain, FOV_MAP_WIDTH, MAX_DECKS},
     network::ReliableServerMessage,
     senser::Senser,
 };
 
 use super::net::NetProjectileFOV;
 
-pub const FOV_DISTANCE: usize = 23;
-
 pub fn senser_update_fov(
     mut senser_entities: Query<(&mut Senser, &Transform)>,
-    mut map: ResMut<DoryenMap>,
+    map: Res<DoryenMap>,
 ) {
     for (mut senser_component, rigid_body_position_component) in senser_entities.iter_mut() {
         let senser_cell_id_3 = world_to_cell_id(rigid_body_position_component.translation.into());
@@ -207,23 +214,21 @@ pub fn senser_update_fov(
             x: senser_cell_id_3.x,
             y: senser_cell_id_3.z,
         };
+        let senser_deck = get_deck(senser_cell_id_3.y);
 
-        if senser_component.cell_id != senser_cell_id
+        if (senser_component.cell_id != senser_cell_id
+            || senser_component.deck != senser_deck
+            || senser_component.fov.invalidated)
             && senser_cell_id.x < FOV_MAP_WIDTH as i16 / 2
             && senser_cell_id.x > -(FOV_MAP_WIDTH as i16) / 2
             && senser_cell_id.y < FOV_MAP_WIDTH as i16 / 2
             && senser_cell_id.y > -(FOV_MAP_WIDTH as i16) / 2
         {
             senser_component.cell_id = senser_cell_id;
+            senser_component.deck = senser_deck;
 
-            // 240000 ns. 1/4th of a ms. 4x/ms (expensive.)
-            // Will need a faster replacement at some point. Because scales bad with huge maps. Capped to 500x500 tiles (1kmx1km) for time being.
-            // Max map size support is around 4kmx4km but will need a faster FOV algorithm for that.
-            senser_component.fov.clear_fov();
-            let coords = to_doryen_coordinates(senser_cell_id.x, senser_cell_id.y);
-            senser_component
-                .fov
-                .compute_fov(&mut map.map, coords.0, coords.1, FOV_DISTANCE, true);
+            // Only walks the window around the senser, the map size does not add to the cost.
+            senser_component.update_fov(&map.decks[senser_deck]);
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fs, path::Path};
 
-use bevy::prelude::{info, Commands, EventWriter, Res, ResMut, Transform};
+use bevy::prelude::{info, Commands, EventReader, EventWriter, Res, ResMut, Transform};
 use bevy_rapier3d::{
     plugin::{RapierConfiguration, TimestepMode},
     prelude::{CoefficientCombineRule, Collider},
@@ -15,6 +15,7 @@ use api::{
         MainCellProperties,
     },
     pawn::{SpawnPoint, SpawnPointRaw, SpawnPoints},
+    rounds::ResetWorld,
 };
 
 use crate::{
@@ -904,3 +905,21 @@ pub fn startup_build_map(
 
     info!("Spawned {} entities.", current_map_entities_data.len());
 }
+
+// Empties the gridmap, the rounds plugin builds it again the frame after.
+pub fn reset_gridmap(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut gridmap_main: ResMut<GridmapMain>,
+    mut gridmap_details1: ResMut<GridmapDetails1>,
+    mut fov_map: ResMut<DoryenMap>,
+    mut spawn_points: ResMut<SpawnPoints>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    *gridmap_main = GridmapMain::default();
+    *gridmap_details1 = GridmapDetails1::default();
+    *fov_map = DoryenMap::default();
+    spawn_points.i = 0;
+}
@@ -9,9 +9,10 @@ use api::{
     examinable::RichName,
     gridmap::{ExamineMapMessage, GridmapData, GridmapDetails1, GridmapMain, RemoveCell},
     pawn::SpawnPoints,
+    rounds::RoundsLabels,
 };
 
-use crate::init::{startup_build_map, startup_map_cells, startup_misc_resources};
+use crate::init::{reset_gridmap, startup_build_map, startup_map_cells, startup_misc_resources};
 
 use super::{
     events::{gridmap_updates, remove_cell},
@@ -81,6 +82,11 @@ impl Plugin for GridmapPlugin {
                     .with_system(net_system::<NetGridmapUpdates>),
             )
             .add_system(gridmap_sensing_ability)
+            .add_system(
+                reset_gridmap
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
             .add_event::<ExamineMapMessage>();
     }
 }
@@ -447,7 +447,7 @@ pub fn examine_entity(
     }
 }
 
-use api::gridmap::{to_doryen_coordinates, GridmapExamineMessages, GridmapMain};
+use api::gridmap::{GridmapExamineMessages, GridmapMain};
 
 pub struct NetConnExamine {
     pub handle: u64,
@@ -501,11 +501,7 @@ pub fn examine_map(
             }
         }
 
-        let coords = to_doryen_coordinates(
-            examine_event.gridmap_cell_id.x,
-            examine_event.gridmap_cell_id.z,
-        );
-        if examiner_senser_component.fov.is_in_fov(coords.0, coords.1) {
+        if examiner_senser_component.is_cell_in_fov(&examine_event.gridmap_cell_id) {
             match ship_cell_option {
                 Some(ship_cell) => {
                     let mut message = "".to_string();
@@ -91,6 +91,16 @@ pub fn examine_entity(
                                 examine_text = examine_text
                                     + &examinable.name.get_a_name()
                                     + " is attached to his holster.\n";
+                            } else if slot.slot_name == "id" {
+                                examine_text = examine_text
+                                    + "He is wearing "
+                                    + &examinable.name.get_a_name()
+                                    + " on his chest.\n";
+                            } else if slot.slot_name == "headset" {
+                                examine_text = examine_text
+                                    + "He is wearing "
+                                    + &examinable.name.get_a_name()
+                                    + " on his ear.\n";
                             } else {
                                 examine_text = examine_text
                                     + "He is wearing "
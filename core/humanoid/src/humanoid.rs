@@ -24,7 +24,7 @@ use api::{
     inventory::Inventory,
     load_entity::NetUnloadEntity,
     network::ReliableServerMessage,
-    pawn::PawnYAxisRotations,
+    pawn::{KnockDown, PawnYAxisRotations},
     sensable::Sensable,
 };
 use sounds::{
@@ -51,6 +51,10 @@ pub enum CharacterAnimationState {
     Idle,
     Jogging,
     Sprinting,
+    // Lying on the floor until knocked_down_timer_option finishes.
+    KnockedDown,
+    // Name of the emote animation to travel to, played until emote_timer finishes or the pawn moves.
+    Emoting(String),
 }
 
 const FIRST_MELEE_TIME: u64 = 433;
@@ -63,6 +67,8 @@ impl Default for Humanoid {
         t.tick(Duration::from_millis(FIRST_MELEE_TIME));
         Self {
             current_lower_animation_state: CharacterAnimationState::Idle,
+            emote_timer: Timer::new(Duration::from_secs(0), false),
+            knocked_down_timer_option: None,
             character_name: "".to_string(),
             combat_mode: false,
             facing_direction: 0.,
@@ -83,6 +89,21 @@ pub struct NetHumanoid {
     pub message: ReliableServerMessage,
 }
 
+pub fn knock_down(
+    mut knock_down_events: EventReader<KnockDown>,
+    mut humanoids: Query<&mut Humanoid>,
+) {
+    for event in knock_down_events.iter() {
+        match humanoids.get_mut(event.entity) {
+            Ok(mut humanoid_component) => {
+                humanoid_component.knocked_down_timer_option =
+                    Some(Timer::from_seconds(event.duration, false));
+            }
+            Err(_rr) => {}
+        }
+    }
+}
+
 pub fn toggle_combat_mode(
     mut toggle_combat_mode_events: EventReader<InputToggleCombatMode>,
     mut standard_character_query: Query<&mut Humanoid>,
@@ -231,6 +252,33 @@ pub fn humanoids(
             player_input_movement_vector = player_input_component.movement_vector;
         }
 
+        let mut knocked_down = false;
+
+        match &mut standard_character_component.knocked_down_timer_option {
+            Some(timer) => {
+                timer.tick(time.delta());
+                knocked_down = !timer.finished();
+            }
+            None => {}
+        }
+
+        if !knocked_down {
+            standard_character_component.knocked_down_timer_option = None;
+
+            if matches!(
+                standard_character_component.current_lower_animation_state,
+                CharacterAnimationState::KnockedDown
+            ) {
+                standard_character_component.current_lower_animation_state =
+                    CharacterAnimationState::Idle;
+            }
+        }
+
+        let player_input_movement_vector = match knocked_down {
+            true => Vec2::ZERO,
+            false => player_input_movement_vector,
+        };
+
         if player_input_movement_vector.x.abs() == 1. && player_input_movement_vector.y.abs() == 1.
         {
             speed_factor *= 0.75;
@@ -283,6 +331,19 @@ pub fn humanoids(
             .tick(delta_time);
         let ready_to_attack_this_frame = standard_character_component.next_attack_timer.finished();
 
+        if matches!(
+            standard_character_component.current_lower_animation_state,
+            CharacterAnimationState::Emoting(_)
+        ) {
+            standard_character_component.emote_timer.tick(delta_time);
+            if standard_character_component.emote_timer.finished()
+                || standard_character_component.combat_mode
+            {
+                standard_character_component.current_lower_animation_state =
+                    CharacterAnimationState::Idle;
+            }
+        }
+
         // If combat mode, specific new rotation based on mouse direction.
         if standard_character_component.combat_mode && !player_input_component.sprinting {
             let active_slot = inventory_component.get_slot(&inventory_component.active_slot);
@@ -599,6 +660,9 @@ pub fn humanoids(
 
         //let current_linear_velocity: Vec3 = rigid_body_velocity_component.linvel.into();
 
+        // Stops the footsteps of knocked down pawns.
+        let idle = idle || knocked_down;
+
         match (standard_character_component.combat_mode && idle/*&& current_linear_velocity.length() < 0.05*/)
             || (standard_character_component.combat_mode == false && idle)
         {
@@ -850,6 +914,11 @@ pub fn humanoids(
             }
         }
 
+        if knocked_down {
+            standard_character_component.current_lower_animation_state =
+                CharacterAnimationState::KnockedDown;
+        }
+
         let bevy_velocity: Vec3 = rigid_body_velocity_component.linvel.into();
         let speed = bevy_velocity.length();
 
@@ -1016,6 +1085,9 @@ pub fn humanoids(
 #[derive(Component)]
 pub struct Humanoid {
     pub current_lower_animation_state: CharacterAnimationState,
+    pub emote_timer: Timer,
+    // Set while knocked down, the humanoid ignores movement input until it finishes.
+    pub knocked_down_timer_option: Option<Timer>,
     pub character_name: String,
     pub combat_mode: bool,
     pub facing_direction: f32,
@@ -3,11 +3,12 @@ use networking::messages::net_system;
 use api::{
     data::{PostUpdateLabels, UpdateLabels},
     examinable::ExamineLabels,
+    pawn::KnockDown,
 };
 
 use crate::{
     examine_events::{examine_entity, ExamineEntityPawn},
-    humanoid::toggle_combat_mode,
+    humanoid::{knock_down, toggle_combat_mode},
 };
 use bevy::app::CoreStage::PostUpdate;
 
@@ -22,6 +23,8 @@ impl Plugin for HumanoidPlugin {
                 .after(UpdateLabels::ProcessMovementInput),
         )
         .add_system(toggle_combat_mode)
+        .add_event::<KnockDown>()
+        .add_system(knock_down.before(UpdateLabels::StandardCharacters))
         .add_system(examine_entity.after(ExamineLabels::Default))
         .add_event::<ExamineEntityPawn>()
         .add_system_set_to_stage(
@@ -287,6 +287,8 @@ pub fn drop_current_item(
 
                     match handle_option {
                         Some(handle) => {
+                            // Not batched, EntityUpdateBatching lives in connected_player and
+                            // this has to reach the client before the DropItem message below.
                             net_drop_current_item.send(NetDropCurrentItem {
                                 handle: *handle,
                                 message: ReliableServerMessage::EntityUpdate(
@@ -853,6 +855,7 @@ pub fn throw_item(
 
                     match handle_option {
                         Some(handle) => {
+                            // Unbatched like the drop, it stays in order with the throw message.
                             net_throw_item.send(NetThrowItem {
                                 handle: *handle,
                                 message: ReliableServerMessage::EntityUpdate(
@@ -65,6 +65,9 @@ impl InventoryItem {
     pub fn get_default_laser_words() -> Vec<String> {
         vec!["shot".to_string(), "hit".to_string(), "beamed".to_string()]
     }
+    pub fn get_default_ballistic_words() -> Vec<String> {
+        vec!["shot".to_string(), "hit".to_string(), "gunned".to_string()]
+    }
     pub fn get_default_trigger_weapon_words() -> Vec<String> {
         vec!["fired".to_string(), "shot".to_string()]
     }
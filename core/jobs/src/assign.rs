@@ -0,0 +1,72 @@
+use bevy::prelude::{warn, Entity};
+use rand::prelude::SliceRandom;
+
+use crate::jobs::{JobData, JobSlots, Jobs};
+
+pub struct JobRequest {
+    pub entity: Entity,
+    pub handle: u64,
+    pub preference: Option<String>,
+}
+
+// Requests are drawn in random order so players that board before the round starts have equal
+// odds of getting a limited job. Whoever doesn't get their preference gets the default job.
+pub fn assign_jobs(
+    mut requests: Vec<JobRequest>,
+    jobs: &Jobs,
+    job_slots: &mut JobSlots,
+) -> Vec<(Entity, JobData)> {
+    let mut rng = rand::thread_rng();
+    requests.shuffle(&mut rng);
+
+    let mut assigned = vec![];
+    let mut unassigned = vec![];
+
+    for request in requests {
+        let preferred_job_option = match &request.preference {
+            Some(job_id) => jobs.get(job_id),
+            None => None,
+        };
+
+        match preferred_job_option {
+            Some(preferred_job) => {
+                if preferred_job.selectable && job_slots.take(preferred_job, request.handle) {
+                    assigned.push((request.entity, preferred_job.clone()));
+                } else {
+                    unassigned.push(request);
+                }
+            }
+            None => {
+                unassigned.push(request);
+            }
+        }
+    }
+
+    for request in unassigned {
+        assigned.push((
+            request.entity,
+            get_fallback_job(request.handle, jobs, job_slots),
+        ));
+    }
+
+    assigned
+}
+
+// The default job, or any selectable job with a free slot once the default job is full.
+fn get_fallback_job(handle: u64, jobs: &Jobs, job_slots: &mut JobSlots) -> JobData {
+    let default_job = jobs.get_default();
+
+    if job_slots.take(default_job, handle) {
+        return default_job.clone();
+    }
+
+    for job in jobs.get_selectable() {
+        if job_slots.take(job, handle) {
+            return job.clone();
+        }
+    }
+
+    warn!("Every job is full, overfilling the default job.");
+    job_slots.occupy(default_job, handle);
+    default_job.clone()
+}
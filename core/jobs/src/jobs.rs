@@ -0,0 +1,263 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use bevy::prelude::{info, Changed, Component, EventReader, Query, ResMut, With};
+use pawn::pawn::{Pawn, ShipAuthorizationEnum, ShipJob};
+use serde::Deserialize;
+use api::{data::ConnectedPlayer, health::Health, rounds::ResetWorld};
+
+pub const DUMMY_JOB_ID: &str = "dummy";
+
+#[derive(Deserialize, Clone)]
+pub struct JobData {
+    pub id: String,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    // None for unlimited slots.
+    pub slots: Option<u8>,
+    pub selectable: bool,
+    // (slot_name, entity_name)
+    pub inventory: Vec<(String, String)>,
+    pub access: Vec<ShipAuthorizationEnum>,
+    pub radio_channels: Vec<String>,
+    // Spoken on top of the languages everybody knows.
+    pub languages: Vec<String>,
+    pub spawn_point_type: String,
+}
+
+impl JobData {
+    pub fn get_ship_job(&self) -> ShipJob {
+        ShipJob {
+            id: self.id.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct JobsRaw {
+    pub default_job: String,
+    pub jobs: Vec<JobData>,
+}
+
+#[derive(Default)]
+pub struct Jobs {
+    pub list: Vec<JobData>,
+    pub default_job: String,
+}
+
+impl Jobs {
+    pub fn get(&self, job_id: &str) -> Option<&JobData> {
+        self.list.iter().find(|job| job.id == job_id)
+    }
+
+    pub fn get_default(&self) -> &JobData {
+        self.get(&self.default_job)
+            .expect("jobs.rs get_default() couldn't find default job.")
+    }
+
+    pub fn get_selectable(&self) -> Vec<&JobData> {
+        self.list.iter().filter(|job| job.selectable).collect()
+    }
+}
+
+// Amount of players that have been given each job and the job each player handle holds.
+#[derive(Default)]
+pub struct JobSlots {
+    pub taken: HashMap<String, u8>,
+    pub holders: HashMap<u64, String>,
+}
+
+impl JobSlots {
+    pub fn has_free_slot(&self, job: &JobData) -> bool {
+        match job.slots {
+            Some(slots) => match self.taken.get(&job.id) {
+                Some(taken) => *taken < slots,
+                None => slots > 0,
+            },
+            None => true,
+        }
+    }
+
+    // Takes a slot of the job for the player if one is free.
+    pub fn take(&mut self, job: &JobData, handle: u64) -> bool {
+        self.release(handle);
+
+        if !self.has_free_slot(job) {
+            return false;
+        }
+
+        self.occupy(job, handle);
+        true
+    }
+
+    // Takes a slot of the job for the player even if the job is full.
+    pub fn occupy(&mut self, job: &JobData, handle: u64) {
+        self.release(handle);
+
+        *self.taken.entry(job.id.clone()).or_insert(0) += 1;
+        self.holders.insert(handle, job.id.clone());
+    }
+
+    // Frees the slot held by the player, if any.
+    pub fn release(&mut self, handle: u64) {
+        match self.holders.remove(&handle) {
+            Some(job_id) => match self.taken.get_mut(&job_id) {
+                Some(taken) => {
+                    *taken = taken.saturating_sub(1);
+                }
+                None => {}
+            },
+            None => {}
+        }
+    }
+}
+
+// Eliminated players free their job slot for players boarding later.
+pub fn release_eliminated_job_slots(
+    pawns: Query<(&ConnectedPlayer, &Health), (With<Pawn>, Changed<Health>)>,
+    mut job_slots: ResMut<JobSlots>,
+) {
+    for (connected_player, health) in pawns.iter() {
+        if health.is_eliminated() {
+            job_slots.release(connected_player.handle);
+        }
+    }
+}
+
+pub fn reset_job_slots(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut job_slots: ResMut<JobSlots>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    *job_slots = JobSlots::default();
+}
+
+#[derive(Component, Clone)]
+pub struct AssignedJob {
+    pub job: JobData,
+}
+
+pub fn startup_jobs(mut jobs: ResMut<Jobs>) {
+    let jobs_json = Path::new("data").join("jobs").join("jobs.json");
+    let jobs_raw_json: String =
+        fs::read_to_string(jobs_json).expect("startup_jobs() Error reading jobs.json from drive.");
+    let jobs_raw: JobsRaw = serde_json::from_str(&jobs_raw_json)
+        .expect("startup_jobs() Error parsing jobs.json String.");
+
+    jobs.list = jobs_raw.jobs;
+    jobs.default_job = jobs_raw.default_job;
+
+    // Validate early, every fallback assignment depends on it.
+    jobs.get_default();
+
+    info!("Loaded {} jobs.", jobs.list.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JobData, JobSlots};
+
+    fn job(id: &str, slots: Option<u8>) -> JobData {
+        JobData {
+            id: id.to_string(),
+            name: id.to_string(),
+            title: id.to_string(),
+            description: "".to_string(),
+            slots,
+            selectable: true,
+            inventory: vec![],
+            access: vec![],
+            radio_channels: vec![],
+            languages: vec![],
+            spawn_point_type: "generic".to_string(),
+        }
+    }
+
+    #[test]
+    fn take_respects_slot_limit() {
+        let control = job("control", Some(1));
+        let mut job_slots = JobSlots::default();
+
+        assert!(job_slots.take(&control, 1));
+        assert!(!job_slots.take(&control, 2));
+        assert!(!job_slots.has_free_slot(&control));
+        assert_eq!(job_slots.holders.get(&2), None);
+    }
+
+    #[test]
+    fn zero_slots_is_never_free() {
+        let dummy = job("dummy", Some(0));
+        let mut job_slots = JobSlots::default();
+
+        assert!(!job_slots.has_free_slot(&dummy));
+        assert!(!job_slots.take(&dummy, 1));
+    }
+
+    #[test]
+    fn unlimited_jobs_always_have_a_slot() {
+        let security = job("security", None);
+        let mut job_slots = JobSlots::default();
+
+        for handle in 0..300 {
+            assert!(job_slots.take(&security, handle));
+        }
+        assert!(job_slots.has_free_slot(&security));
+    }
+
+    #[test]
+    fn release_frees_the_slot() {
+        let control = job("control", Some(1));
+        let mut job_slots = JobSlots::default();
+
+        job_slots.take(&control, 1);
+        job_slots.release(1);
+
+        assert!(job_slots.has_free_slot(&control));
+        assert!(job_slots.take(&control, 2));
+        assert_eq!(job_slots.holders.get(&2), Some(&"control".to_string()));
+    }
+
+    #[test]
+    fn release_is_idempotent() {
+        let control = job("control", Some(2));
+        let mut job_slots = JobSlots::default();
+
+        job_slots.take(&control, 1);
+        job_slots.take(&control, 2);
+        job_slots.release(1);
+        job_slots.release(1);
+        job_slots.release(3);
+
+        assert_eq!(job_slots.taken.get("control"), Some(&1));
+    }
+
+    #[test]
+    fn taking_again_moves_the_slot() {
+        let control = job("control", Some(1));
+        let security = job("security", None);
+        let mut job_slots = JobSlots::default();
+
+        job_slots.take(&control, 1);
+        job_slots.take(&security, 1);
+
+        assert!(job_slots.has_free_slot(&control));
+        assert_eq!(job_slots.taken.get("security"), Some(&1));
+    }
+
+    #[test]
+    fn occupy_overflows_the_limit() {
+        let control = job("control", Some(1));
+        let mut job_slots = JobSlots::default();
+
+        job_slots.take(&control, 1);
+        job_slots.occupy(&control, 2);
+
+        assert_eq!(job_slots.taken.get("control"), Some(&2));
+        job_slots.release(2);
+        assert_eq!(job_slots.taken.get("control"), Some(&1));
+    }
+}
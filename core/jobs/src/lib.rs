@@ -0,0 +1,3 @@
+pub mod assign;
+pub mod jobs;
+pub mod plugin;
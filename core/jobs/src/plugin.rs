@@ -0,0 +1,20 @@
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin};
+use api::{data::StartupLabels, rounds::RoundsLabels};
+
+use crate::jobs::{release_eliminated_job_slots, reset_job_slots, startup_jobs, JobSlots, Jobs};
+
+pub struct JobsPlugin;
+
+impl Plugin for JobsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Jobs>()
+            .init_resource::<JobSlots>()
+            .add_startup_system(startup_jobs.before(StartupLabels::InitEntities))
+            .add_system(release_eliminated_job_slots)
+            .add_system(
+                reset_job_slots
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            );
+    }
+}
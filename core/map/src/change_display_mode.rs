@@ -38,6 +38,7 @@ pub fn change_display_mode(
         if event.display_mode == "atmospherics_temperature"
             || event.display_mode == "atmospherics_pressure"
             || event.display_mode == "atmospherics_liveable"
+            || event.display_mode == "atmospherics_fire"
         {
             if data_link_component
                 .links
@@ -72,6 +72,7 @@ pub struct Map {
     pub view_range: usize,
     pub camera_position: Vec2,
     pub passed_mouse_cell: Option<(i16, i16)>,
+    pub deck: usize,
 }
 impl Default for Map {
     fn default() -> Self {
@@ -81,6 +82,7 @@ impl Default for Map {
             view_range: 20,
             camera_position: Vec2::default(),
             passed_mouse_cell: None,
+            deck: 0,
         }
     }
 }
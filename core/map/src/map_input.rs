@@ -1,16 +1,20 @@
 use bevy::{
     math::Vec2,
-    prelude::{EventReader, EventWriter, Query},
+    prelude::{EventReader, EventWriter, Query, Res, ResMut},
 };
 use networking::messages::{InputMap, InputMapRequestDisplayModes, MapInput};
 use api::{
-    gridmap::FOV_MAP_WIDTH,
+    gridmap::{get_deck, GridmapMain, FOV_MAP_WIDTH, MAX_DECKS},
     network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage},
 };
 
 pub fn map_input(
     mut input_view_range_change_events: EventReader<InputMap>,
     mut map_holders: Query<&mut Map>,
+    mut display_atmos_state: ResMut<MapHolders>,
+    gridmap_main: Res<GridmapMain>,
+    map_data: Res<MapData>,
+    mut net: EventWriter<NetMapSelectDeck>,
 ) {
     for event in input_view_range_change_events.iter() {
         match map_holders.get_mut(event.entity) {
@@ -27,6 +31,32 @@ pub fn map_input(
                 MapInput::MouseCell(idx, idy) => {
                     map_component.passed_mouse_cell = Some((idx, idy));
                 }
+                MapInput::Deck(deck) => {
+                    let decks = gridmap_main.decks.max(1);
+                    map_component.deck = (deck as usize).min(decks - 1);
+
+                    match display_atmos_state.holders.get_mut(&event.entity) {
+                        Some(atmospherics_map_state) => {
+                            atmospherics_map_state.reset_cache = true;
+                        }
+                        None => {}
+                    }
+
+                    net.send(NetMapSelectDeck {
+                        handle: event.handle,
+                        message: ReliableServerMessage::MapSelectedDeck(
+                            map_component.deck as u8,
+                            decks as u8,
+                        ),
+                    });
+
+                    for add in map_data.to_net(map_component.deck) {
+                        net.send(NetMapSelectDeck {
+                            handle: event.handle,
+                            message: ReliableServerMessage::MapDefaultAddition(add.0, add.1, add.2),
+                        });
+                    }
+                }
             },
             Err(_) => {
                 continue;
@@ -74,19 +104,48 @@ impl PendingMessage for NetRequestDisplayModes {
     }
 }
 
-use api::data::Vec2Int;
+pub struct NetMapSelectDeck {
+    pub handle: u64,
+    pub message: ReliableServerMessage,
+}
+impl PendingMessage for NetMapSelectDeck {
+    fn get_message(&self) -> PendingNetworkMessage {
+        PendingNetworkMessage {
+            handle: self.handle,
+            message: self.message.clone(),
+        }
+    }
+}
+
+use api::data::{Vec2Int, Vec3Int};
 use std::collections::HashMap;
 
-use crate::map::Map;
-#[derive(Default)]
+use crate::map::{Map, MapHolders};
+// Default map additions of every deck.
 pub struct MapData {
-    pub data: HashMap<Vec2Int, i16>,
+    pub data: Vec<HashMap<Vec2Int, i16>>,
+}
+impl Default for MapData {
+    fn default() -> Self {
+        Self {
+            data: vec![HashMap::new(); MAX_DECKS],
+        }
+    }
 }
 impl MapData {
-    pub fn to_net(&self) -> Vec<(i16, i16, i16)> {
+    pub fn insert(&mut self, cell_id: Vec3Int, item: i16) {
+        self.data[get_deck(cell_id.y)].insert(
+            Vec2Int {
+                x: cell_id.x,
+                y: cell_id.z,
+            },
+            item,
+        );
+    }
+    pub fn to_net(&self, deck: usize) -> Vec<(i16, i16, i16)> {
         let mut net_data = vec![];
 
-        for (id, item) in self.data.iter() {
+        for (id, item) in self.data[deck].iter() {
             net_data.push((id.x, id.y, *item));
         }
 
@@ -6,7 +6,7 @@ use crate::{map::MapHolders, map_input::MapData};
 
 use super::{
     change_display_mode::change_display_mode,
-    map_input::{map_input, request_display_modes, NetRequestDisplayModes},
+    map_input::{map_input, request_display_modes, NetMapSelectDeck, NetRequestDisplayModes},
 };
 use bevy::app::CoreStage::PostUpdate;
 
@@ -16,6 +16,7 @@ impl Plugin for MapPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MapData>()
             .add_event::<NetRequestDisplayModes>()
+            .add_event::<NetMapSelectDeck>()
             .add_system(change_display_mode.label(MapLabels::ChangeMode))
             .add_system(request_display_modes)
             .add_system(map_input.label(MapLabels::ChangeMode))
@@ -24,7 +25,8 @@ impl Plugin for MapPlugin {
                 SystemSet::new()
                     .after(PostUpdateLabels::VisibleChecker)
                     .label(PostUpdateLabels::Net)
-                    .with_system(net_system::<NetRequestDisplayModes>),
+                    .with_system(net_system::<NetRequestDisplayModes>)
+                    .with_system(net_system::<NetMapSelectDeck>),
             )
             .init_resource::<MapHolders>();
     }
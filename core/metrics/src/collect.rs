@@ -0,0 +1,99 @@
+use std::time::Instant;
+
+use bevy::prelude::{Entity, EventReader, Query, Res, SystemLabel, World};
+use api::{
+    data::ConnectedPlayer,
+    entity_updates::{EntityData, EntityGroup},
+    metrics::Metrics,
+};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum MetricsLabels {
+    Collect,
+}
+
+// The tick is measured from the start of the first stage to the end of the last one.
+pub const FIRST_STAGE: &str = "First";
+pub const LAST_STAGE: &str = "Last";
+
+pub fn start_stage_timer(stage: &'static str) -> impl FnMut(&mut World) {
+    move |world: &mut World| match world.get_resource::<Metrics>() {
+        Some(metrics) => {
+            metrics.lock().stage_started.insert(stage, Instant::now());
+        }
+        None => {}
+    }
+}
+
+pub fn stop_stage_timer(stage: &'static str) -> impl FnMut(&mut World) {
+    move |world: &mut World| match world.get_resource::<Metrics>() {
+        Some(metrics) => {
+            let mut data = metrics.lock();
+
+            match data.stage_started.get(stage) {
+                Some(started) => {
+                    let duration = started.elapsed().as_secs_f64();
+                    data.stage_durations.insert(stage, duration);
+                }
+                None => {}
+            }
+
+            if stage == LAST_STAGE {
+                match data.stage_started.get(FIRST_STAGE) {
+                    Some(started) => {
+                        data.tick_duration = started.elapsed().as_secs_f64();
+                    }
+                    None => {}
+                }
+            }
+        }
+        None => {}
+    }
+}
+
+fn get_entity_group_name(entity_group: &EntityGroup) -> &'static str {
+    match entity_group {
+        EntityGroup::None => "None",
+        EntityGroup::AirLock => "AirLock",
+        EntityGroup::CounterWindowSensor => "CounterWindowSensor",
+        EntityGroup::Pawn => "Pawn",
+    }
+}
+
+pub fn collect_world_metrics(
+    entities: Query<Entity>,
+    entity_data: Query<&EntityData>,
+    connected_players: Query<&ConnectedPlayer>,
+    metrics: Res<Metrics>,
+) {
+    let mut data = metrics.lock();
+
+    data.entity_groups.clear();
+    data.entity_groups.insert("All", entities.iter().count());
+
+    for entity_data_component in entity_data.iter() {
+        *data
+            .entity_groups
+            .entry(get_entity_group_name(&entity_data_component.entity_group))
+            .or_insert(0) += 1;
+    }
+
+    data.connected_players = connected_players
+        .iter()
+        .filter(|connected_player_component| connected_player_component.connected)
+        .count();
+}
+
+// Runs once a frame at the end of it, so this is the amount of events sent during the tick.
+pub fn record_event_queue_length<T: Send + Sync + 'static>(
+    mut events: EventReader<T>,
+    metrics: Res<Metrics>,
+) {
+    let type_name = std::any::type_name::<T>();
+    let event_name = type_name.rsplit("::").next().unwrap_or(type_name);
+
+    metrics
+        .lock()
+        .event_queue_lengths
+        .insert(event_name, events.iter().count());
+}
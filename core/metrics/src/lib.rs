@@ -0,0 +1,3 @@
+pub mod collect;
+pub mod plugin;
+pub mod server;
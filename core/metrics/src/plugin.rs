@@ -0,0 +1,73 @@
+use bevy::{
+    core::FixedTimestep,
+    prelude::{
+        App, CoreStage, ExclusiveSystemDescriptorCoercion, IntoExclusiveSystem,
+        ParallelSystemDescriptorCoercion, Plugin, SystemSet,
+    },
+};
+use networking::messages::{InputConsoleCommand, InputMouseDirectionUpdate, InputMovementInput};
+use api::{
+    entity_updates::NetSendEntityUpdates,
+    load_entity::{NetLoadEntity, NetUnloadEntity},
+    metrics::Metrics,
+    network::{InputChatMessage, PendingNetworkMessage},
+};
+
+use crate::{
+    collect::{
+        collect_world_metrics, record_event_queue_length, start_stage_timer, stop_stage_timer,
+        MetricsLabels, FIRST_STAGE, LAST_STAGE,
+    },
+    server::{startup_metrics_server, update_metrics_exposition, MetricsConfig, MetricsExposition},
+};
+
+pub struct MetricsPlugin {
+    pub address: Option<String>,
+}
+
+impl Plugin for MetricsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Metrics>()
+            .init_resource::<MetricsExposition>()
+            .insert_resource(MetricsConfig {
+                address: self.address.clone(),
+            })
+            .add_startup_system(startup_metrics_server)
+            .add_system_set_to_stage(
+                CoreStage::Last,
+                SystemSet::new()
+                    .with_system(record_event_queue_length::<PendingNetworkMessage>)
+                    .with_system(record_event_queue_length::<NetSendEntityUpdates>)
+                    .with_system(record_event_queue_length::<NetLoadEntity>)
+                    .with_system(record_event_queue_length::<NetUnloadEntity>)
+                    .with_system(record_event_queue_length::<InputChatMessage>)
+                    .with_system(record_event_queue_length::<InputConsoleCommand>)
+                    .with_system(record_event_queue_length::<InputMovementInput>)
+                    .with_system(record_event_queue_length::<InputMouseDirectionUpdate>),
+            )
+            .add_system_set_to_stage(
+                CoreStage::Last,
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(1.))
+                    .with_system(collect_world_metrics.label(MetricsLabels::Collect))
+                    .with_system(update_metrics_exposition.after(MetricsLabels::Collect)),
+            );
+
+        for (stage, stage_name) in [
+            (CoreStage::First, FIRST_STAGE),
+            (CoreStage::PreUpdate, "PreUpdate"),
+            (CoreStage::Update, "Update"),
+            (CoreStage::PostUpdate, "PostUpdate"),
+            (CoreStage::Last, LAST_STAGE),
+        ] {
+            app.add_system_to_stage(
+                stage.clone(),
+                start_stage_timer(stage_name).exclusive_system().at_start(),
+            )
+            .add_system_to_stage(
+                stage,
+                stop_stage_timer(stage_name).exclusive_system().at_end(),
+            );
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as FmtWrite,
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use bevy::prelude::{info, warn, Res};
+use api::metrics::{Metrics, MetricsData};
+
+pub struct MetricsConfig {
+    // Local address the endpoint listens on, None disables it.
+    pub address: Option<String>,
+}
+
+// The latest rendered metrics, shared with the thread answering scrapes.
+#[derive(Default)]
+pub struct MetricsExposition {
+    pub text: Arc<Mutex<String>>,
+}
+
+pub fn startup_metrics_server(config: Res<MetricsConfig>, exposition: Res<MetricsExposition>) {
+    let address;
+    match &config.address {
+        Some(a) => {
+            address = a.clone();
+        }
+        None => {
+            return;
+        }
+    }
+
+    let listener;
+    match TcpListener::bind(&address) {
+        Ok(l) => {
+            listener = l;
+        }
+        Err(rr) => {
+            warn!("Couldn't bind metrics endpoint to {}: {}", address, rr);
+            return;
+        }
+    }
+
+    info!("Serving metrics on http://{}/metrics", address);
+
+    let text = exposition.text.clone();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(mut s) => {
+                    respond(&mut s, &text);
+                }
+                Err(_rr) => {}
+            }
+        }
+    });
+}
+
+fn respond(stream: &mut TcpStream, text: &Arc<Mutex<String>>) {
+    // Scrapers that never finish their request don't get to block the endpoint.
+    match stream.set_read_timeout(Some(Duration::from_secs(2))) {
+        Ok(_) => {}
+        Err(_rr) => {
+            return;
+        }
+    }
+
+    let mut buffer = [0; 1024];
+    let read;
+    match stream.read(&mut buffer) {
+        Ok(r) => {
+            read = r;
+        }
+        Err(_rr) => {
+            return;
+        }
+    }
+
+    let request = String::from_utf8_lossy(&buffer[..read]);
+
+    let (status, body) = if request.starts_with("GET /metrics ") || request.starts_with("GET / ") {
+        let body = match text.lock() {
+            Ok(t) => t.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        ("200 OK", body)
+    } else {
+        ("404 Not Found", "".to_string())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    match stream.write_all(response.as_bytes()) {
+        Ok(_) => {}
+        Err(_rr) => {}
+    }
+}
+
+fn write_labeled<T: std::fmt::Display>(
+    text: &mut String,
+    name: &str,
+    metric_type: &str,
+    help: &str,
+    label: &str,
+    values: &HashMap<&'static str, T>,
+) {
+    let _ = writeln!(text, "# HELP {} {}", name, help);
+    let _ = writeln!(text, "# TYPE {} {}", name, metric_type);
+
+    let mut keys: Vec<&&'static str> = values.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        let _ = writeln!(text, "{}{{{}=\"{}\"}} {}", name, label, key, values[key]);
+    }
+}
+
+fn write_single<T: std::fmt::Display>(
+    text: &mut String,
+    name: &str,
+    metric_type: &str,
+    help: &str,
+    value: T,
+) {
+    let _ = writeln!(text, "# HELP {} {}", name, help);
+    let _ = writeln!(text, "# TYPE {} {}", name, metric_type);
+    let _ = writeln!(text, "{} {}", name, value);
+}
+
+// Prometheus text exposition format.
+pub fn render_metrics(data: &MetricsData) -> String {
+    let mut text = String::new();
+
+    write_single(
+        &mut text,
+        "space_tick_duration_seconds",
+        "gauge",
+        "Duration of the latest tick.",
+        data.tick_duration,
+    );
+    write_labeled(
+        &mut text,
+        "space_stage_duration_seconds",
+        "gauge",
+        "Duration of each stage during the latest tick.",
+        "stage",
+        &data.stage_durations,
+    );
+    write_labeled(
+        &mut text,
+        "space_system_duration_seconds",
+        "gauge",
+        "Duration of the latest run of timed systems.",
+        "system",
+        &data.system_durations,
+    );
+    write_labeled(
+        &mut text,
+        "space_fixed_timestep_overstep_percent",
+        "gauge",
+        "Overstep percentage of fixed timesteps.",
+        "timestep",
+        &data.oversteps,
+    );
+    write_labeled(
+        &mut text,
+        "space_entities",
+        "gauge",
+        "Entities per entity group, All counts every entity.",
+        "group",
+        &data.entity_groups,
+    );
+    write_single(
+        &mut text,
+        "space_connected_players",
+        "gauge",
+        "Connected players.",
+        data.connected_players,
+    );
+    write_labeled(
+        &mut text,
+        "space_sent_bytes_total",
+        "counter",
+        "Serialized bytes sent per message type.",
+        "message",
+        &data.bytes_sent,
+    );
+    write_labeled(
+        &mut text,
+        "space_sent_messages_total",
+        "counter",
+        "Messages sent per message type.",
+        "message",
+        &data.messages_sent,
+    );
+    write_labeled(
+        &mut text,
+        "space_event_queue_length",
+        "gauge",
+        "Events sent during the latest tick.",
+        "event",
+        &data.event_queue_lengths,
+    );
+
+    text
+}
+
+pub fn update_metrics_exposition(metrics: Res<Metrics>, exposition: Res<MetricsExposition>) {
+    let rendered = render_metrics(&metrics.lock());
+
+    match exposition.text.lock() {
+        Ok(mut text) => {
+            *text = rendered;
+        }
+        Err(_rr) => {
+            warn!("Couldn't update the metrics exposition.");
+        }
+    }
+}
@@ -77,6 +77,7 @@ pub enum MapInput {
     Range(f32),
     Position(Vec2),
     MouseCell(i16, i16),
+    Deck(u8),
 }
 
 pub struct InputMap {
@@ -134,6 +135,7 @@ pub fn incoming_messages(
         EventWriter<TextTreeInputSelection>,
         EventWriter<InputMapRequestDisplayModes>,
         EventWriter<InputMap>,
+        EventWriter<InputHeartBeat>,
     ),
 
     mut console_commands_queue: EventWriter<InputConsoleCommand>,
@@ -179,6 +181,7 @@ pub fn incoming_messages(
         mut text_tree_input_selection,
         mut input_map_request_display_modes,
         mut input_map_view_range,
+        mut input_heart_beat,
     ) = tuple2;
 
     for handle in net.clients_id().into_iter() {
@@ -372,7 +375,16 @@ pub fn incoming_messages(
                     //                                    |
                 } // Where the souls of the players are   |
                 //   while they're connected.             V
-                ReliableClientMessage::HeartBeat => { /* <3 */ }
+                ReliableClientMessage::HeartBeat => match handle_to_entity.map.get(&handle) {
+                    Some(player_entity) => {
+                        input_heart_beat.send(InputHeartBeat {
+                            entity: *player_entity,
+                        });
+                    }
+                    None => {
+                        warn!("Couldn't find player_entity belonging to HeartBeat sender handle.");
+                    }
+                },
                 ReliableClientMessage::ConsoleCommand(command_name, variant_arguments) => {
                     match handle_to_entity.map.get(&handle) {
                         Some(player_entity) => {
@@ -610,6 +622,20 @@ pub fn incoming_messages(
                         }
                     }
                 }
+                ReliableClientMessage::MapSelectDeck(deck) => {
+                    match handle_to_entity.map.get(&handle) {
+                        Some(player_entity) => {
+                            input_map_view_range.send(InputMap {
+                                handle: handle,
+                                entity: *player_entity,
+                                input: MapInput::Deck(deck),
+                            });
+                        }
+                        None => {
+                            warn!("Couldn't find player_entity belonging to MapSelectDeck sender handle.");
+                        }
+                    }
+                }
             }
         }
 
@@ -739,6 +765,11 @@ pub struct InputToggleAutoMove {
     pub entity: Entity,
 }
 
+// Clients answer every ServerTime with a HeartBeat.
+pub struct InputHeartBeat {
+    pub entity: Entity,
+}
+
 pub struct InputAttackEntity {
     pub entity: Entity,
     pub target_entity_bits: u64,
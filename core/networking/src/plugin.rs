@@ -3,13 +3,11 @@ use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin};
 use bevy_renet::renet::NETCODE_KEY_BYTES;
 use bevy_renet::RenetServerPlugin;
 use api::data::{PostUpdateLabels, PreUpdateLabels};
-use api::network::{
-    InputChatMessage, PendingMessage, PendingNetworkMessage, ReliableServerMessage,
-};
+use api::network::{InputChatMessage, PendingMessage, PendingNetworkMessage, ReliableServerMessage};
 
 use crate::messages::{
     net_system, ExamineEntityMessages, InputAltItemAttack, InputAttackCell, InputAttackEntity,
-    InputBuildGraphics, InputConsoleCommand, InputDropCurrentItem, InputMap,
+    InputBuildGraphics, InputConsoleCommand, InputDropCurrentItem, InputHeartBeat, InputMap,
     InputMapChangeDisplayMode, InputMapRequestDisplayModes, InputMouseAction,
     InputMouseDirectionUpdate, InputMovementInput, InputSceneReady, InputSelectBodyPart,
     InputSprinting, InputSwitchHands, InputTabAction, InputTabDataMap, InputTakeOffItem,
@@ -53,6 +51,7 @@ impl Plugin for NetworkingPlugin {
         .add_event::<InputMapChangeDisplayMode>()
         .add_event::<InputMapRequestDisplayModes>()
         .add_event::<InputMap>()
+        .add_event::<InputHeartBeat>()
         .init_resource::<ExamineEntityMessages>()
         .add_event::<InputChatMessage>()
         .add_event::<InputToggleCombatMode>()
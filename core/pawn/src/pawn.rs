@@ -2,28 +2,45 @@ use std::collections::HashMap;
 
 use bevy::{
     math::Vec2,
-    prelude::{Component, Entity},
+    prelude::{warn, Component, Entity, EventReader, Query, ResMut},
 };
 use api::{
+    chat::JOB_SECURITY_WORD,
     data::Vec3Int,
     get_spawn_position::FacingDirection,
+    inventory::{Inventory, SlotType},
+    rounds::ResetWorld,
     tab_actions::{TabAction, TabActionsData},
 };
+use serde::Deserialize;
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Clone, Debug, Deserialize)]
 pub enum ShipAuthorizationEnum {
     Security,
     Common,
+    Command,
 }
-#[derive(Copy, Clone)]
-pub enum ShipJobsEnum {
-    Security,
-    Control,
+
+// Full job definitions live in the jobs catalogue.
+#[derive(Clone, Debug)]
+pub struct ShipJob {
+    pub id: String,
+    pub name: String,
+}
+
+impl Default for ShipJob {
+    fn default() -> Self {
+        Self {
+            id: "security".to_string(),
+            name: JOB_SECURITY_WORD.to_string(),
+        }
+    }
 }
+
 #[derive(Component)]
 pub struct Pawn {
     pub name: String,
-    pub job: ShipJobsEnum,
+    pub job: ShipJob,
     pub facing_direction: FacingDirection,
     pub tab_actions: HashMap<u32, TabAction>,
     pub tab_actions_data: TabActionsData,
@@ -33,7 +50,7 @@ impl Default for Pawn {
     fn default() -> Self {
         Self {
             name: "".to_string(),
-            job: ShipJobsEnum::Security,
+            job: ShipJob::default(),
             facing_direction: FacingDirection::Up,
             tab_actions: HashMap::new(),
             tab_actions_data: TabActionsData::default(),
@@ -98,11 +115,54 @@ impl Pawn {
     }
 }
 
+// Sits on ID cards, pawns get their access from the card worn in their ID slot.
 #[derive(Component)]
 pub struct ShipAuthorization {
     pub access: Vec<ShipAuthorizationEnum>,
 }
 
+pub fn get_id_card_access(
+    inventory: &Inventory,
+    id_cards: &Query<&ShipAuthorization>,
+) -> Vec<ShipAuthorizationEnum> {
+    for slot in inventory.slots.iter() {
+        if slot.slot_type != SlotType::Id {
+            continue;
+        }
+        match slot.slot_item {
+            Some(item_entity) => match id_cards.get(item_entity) {
+                Ok(ship_authorization) => {
+                    return ship_authorization.access.clone();
+                }
+                Err(_) => {}
+            },
+            None => {}
+        }
+    }
+
+    vec![]
+}
+
+#[derive(Deserialize)]
+struct RawAccessData {
+    access: Vec<ShipAuthorizationEnum>,
+}
+
+// Map entities can carry {"access":["Security"]} in their entities.json data string.
+pub fn get_raw_entity_access(data: &str) -> Option<Vec<ShipAuthorizationEnum>> {
+    if data.is_empty() {
+        return None;
+    }
+
+    match serde_json::from_str::<RawAccessData>(data) {
+        Ok(raw_access_data) => Some(raw_access_data.access),
+        Err(_) => {
+            warn!("Couldn't parse access data of map entity: {}", data);
+            None
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct ControllerInput {
     pub movement_vector: Vec2,
@@ -137,6 +197,7 @@ pub struct PersistentPlayerData {
     pub user_name_is_set: bool,
     pub character_name: String,
     pub user_name: String,
+    pub job_preference: Option<String>,
 }
 impl Default for PersistentPlayerData {
     fn default() -> Self {
@@ -144,6 +205,7 @@ impl Default for PersistentPlayerData {
             user_name_is_set: false,
             character_name: "".to_string(),
             user_name: "".to_string(),
+            job_preference: None,
         }
     }
 }
@@ -162,3 +224,15 @@ pub fn get_dummy_name(used_names: &mut UsedNames) -> String {
 
     return_name
 }
+
+pub fn reset_used_names(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut used_names: ResMut<UsedNames>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    used_names.names.clear();
+    used_names.dummy_i = 0;
+}
@@ -1,10 +1,15 @@
 use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, SystemSet};
 use networking::messages::net_system;
 use api::{
-    data::PostUpdateLabels, examinable::ExamineLabels, tab_actions::TabActionsQueueLabels,
+    data::PostUpdateLabels, examinable::ExamineLabels, rounds::RoundsLabels,
+    tab_actions::TabActionsQueueLabels,
 };
 
-use crate::{examine_events::examine_map, pawn::UsedNames, user_name::NetPawn};
+use crate::{
+    examine_events::examine_map,
+    pawn::{reset_used_names, UsedNames},
+    user_name::NetPawn,
+};
 
 use super::{actions::actions, user_name::user_name};
 use bevy::app::CoreStage::PostUpdate;
@@ -14,6 +19,11 @@ impl Plugin for PawnPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<UsedNames>()
             .add_system(user_name)
+            .add_system(
+                reset_used_names
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
             .add_system(examine_map.after(ExamineLabels::Default))
             .add_system(
                 actions
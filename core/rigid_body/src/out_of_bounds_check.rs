@@ -1,16 +1,21 @@
 use bevy::prelude::{warn, Entity, Query, Transform, Without};
 use physics::physics::RigidBodyDisabled;
-use api::{entity_updates::EntityData, gridmap::FOV_MAP_WIDTH};
+use api::{
+    entity_updates::EntityData,
+    gridmap::{get_deck_floor_height, FOV_MAP_WIDTH, MAX_DECKS},
+};
 
 pub fn out_of_bounds_check(
     mut rigid_bodies: Query<(Entity, &EntityData, &mut Transform), (Without<RigidBodyDisabled>,)>,
 ) {
     let max = FOV_MAP_WIDTH as f32 * 0.5 * 2.;
+    // Up to the roof slab above the top deck.
+    let max_y = get_deck_floor_height(MAX_DECKS) + 1.;
 
     for (rigid_body_entity, entity_data_component, mut rigid_body_position_component) in
         rigid_bodies.iter_mut()
     {
-        if rigid_body_position_component.translation.y > 5.
+        if rigid_body_position_component.translation.y > max_y
             || rigid_body_position_component.translation.y < -5.
         {
             warn!(
@@ -0,0 +1,40 @@
+use std::{fs, path::Path};
+
+use bevy::prelude::{info, ResMut};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RoundConfig {
+    // All durations are in seconds.
+    pub lobby_duration: f32,
+    pub starting_duration: f32,
+    // None lets a round run until it is ended by a game mode or an admin.
+    pub round_duration: Option<f32>,
+    pub ending_duration: f32,
+    // Fraction of connected players that have to be ready to skip the lobby timer.
+    pub ready_ratio: f32,
+    pub min_players: u16,
+}
+
+impl Default for RoundConfig {
+    fn default() -> Self {
+        Self {
+            lobby_duration: 120.,
+            starting_duration: 10.,
+            round_duration: None,
+            ending_duration: 30.,
+            ready_ratio: 0.5,
+            min_players: 1,
+        }
+    }
+}
+
+pub fn startup_round_config(mut round_config: ResMut<RoundConfig>) {
+    let rounds_json = Path::new("data").join("rounds").join("rounds.json");
+    let rounds_raw_json: String = fs::read_to_string(rounds_json)
+        .expect("startup_round_config() Error reading rounds.json from drive.");
+    *round_config = serde_json::from_str(&rounds_raw_json)
+        .expect("startup_round_config() Error parsing rounds.json String.");
+
+    info!("Loaded round configuration.");
+}
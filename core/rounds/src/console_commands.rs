@@ -0,0 +1,115 @@
+use bevy::prelude::{EventReader, EventWriter, Query, Res, ResMut};
+use console_commands::commands::{AllConsoleCommands, NetConsoleCommands};
+use networking::messages::InputConsoleCommand;
+use api::{
+    console_commands::{CONSOLE_ERROR_COLOR, CONSOLE_SUCCESS_COLOR},
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+    rounds::{EndRound, RoundState, RoundStatus},
+};
+
+use crate::round::RoundController;
+
+pub fn initialize_console_commands(mut commands: ResMut<AllConsoleCommands>) {
+    commands.list.push((
+        "ready".to_string(),
+        "Toggle your vote to start the round before the lobby timer runs out.".to_string(),
+        vec![],
+    ));
+    commands.list.push((
+        "startRound".to_string(),
+        "Start the round right away.".to_string(),
+        vec![],
+    ));
+    commands.list.push((
+        "endRound".to_string(),
+        "End the current round and show the round-end summary.".to_string(),
+        vec![],
+    ));
+}
+
+pub fn rounds_console_commands(
+    mut queue: EventReader<InputConsoleCommand>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+    connected_players: Query<&ConnectedPlayer>,
+    round_status: Res<RoundStatus>,
+    mut round_controller: ResMut<RoundController>,
+    mut end_round: EventWriter<EndRound>,
+) {
+    for console_command_event in queue.iter() {
+        if console_command_event.command_name != "ready"
+            && console_command_event.command_name != "startRound"
+            && console_command_event.command_name != "endRound"
+        {
+            continue;
+        }
+
+        let handle;
+        match console_command_event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let connected_player;
+        match connected_players.get(console_command_event.entity) {
+            Ok(c) => {
+                connected_player = c;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let reply;
+
+        if console_command_event.command_name == "ready" {
+            if round_status.state != RoundState::Lobby {
+                reply = "[color=".to_string()
+                    + CONSOLE_ERROR_COLOR
+                    + "]The round is not in the lobby phase.[/color]";
+            } else if round_controller.ready.remove(&handle) {
+                reply = "[color=".to_string()
+                    + CONSOLE_SUCCESS_COLOR
+                    + "]You are no longer ready.[/color]";
+            } else {
+                round_controller.ready.insert(handle);
+                reply = "[color=".to_string()
+                    + CONSOLE_SUCCESS_COLOR
+                    + "]You are ready. The round starts early once enough players are ready.[/color]";
+            }
+        } else if connected_player.rcon == false {
+            reply = "[color=".to_string() + CONSOLE_ERROR_COLOR + "]RCON status denied.[/color]";
+        } else if console_command_event.command_name == "startRound" {
+            if round_status.state != RoundState::Lobby {
+                reply = "[color=".to_string()
+                    + CONSOLE_ERROR_COLOR
+                    + "]The round has already started.[/color]";
+            } else {
+                round_controller.force_start = true;
+                reply =
+                    "[color=".to_string() + CONSOLE_SUCCESS_COLOR + "]Starting the round.[/color]";
+            }
+        } else {
+            if round_status.state != RoundState::InProgress {
+                reply = "[color=".to_string()
+                    + CONSOLE_ERROR_COLOR
+                    + "]There is no round in progress.[/color]";
+            } else {
+                end_round.send(EndRound {
+                    reason: "The round was ended by an administrator.".to_string(),
+                });
+                reply =
+                    "[color=".to_string() + CONSOLE_SUCCESS_COLOR + "]Ending the round.[/color]";
+            }
+        }
+
+        net_console_commands.send(NetConsoleCommands {
+            handle,
+            message: ReliableServerMessage::ConsoleWriteLine(reply),
+        });
+    }
+}
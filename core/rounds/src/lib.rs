@@ -0,0 +1,6 @@
+pub mod config;
+pub mod console_commands;
+pub mod net;
+pub mod plugin;
+pub mod reset;
+pub mod round;
@@ -0,0 +1,83 @@
+use atmospherics::init::startup_atmospherics;
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, SystemSet};
+use gridmap::init::startup_build_map;
+use networking::messages::net_system;
+use api::{
+    console_commands::ConsoleCommandsLabels,
+    data::{PostUpdateLabels, StartupLabels},
+    rounds::{
+        map_rebuild_requested, EndRound, MapRebuild, ResetWorld, RoundEndSummary,
+        RoundStateChanged, RoundStatus, RoundsLabels,
+    },
+};
+
+use crate::{
+    config::{startup_round_config, RoundConfig},
+    console_commands::{initialize_console_commands, rounds_console_commands},
+    net::NetRounds,
+    reset::{finish_map_rebuild, reset_world},
+    round::{
+        broadcast_round_end_summary, round_controller, startup_round_controller, RoundController,
+    },
+};
+use bevy::app::CoreStage::PostUpdate;
+
+pub struct RoundsPlugin;
+
+impl Plugin for RoundsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoundConfig>()
+            .init_resource::<RoundController>()
+            .init_resource::<RoundStatus>()
+            .init_resource::<RoundEndSummary>()
+            .init_resource::<MapRebuild>()
+            .add_event::<EndRound>()
+            .add_event::<RoundStateChanged>()
+            .add_event::<ResetWorld>()
+            .add_event::<NetRounds>()
+            .add_startup_system(startup_round_config.label(StartupLabels::MiscResources))
+            .add_startup_system(startup_round_controller.after(StartupLabels::MiscResources))
+            .add_startup_system(
+                initialize_console_commands
+                    .before(ConsoleCommandsLabels::Finalize)
+                    .label(StartupLabels::ConsoleCommands),
+            )
+            .add_system(rounds_console_commands.before(RoundsLabels::Controller))
+            .add_system(round_controller.label(RoundsLabels::Controller))
+            .add_system(
+                broadcast_round_end_summary
+                    .after(RoundsLabels::Controller)
+                    .after(RoundsLabels::Summary),
+            )
+            // The map is rebuilt with the startup systems the frame after the world got reset.
+            .add_system(
+                startup_build_map
+                    .with_run_criteria(map_rebuild_requested)
+                    .label(RoundsLabels::RebuildMap)
+                    .before(RoundsLabels::ResetWorld),
+            )
+            .add_system(
+                startup_atmospherics
+                    .with_run_criteria(map_rebuild_requested)
+                    .label(RoundsLabels::RebuildAtmospherics)
+                    .after(RoundsLabels::RebuildMap),
+            )
+            .add_system(
+                finish_map_rebuild
+                    .after(RoundsLabels::RebuildAtmospherics)
+                    .before(RoundsLabels::ResetWorld),
+            )
+            .add_system(
+                reset_world
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetRounds>),
+            );
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::{
+    info, Commands, DespawnRecursiveExt, Entity, EventReader, EventWriter, Or, Parent, Query,
+    ResMut, With, Without,
+};
+use bevy_rapier3d::prelude::RigidBody;
+use gridmap::events::Cell;
+use pawn::pawn::{ControllerInput, PersistentPlayerData, UsedNames};
+use sfx::builder::Sfx;
+use api::{
+    chat::get_talk_spaces_setupui,
+    connected_player::SoftPlayer,
+    data::{ConnectedPlayer, HandleToEntity, Showcase},
+    entity_updates::EntityData,
+    network::{ReliableServerMessage, ServerConfigMessage},
+    rounds::{MapRebuild, ResetWorld},
+};
+
+use crate::net::NetRounds;
+
+// Sends boarded players back to the setup UI and despawns the world,
+// other plugins reset their own round state on ResetWorld.
+pub fn reset_world(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut commands: Commands,
+    world_entities: Query<
+        Entity,
+        (
+            Or<(With<EntityData>, With<Cell>, With<RigidBody>)>,
+            Without<Parent>,
+            Without<Sfx>,
+            Without<Showcase>,
+        ),
+    >,
+    boarded_players: Query<(&ConnectedPlayer, &PersistentPlayerData), Without<SoftPlayer>>,
+    mut used_names: ResMut<UsedNames>,
+    mut handle_to_entity: ResMut<HandleToEntity>,
+    mut map_rebuild: ResMut<MapRebuild>,
+    mut net_rounds: EventWriter<NetRounds>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    // Boarded players get a fresh soft entity so they end up in the setup UI again.
+    for (connected_player, persistent_player_data) in boarded_players.iter() {
+        handle_to_entity.map.remove(&connected_player.handle);
+
+        if !connected_player.connected {
+            continue;
+        }
+
+        let soft_entity = commands
+            .spawn()
+            .insert_bundle((
+                connected_player.clone(),
+                SoftPlayer,
+                PersistentPlayerData {
+                    user_name_is_set: persistent_player_data.user_name_is_set,
+                    character_name: "".to_string(),
+                    user_name: persistent_player_data.user_name.clone(),
+                    job_preference: persistent_player_data.job_preference.clone(),
+                },
+                ControllerInput::default(),
+            ))
+            .id();
+
+        used_names
+            .user_names
+            .insert(persistent_player_data.user_name.clone(), soft_entity);
+        handle_to_entity
+            .map
+            .insert(connected_player.handle, soft_entity);
+        handle_to_entity
+            .inv_map
+            .insert(soft_entity, connected_player.handle);
+
+        net_rounds.send(NetRounds {
+            handle: connected_player.handle,
+            message: ReliableServerMessage::ConfigMessage(ServerConfigMessage::ChangeScene(
+                false,
+                "setupUI".to_string(),
+            )),
+        });
+        net_rounds.send(NetRounds {
+            handle: connected_player.handle,
+            message: ReliableServerMessage::ConfigMessage(ServerConfigMessage::EntityId(
+                soft_entity.to_bits(),
+            )),
+        });
+        net_rounds.send(NetRounds {
+            handle: connected_player.handle,
+            message: ReliableServerMessage::ConfigMessage(ServerConfigMessage::TalkSpaces(
+                get_talk_spaces_setupui(),
+            )),
+        });
+    }
+
+    let mut despawned = 0;
+
+    for entity in world_entities.iter() {
+        handle_to_entity.inv_map.remove(&entity);
+        commands.entity(entity).despawn_recursive();
+        despawned += 1;
+    }
+
+    // The map is rebuilt next frame, once the despawns above have been applied.
+    map_rebuild.requested = true;
+
+    info!("Reset the world, despawned {} entities.", despawned);
+}
+
+pub fn finish_map_rebuild(mut map_rebuild: ResMut<MapRebuild>) {
+    if map_rebuild.requested {
+        map_rebuild.requested = false;
+        info!("Rebuilt the map.");
+    }
+}
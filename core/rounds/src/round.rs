@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use bevy::{
+    core::{Time, Timer},
+    prelude::{info, EventReader, EventWriter, Query, Res, ResMut, With},
+};
+use pawn::pawn::{Pawn, PersistentPlayerData};
+use api::{
+    chat::{FURTHER_NORMAL_FONT, GOLD_COLOR},
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+    rounds::{EndRound, ResetWorld, RoundEndSummary, RoundState, RoundStateChanged, RoundStatus},
+};
+
+use crate::{config::RoundConfig, net::NetRounds};
+
+pub struct RoundController {
+    pub timer: Timer,
+    // Handles of players that voted to start the round early.
+    pub ready: HashSet<u64>,
+    pub force_start: bool,
+    pub started_at: f64,
+    pub ended_at: f64,
+    pub end_reason: String,
+}
+
+impl Default for RoundController {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(RoundConfig::default().lobby_duration, false),
+            ready: HashSet::new(),
+            force_start: false,
+            started_at: 0.,
+            ended_at: 0.,
+            end_reason: "".to_string(),
+        }
+    }
+}
+
+pub fn startup_round_controller(
+    round_config: Res<RoundConfig>,
+    mut round_controller: ResMut<RoundController>,
+) {
+    round_controller.timer = Timer::from_seconds(round_config.lobby_duration, false);
+}
+
+pub fn broadcast_round_message(
+    net_rounds: &mut EventWriter<NetRounds>,
+    connected_players: &Query<&ConnectedPlayer>,
+    message: &str,
+) {
+    for connected_player in connected_players.iter() {
+        if !connected_player.connected {
+            continue;
+        }
+        net_rounds.send(NetRounds {
+            handle: connected_player.handle,
+            message: ReliableServerMessage::ChatMessage(
+                "[font=".to_string()
+                    + FURTHER_NORMAL_FONT
+                    + "][color="
+                    + GOLD_COLOR
+                    + "]"
+                    + message
+                    + "[/color][/font]",
+            ),
+        });
+    }
+}
+
+fn set_round_state(
+    round_status: &mut ResMut<RoundStatus>,
+    round_state_changed: &mut EventWriter<RoundStateChanged>,
+    new: RoundState,
+) {
+    let previous = round_status.state;
+    round_status.state = new;
+
+    info!("Round {} is now {:?}.", round_status.round_number, new);
+
+    round_state_changed.send(RoundStateChanged { previous, new });
+}
+
+pub fn round_controller(
+    time: Res<Time>,
+    round_config: Res<RoundConfig>,
+    mut round_controller: ResMut<RoundController>,
+    mut round_status: ResMut<RoundStatus>,
+    mut round_end_summary: ResMut<RoundEndSummary>,
+    mut end_round_events: EventReader<EndRound>,
+    mut round_state_changed: EventWriter<RoundStateChanged>,
+    mut reset_world: EventWriter<ResetWorld>,
+    mut net_rounds: EventWriter<NetRounds>,
+    connected_players: Query<&ConnectedPlayer>,
+) {
+    round_controller.timer.tick(time.delta());
+
+    // Only the first request ends the round, the others are drained.
+    let mut end_reason = None;
+    for end_round in end_round_events.iter() {
+        if end_reason.is_none() {
+            end_reason = Some(end_round.reason.clone());
+        }
+    }
+
+    match round_status.state {
+        RoundState::Lobby => {
+            let connected_handles: Vec<u64> = connected_players
+                .iter()
+                .filter(|p| p.connected)
+                .map(|p| p.handle)
+                .collect();
+
+            // Votes of players that have left do not count.
+            round_controller
+                .ready
+                .retain(|handle| connected_handles.contains(handle));
+
+            let enough_players = connected_handles.len() >= round_config.min_players as usize;
+            let ready_vote_passed = !round_controller.ready.is_empty()
+                && round_controller.ready.len() as f32
+                    >= connected_handles.len() as f32 * round_config.ready_ratio;
+
+            if round_controller.force_start
+                || (enough_players && (round_controller.timer.finished() || ready_vote_passed))
+            {
+                round_controller.force_start = false;
+                round_controller.ready.clear();
+                round_controller.timer = Timer::from_seconds(round_config.starting_duration, false);
+
+                set_round_state(
+                    &mut round_status,
+                    &mut round_state_changed,
+                    RoundState::Starting,
+                );
+
+                broadcast_round_message(
+                    &mut net_rounds,
+                    &connected_players,
+                    &format!(
+                        "Round {} starts in {} seconds.",
+                        round_status.round_number, round_config.starting_duration as u32
+                    ),
+                );
+            } else if round_controller.timer.finished() {
+                // Not enough players yet, wait another lobby period.
+                round_controller.timer.reset();
+            }
+        }
+        RoundState::Starting => {
+            if round_controller.timer.finished() {
+                round_controller.started_at = time.seconds_since_startup();
+                if let Some(round_duration) = round_config.round_duration {
+                    round_controller.timer = Timer::from_seconds(round_duration, false);
+                }
+
+                set_round_state(
+                    &mut round_status,
+                    &mut round_state_changed,
+                    RoundState::InProgress,
+                );
+
+                broadcast_round_message(
+                    &mut net_rounds,
+                    &connected_players,
+                    &format!(
+                        "Round {} has started, you may now board the spaceship.",
+                        round_status.round_number
+                    ),
+                );
+            }
+        }
+        RoundState::InProgress => {
+            if end_reason.is_none()
+                && round_config.round_duration.is_some()
+                && round_controller.timer.finished()
+            {
+                end_reason = Some("The shift has come to an end.".to_string());
+            }
+
+            let reason;
+            match end_reason {
+                Some(r) => {
+                    reason = r;
+                }
+                None => {
+                    return;
+                }
+            }
+
+            info!("Round {} ended: {}", round_status.round_number, reason);
+            round_controller.end_reason = reason;
+            round_controller.ended_at = time.seconds_since_startup();
+
+            round_controller.timer = Timer::from_seconds(round_config.ending_duration, false);
+
+            set_round_state(
+                &mut round_status,
+                &mut round_state_changed,
+                RoundState::Ending,
+            );
+        }
+        RoundState::Ending => {
+            if round_controller.timer.finished() {
+                reset_world.send(ResetWorld);
+
+                round_status.round_number += 1;
+                round_end_summary.lines.clear();
+                round_controller.ready.clear();
+                round_controller.timer = Timer::from_seconds(round_config.lobby_duration, false);
+
+                set_round_state(
+                    &mut round_status,
+                    &mut round_state_changed,
+                    RoundState::Lobby,
+                );
+
+                broadcast_round_message(
+                    &mut net_rounds,
+                    &connected_players,
+                    "The spaceship is being prepared for the next round.",
+                );
+            }
+        }
+    }
+}
+
+// Runs after the systems labelled RoundsLabels::Summary had a chance to add their lines.
+pub fn broadcast_round_end_summary(
+    mut round_state_changed: EventReader<RoundStateChanged>,
+    round_controller: Res<RoundController>,
+    round_status: Res<RoundStatus>,
+    mut round_end_summary: ResMut<RoundEndSummary>,
+    mut net_rounds: EventWriter<NetRounds>,
+    connected_players: Query<&ConnectedPlayer>,
+    crew: Query<(&Pawn, &PersistentPlayerData), With<ConnectedPlayer>>,
+) {
+    for state_changed in round_state_changed.iter() {
+        if state_changed.new != RoundState::Ending {
+            continue;
+        }
+
+        let duration_minutes =
+            ((round_controller.ended_at - round_controller.started_at) / 60.) as u32;
+
+        let mut lines = vec![
+            format!(
+                "Round {} has ended after {} minutes.",
+                round_status.round_number, duration_minutes
+            ),
+            round_controller.end_reason.clone(),
+            "Crew manifest:".to_string(),
+        ];
+
+        for (pawn, persistent_player_data) in crew.iter() {
+            lines.push(format!(
+                "{} ({}) - {}",
+                pawn.name, persistent_player_data.user_name, pawn.job.name
+            ));
+        }
+
+        lines.extend(round_end_summary.lines.drain(..));
+
+        for line in lines.iter() {
+            broadcast_round_message(&mut net_rounds, &connected_players, line);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::{math::Vec3, prelude::Entity};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use senser::sensable_grid::SensableGrid;
+
+const ENTITIES: u32 = 10000;
+const SENSERS: usize = 100;
+const VIEW_DISTANCE: f32 = 90.;
+
+// Spread over the 1km x 1km map.
+fn entity_translations() -> Vec<(Entity, Vec3)> {
+    (0..ENTITIES)
+        .map(|i| {
+            let x = ((i * 7919) % 1000) as f32 - 500.;
+            let z = ((i * 104729) % 1000) as f32 - 500.;
+            (Entity::from_raw(i), Vec3::new(x, 0., z))
+        })
+        .collect()
+}
+
+fn senser_translations() -> Vec<Vec3> {
+    (0..SENSERS)
+        .map(|i| {
+            Vec3::new(
+                ((i * 37) % 800) as f32 - 400.,
+                0.,
+                ((i * 53) % 800) as f32 - 400.,
+            )
+        })
+        .collect()
+}
+
+fn bench_sensable_grid(c: &mut Criterion) {
+    let entities = entity_translations();
+    let sensers = senser_translations();
+
+    let mut grid = SensableGrid::default();
+    for (entity, translation) in entities.iter() {
+        grid.update(*entity, *translation);
+    }
+
+    c.bench_function("10k entities, 100 sensers, brute force", |b| {
+        b.iter(|| {
+            let mut in_range = 0;
+            for senser in sensers.iter() {
+                for (_entity, translation) in entities.iter() {
+                    if senser.distance(*translation) < VIEW_DISTANCE {
+                        in_range += 1;
+                    }
+                }
+            }
+            black_box(in_range)
+        })
+    });
+
+    c.bench_function("10k entities, 100 sensers, sensable grid", |b| {
+        let mut nearby = vec![];
+        b.iter(|| {
+            let mut in_range = 0;
+            for senser in sensers.iter() {
+                nearby.clear();
+                grid.get_nearby(*senser, VIEW_DISTANCE, &mut nearby);
+                in_range += nearby.len();
+            }
+            black_box(in_range)
+        })
+    });
+
+    c.bench_function("10k entities moving, sensable grid update", |b| {
+        let mut offset = 0.;
+        b.iter(|| {
+            offset += 1.;
+            for (entity, translation) in entities.iter() {
+                grid.update(*entity, *translation + Vec3::new(offset % 32., 0., 0.));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_sensable_grid);
+criterion_main!(benches);
@@ -1,2 +1,3 @@
 pub mod plugin;
+pub mod sensable_grid;
 pub mod visible_checker;
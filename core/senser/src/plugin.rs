@@ -1,18 +1,28 @@
 use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin};
 use api::data::PostUpdateLabels;
 
-use super::visible_checker::visible_checker;
+use super::{
+    sensable_grid::{update_sensable_grid, SensableGrid},
+    visible_checker::visible_checker,
+};
 use bevy::app::CoreStage::PostUpdate;
 
 pub struct SenserPlugin;
 
 impl Plugin for SenserPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_to_stage(
-            PostUpdate,
-            visible_checker
-                .after(PostUpdateLabels::SendEntityUpdates)
-                .label(PostUpdateLabels::VisibleChecker),
-        );
+        app.init_resource::<SensableGrid>()
+            .add_system_to_stage(
+                PostUpdate,
+                update_sensable_grid
+                    .after(PostUpdateLabels::SendEntityUpdates)
+                    .before(PostUpdateLabels::VisibleChecker),
+            )
+            .add_system_to_stage(
+                PostUpdate,
+                visible_checker
+                    .after(PostUpdateLabels::SendEntityUpdates)
+                    .label(PostUpdateLabels::VisibleChecker),
+            );
     }
 }
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use bevy::{
+    math::Vec3,
+    prelude::{Added, Changed, Entity, Or, Query, RemovedComponents, ResMut, Transform, With},
+};
+use api::{gridmap::CELL_SIZE, sensable::Sensable};
+
+// Width and depth of a chunk in cells.
+pub const CHUNK_CELLS: f32 = 8.;
+
+type ChunkId = (i32, i32);
+
+fn get_chunk_id(translation: Vec3) -> ChunkId {
+    let chunk_size = CHUNK_CELLS * CELL_SIZE;
+    (
+        (translation.x / chunk_size).floor() as i32,
+        (translation.z / chunk_size).floor() as i32,
+    )
+}
+
+// Sensable entities bucketed by chunk so sensers only test what is near them.
+#[derive(Default)]
+pub struct SensableGrid {
+    chunks: HashMap<ChunkId, Vec<Entity>>,
+    entity_chunks: HashMap<Entity, ChunkId>,
+}
+
+impl SensableGrid {
+    pub fn update(&mut self, entity: Entity, translation: Vec3) {
+        let chunk_id = get_chunk_id(translation);
+
+        match self.entity_chunks.insert(entity, chunk_id) {
+            Some(old_chunk_id) => {
+                if old_chunk_id == chunk_id {
+                    return;
+                }
+                self.remove_from_chunk(entity, old_chunk_id);
+            }
+            None => {}
+        }
+
+        self.chunks.entry(chunk_id).or_default().push(entity);
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        match self.entity_chunks.remove(&entity) {
+            Some(chunk_id) => {
+                self.remove_from_chunk(entity, chunk_id);
+            }
+            None => {}
+        }
+    }
+
+    fn remove_from_chunk(&mut self, entity: Entity, chunk_id: ChunkId) {
+        match self.chunks.get_mut(&chunk_id) {
+            Some(entities) => {
+                match entities.iter().position(|e| e == &entity) {
+                    Some(index) => {
+                        entities.swap_remove(index);
+                    }
+                    None => {}
+                }
+                if entities.is_empty() {
+                    self.chunks.remove(&chunk_id);
+                }
+            }
+            None => {}
+        }
+    }
+
+    // Entities in the chunks overlapping the square around translation.
+    pub fn get_nearby(&self, translation: Vec3, distance: f32, nearby: &mut Vec<Entity>) {
+        let min = get_chunk_id(translation - Vec3::new(distance, 0., distance));
+        let max = get_chunk_id(translation + Vec3::new(distance, 0., distance));
+
+        for x in min.0..max.0 + 1 {
+            for z in min.1..max.1 + 1 {
+                match self.chunks.get(&(x, z)) {
+                    Some(entities) => {
+                        nearby.extend(entities.iter());
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+pub fn update_sensable_grid(
+    moved_sensables: Query<
+        (Entity, &Transform),
+        (With<Sensable>, Or<(Changed<Transform>, Added<Sensable>)>),
+    >,
+    removed_sensables: RemovedComponents<Sensable>,
+    mut sensable_grid: ResMut<SensableGrid>,
+) {
+    for entity in removed_sensables.iter() {
+        sensable_grid.remove(entity);
+    }
+    for (entity, transform) in moved_sensables.iter() {
+        sensable_grid.update(entity, transform.translation);
+    }
+}
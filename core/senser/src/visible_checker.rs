@@ -1,6 +1,8 @@
+use std::time::Instant;
+
 use bevy::{
     math::Vec3,
-    prelude::{warn, Entity, EventWriter, Mut, Query, Transform},
+    prelude::{warn, Entity, EventWriter, Mut, Query, Res, Transform},
 };
 use bevy_rapier3d::prelude::RigidBody;
 
@@ -22,7 +24,20 @@ pub fn visible_checker(
     )>,
     mut net_load_entity: EventWriter<NetLoadEntity>,
     mut net_unload_entity: EventWriter<NetUnloadEntity>,
+    sensable_grid: Res<SensableGrid>,
+    metrics: Res<Metrics>,
 ) {
+    let started = Instant::now();
+
+    // Always sensed entities are sensed from anywhere, so they are not looked up by chunk.
+    let always_sensed: Vec<Entity> = query_visible_entities
+        .iter()
+        .filter(|(_, sensable_component, _, _, _, _, _)| sensable_component.always_sensed)
+        .map(|(entity, _, _, _, _, _, _)| entity)
+        .collect();
+
+    let mut candidates = vec![];
+
     for (
         entity,
         mut visible_checker_component,
@@ -38,16 +53,35 @@ pub fn visible_checker(
             visible_checker_translation.z,
         );
 
-        for (
-            visible_entity_id,
-            mut visible_component,
-            transform_component,
-            rigid_body_component_option,
-            entity_data_component,
-            entity_updates_component,
-            entity_world_mode_option,
-        ) in query_visible_entities.iter_mut()
-        {
+        // Nearby entities plus the ones still loaded, so those unload once they left.
+        candidates.clear();
+        sensable_grid.get_nearby(
+            visible_checker_translation_vec,
+            VIEW_DISTANCE,
+            &mut candidates,
+        );
+        candidates.extend(always_sensed.iter());
+        candidates.extend(visible_checker_component.sensing.iter());
+        candidates.extend(visible_checker_component.sensing_cached.iter());
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        for candidate in candidates.iter() {
+            let (
+                visible_entity_id,
+                mut visible_component,
+                transform_component,
+                rigid_body_component_option,
+                entity_data_component,
+                entity_updates_component,
+                entity_world_mode_option,
+            ) = match query_visible_entities.get_mut(*candidate) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
             let visible_entity_transform;
 
             let mut is_interpolated = false;
@@ -141,7 +175,14 @@ pub fn visible_checker(
         for i in gone_sfx_entities {
             visible_checker_component.sfx.remove(i);
         }
+
+        // Despawned entities already unloaded themselves from their cached sensers.
+        visible_checker_component
+            .sensing_cached
+            .retain(|entity| query_visible_entities.get(*entity).is_ok());
     }
+
+    metrics.record_system("visible_checker", started.elapsed());
 }
 
 const VIEW_DISTANCE: f32 = 90.;
@@ -183,8 +224,7 @@ fn visible_check(
         && is_cached
     {
         let visible_entity_cell_id = world_to_cell_id(visible_entity_transform.translation);
-        let coords = to_doryen_coordinates(visible_entity_cell_id.x, visible_entity_cell_id.z);
-        is_sensed = senser_component.fov.is_in_fov(coords.0, coords.1);
+        is_sensed = senser_component.is_cell_in_fov(&visible_entity_cell_id);
     }
 
     if sensable_component.is_light {
@@ -251,6 +291,7 @@ fn visible_check(
                     sensable_component
                         .sensed_by_cached
                         .push(visible_checker_entity_id);
+                    senser_component.sensing_cached.push(visible_entity_id);
                 }
             }
         } else if sensed_by_cached_contains && unload_entirely {
@@ -274,6 +315,9 @@ fn visible_check(
                 .position(|x| x == &visible_checker_entity_id)
                 .unwrap();
             sensable_component.sensed_by_cached.remove(index);
+            senser_component
+                .sensing_cached
+                .retain(|x| x != &visible_entity_id);
 
             match senser_component
                 .sensing
@@ -304,6 +348,7 @@ fn visible_check(
                 sensable_component
                     .sensed_by_cached
                     .push(visible_checker_entity_id);
+                senser_component.sensing_cached.push(visible_entity_id);
             }
         }
     } else {
@@ -344,6 +389,9 @@ fn visible_check(
                 .position(|x| x == &visible_checker_entity_id)
                 .unwrap();
             sensable_component.sensed_by_cached.remove(index);
+            senser_component
+                .sensing_cached
+                .retain(|x| x != &visible_entity_id);
         }
     }
 }
@@ -352,8 +400,11 @@ use physics::world_mode::{WorldMode, WorldModes};
 use api::{
     data::ConnectedPlayer,
     entity_updates::{EntityData, EntityUpdates},
-    gridmap::{to_doryen_coordinates, world_to_cell_id},
+    gridmap::world_to_cell_id,
     load_entity::{load_entity, unload_entity, NetLoadEntity, NetUnloadEntity},
+    metrics::Metrics,
     sensable::Sensable,
     senser::Senser,
 };
+
+use crate::sensable_grid::SensableGrid;
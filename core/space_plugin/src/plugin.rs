@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use air_locks::plugin::AirLocksPlugin;
 use asana::plugin::AsanaPlugin;
+use atmos_machinery::plugin::AtmosMachineryPlugin;
 use atmospherics::plugin::AtmosphericsPlugin;
 use bevy::{
     app::{RunMode, ScheduleRunnerPlugin, ScheduleRunnerSettings},
@@ -25,21 +26,32 @@ use counter_windows::plugin::CounterWindowsPlugin;
 use entity::plugin::EntityPlugin;
 use gridmap::plugin::GridmapPlugin;
 use health::plugin::HealthPlugin;
+use game_modes::plugin::{GameModesPlugin, TraitorModePlugin};
+use headset::plugin::HeadsetPlugin;
 use helmet_security::plugin::HelmetsPlugin;
 use human_male::plugin::HumanMalePlugin;
 use humanoid::plugin::HumanoidPlugin;
+use id_card::plugin::IdCardPlugin;
 use inventory::plugin::InventoryPlugin;
 use inventory_item::plugin::InventoryItemPlugin;
+use jobs::plugin::JobsPlugin;
 use jumpsuit_security::plugin::JumpsuitsPlugin;
+use ladder::plugin::LadderPlugin;
 use line_arrow::plugin::{LineArrowPlugin, PointArrowPlugin};
+use magazine_b1::plugin::MagazineB1Plugin;
 use map::plugin::MapPlugin;
+use metrics::plugin::MetricsPlugin;
 use networking::plugin::NetworkingPlugin;
 use omni_light::plugin::OmniLightPlugin;
 use pawn::plugin::PawnPlugin;
 use physics::plugin::PhysicsPlugin;
+use pistol_b1::plugin::PistolB1Plugin;
 use pistol_l1::plugin::PistolL1Plugin;
+use power_cell_l1::plugin::PowerCellL1Plugin;
+use recharger::plugin::RechargerPlugin;
 use reflection_probe::plugin::ReflectionProbePlugin;
 use rigid_body::plugin::RigidBodyPlugin;
+use rounds::plugin::RoundsPlugin;
 use senser::plugin::SenserPlugin;
 use sfx::plugin::SfxPlugin;
 use api::{chat::MOTD, data::TickRate};
@@ -54,6 +66,8 @@ pub struct SpacePlugin {
     pub threads_amount: Option<u8>,
     pub give_all_rcon: bool,
     pub custom_encryption_key: Option<[u8; NETCODE_KEY_BYTES]>,
+    // Local address of the Prometheus metrics endpoint, None disables it.
+    pub metrics_address: Option<String>,
 }
 impl Default for SpacePlugin {
     fn default() -> Self {
@@ -65,6 +79,7 @@ impl Default for SpacePlugin {
             threads_amount: Some(2),
             give_all_rcon: true,
             custom_encryption_key: None,
+            metrics_address: Some("127.0.0.1:9184".to_string()),
         }
     }
 }
@@ -94,6 +109,10 @@ impl Plugin for SpacePlugin {
             .add_plugin(WorldEnvironmentPlugin)
             .add_plugin(GridmapPlugin)
             .add_plugin(PawnPlugin)
+            .add_plugin(JobsPlugin)
+            .add_plugin(RoundsPlugin)
+            .add_plugin(GameModesPlugin)
+            .add_plugin(TraitorModePlugin)
             .add_plugin(HumanMalePlugin)
             .add_plugin(SfxPlugin)
             .add_plugin(HealthPlugin)
@@ -115,6 +134,7 @@ impl Plugin for SpacePlugin {
             .add_plugin(HumanoidPlugin)
             .add_plugin(RigidBodyPlugin)
             .add_plugin(ComputersPlugin)
+            .add_plugin(AtmosMachineryPlugin)
             .add_plugin(CombatPlugin)
             .add_plugin(OmniLightPlugin)
             .add_plugin(ReflectionProbePlugin)
@@ -122,10 +142,20 @@ impl Plugin for SpacePlugin {
             .add_plugin(SenserPlugin)
             .add_plugin(JumpsuitsPlugin)
             .add_plugin(HelmetsPlugin)
+            .add_plugin(IdCardPlugin)
+            .add_plugin(HeadsetPlugin)
             .add_plugin(PistolL1Plugin)
+            .add_plugin(PistolB1Plugin)
+            .add_plugin(MagazineB1Plugin)
+            .add_plugin(PowerCellL1Plugin)
+            .add_plugin(RechargerPlugin)
+            .add_plugin(LadderPlugin)
             .add_plugin(LineArrowPlugin)
             .add_plugin(PointArrowPlugin)
-            .add_plugin(SoundsPlugin);
+            .add_plugin(SoundsPlugin)
+            .add_plugin(MetricsPlugin {
+                address: self.metrics_address.clone(),
+            });
 
         match self.threads_amount {
             Some(amn) => {
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use bevy::prelude::{Entity, Query};
 use api::{
-    data::EntityDataResource,
+    data::{EntityDataResource, HUMAN_DUMMY_ENTITY_NAME, HUMAN_MALE_ENTITY_NAME},
     data_link::DataLink,
     entity_updates::EntityData,
     gridmap::{CellData, GridMapType},
@@ -64,3 +64,33 @@ pub fn pickup_tab_prerequisite_check(
         && entity_id_bits_option.is_some()
         && inventory_component.get_active_slot_entity().is_none()
 }
+
+// Emote tab actions are built from the emote catalogue, they only target other humanoids in sight.
+pub const EMOTE_TARGET_DISTANCE: f32 = 10.;
+
+pub fn emote_tab_prerequisite_check(
+    _self_tab_entity: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    _inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    let entity_bits = match entity_id_bits_option {
+        Some(bits) => bits,
+        None => {
+            return false;
+        }
+    };
+
+    match entity_datas.get(Entity::from_bits(entity_bits)) {
+        Ok(entity_data) => {
+            distance < EMOTE_TARGET_DISTANCE
+                && (entity_data.entity_name == HUMAN_MALE_ENTITY_NAME
+                    || entity_data.entity_name == HUMAN_DUMMY_ENTITY_NAME)
+        }
+        Err(_rr) => false,
+    }
+}
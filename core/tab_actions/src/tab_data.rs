@@ -12,10 +12,7 @@ use api::{
     data_link::DataLink,
     entity_updates::EntityData,
     examinable::Examinable,
-    gridmap::{
-        cell_id_to_world, to_doryen_coordinates, GridMapType, GridmapData, GridmapDetails1,
-        GridmapMain,
-    },
+    gridmap::{cell_id_to_world, GridMapType, GridmapData, GridmapDetails1, GridmapMain},
     inventory::Inventory,
     network::ReliableServerMessage,
     sensable::Sensable,
@@ -161,9 +158,6 @@ pub fn tab_data(
 
             let cell_world_position = cell_id_to_world(event.gridmap_cell_id);
 
-            let doryen_coords =
-                to_doryen_coordinates(event.gridmap_cell_id.x, event.gridmap_cell_id.z);
-
             let this_map;
             let this_names;
 
@@ -208,10 +202,7 @@ pub fn tab_data(
                 }
             }
 
-            if player_senser_component
-                .fov
-                .is_in_fov(doryen_coords.0, doryen_coords.1)
-            {
+            if player_senser_component.is_cell_in_fov(&event.gridmap_cell_id) {
                 if (tab_action.prerequisite_check)(
                     tab_action.belonging_entity,
                     None,
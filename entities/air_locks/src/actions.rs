@@ -4,6 +4,7 @@ pub fn air_locks_actions(
     mut air_lock_lock_open_event: EventWriter<AirLockLockOpen>,
     mut air_lock_lock_closed_event: EventWriter<AirLockLockClosed>,
     mut air_lock_unlock_event: EventWriter<AirLockUnlock>,
+    mut firelock_override_event: EventWriter<InputFirelockOverride>,
 ) {
     for queued in queue.queue.iter() {
         if queued.tab_id == "actions::air_locks/toggleopen" {
@@ -38,6 +39,14 @@ pub fn air_locks_actions(
                     handle_option: queued.handle_option,
                 });
             }
+        } else if queued.tab_id == "actions::air_locks/firelockoverride" {
+            if queued.target_entity_option.is_some() {
+                firelock_override_event.send(InputFirelockOverride {
+                    firelock: Entity::from_bits(queued.target_entity_option.unwrap()),
+                    overrider: queued.player_entity,
+                    handle_option: queued.handle_option,
+                });
+            }
         }
     }
 }
@@ -49,11 +58,13 @@ use api::{
     entity_updates::EntityData,
     gridmap::{CellData, GridMapType},
     inventory::Inventory,
+    pawn::REACH_DISTANCE,
     tab_actions::QueuedTabActions,
 };
 
-use super::air_lock_added::{
-    AirLockLockClosed, AirLockLockOpen, AirLockUnlock, InputAirLockToggleOpen,
+use super::{
+    air_lock_added::{AirLockLockClosed, AirLockLockOpen, AirLockUnlock, InputAirLockToggleOpen},
+    firelock::InputFirelockOverride,
 };
 
 pub fn toggle_open_action(
@@ -116,3 +127,16 @@ pub fn lock_closed_action(
             .links
             .contains(&DataLinkType::RemoteLock)
 }
+
+pub fn firelock_override_action(
+    _self_tab_entity: Option<Entity>,
+    _entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    _inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    distance < REACH_DISTANCE
+}
@@ -23,6 +23,8 @@ pub enum AccessLightsStatus {
     Neutral,
     Granted,
     Denied,
+    // Firelock sealed off by a pressure or temperature differential.
+    Warning,
 }
 
 impl Default for AirLock {
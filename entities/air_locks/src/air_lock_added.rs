@@ -56,7 +56,7 @@ pub fn air_lock_added(
         if AtmosphericsResource::is_id_out_of_range(cell_id2) {
             continue;
         }
-        let atmos_id = get_atmos_index(cell_id2);
+        let atmos_id = atmospherics_resource.get_cell_atmos_index(cell_id);
         let atmospherics = atmospherics_resource
             .atmospherics
             .get_mut(atmos_id)
@@ -158,7 +158,7 @@ use api::{
     data::Vec2Int,
     entity_updates::{EntityData, EntityGroup},
     examinable::{Examinable, RichName},
-    gridmap::{get_atmos_index, world_to_cell_id, EntityGridData, GridmapMain},
+    gridmap::{world_to_cell_id, EntityGridData, GridmapMain},
 };
 
 use crate::spawn::{
@@ -176,11 +176,7 @@ pub fn air_lock_default_map_added(
         airlock_windows.iter()
     {
         let cell_id = world_to_cell_id(rigid_body_position_component.translation.into());
-        let cell_id2 = Vec2Int {
-            x: cell_id.x,
-            y: cell_id.z,
-        };
-        map_data.data.insert(cell_id2, GREEN_MAP_TILE_ENTRANCE);
+        map_data.insert(cell_id, GREEN_MAP_TILE_ENTRANCE);
 
         gridmap_main.entity_data.insert(
             cell_id,
@@ -4,7 +4,7 @@ use bevy::{
     prelude::{warn, Commands, Entity, EventReader, EventWriter, Query, ResMut, Transform},
 };
 use bevy_rapier3d::prelude::CollisionGroups;
-use pawn::pawn::{Pawn, ShipAuthorization};
+use pawn::pawn::{get_id_card_access, Pawn, ShipAuthorization};
 use physics::physics::{get_bit_masks, ColliderGroup};
 use sfx::{builder::sfx_builder, entity_update::SfxAutoDestroyTimers};
 use api::{
@@ -12,7 +12,8 @@ use api::{
     data::{AirLockCloseRequest, LockedStatus, Vec2Int},
     entity_updates::EntityGroup,
     examinable::Examinable,
-    gridmap::{get_atmos_index, world_to_cell_id},
+    gridmap::{world_to_cell_id},
+    inventory::Inventory,
     network::ReliableServerMessage,
 };
 use sounds::{
@@ -30,6 +31,7 @@ use super::{
     air_lock_added::{
         AirLockCollision, AirLockLockClosed, AirLockLockOpen, AirLockUnlock, InputAirLockToggleOpen,
     },
+    firelock::{sealed_firelock_notice, Firelock},
     net::NetAirLock,
 };
 
@@ -38,12 +40,21 @@ pub struct AirLockOpenRequest {
     pub opened: Entity,
 }
 
+// Sealed firelocks only unlock through firelock_override, which lifts the seal first.
+fn is_sealed_firelock(firelocks: &Query<&mut Firelock>, entity: Entity) -> bool {
+    match firelocks.get(entity) {
+        Ok(firelock_component) => firelock_component.sealed,
+        Err(_rr) => false,
+    }
+}
+
 pub fn air_lock_events(
     mut air_lock_collisions: EventReader<AirLockCollision>,
     mut toggle_open_action: EventReader<InputAirLockToggleOpen>,
     transforms: Query<&Transform>,
     mut air_lock_query: Query<(&mut AirLock, Entity, &mut Examinable, &Children)>,
-    pawn_query: Query<(&Pawn, &ShipAuthorization)>,
+    pawn_query: Query<(&Pawn, &Inventory)>,
+    id_cards: Query<&ShipAuthorization>,
     mut auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
     mut commands: Commands,
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
@@ -52,6 +63,7 @@ pub fn air_lock_events(
     mut unlock_events: EventReader<AirLockUnlock>,
     mut net_airlocks: EventWriter<NetAirLock>,
     mut collision_groups: Query<&mut CollisionGroups>,
+    mut firelocks: Query<&mut Firelock>,
 ) {
     let mut close_requests = vec![];
     let mut open_requests = vec![];
@@ -59,6 +71,23 @@ pub fn air_lock_events(
     for event in unlock_events.iter() {
         match air_lock_query.get_mut(event.locked) {
             Ok((mut air_lock_component, _air_lock_entity, mut examinable_component, _children)) => {
+                if is_sealed_firelock(&firelocks, event.locked) {
+                    air_lock_component.access_lights = AccessLightsStatus::Denied;
+                    air_lock_component.denied_timer_option = Some(denied_timer());
+                    match event.handle_option {
+                        Some(t) => {
+                            net_airlocks.send(NetAirLock {
+                                handle: t,
+                                message: ReliableServerMessage::ChatMessage(
+                                    sealed_firelock_notice(&examinable_component.name.get_name()),
+                                ),
+                            });
+                        }
+                        None => {}
+                    }
+                    continue;
+                }
+
                 air_lock_component.locked_status = LockedStatus::None;
                 air_lock_component.access_lights = AccessLightsStatus::Neutral;
 
@@ -96,6 +125,23 @@ pub fn air_lock_events(
     for event in air_lock_lock_open_event.iter() {
         match air_lock_query.get_mut(event.locked) {
             Ok((mut air_lock_component, _air_lock_entity, mut examinable_component, _children)) => {
+                if is_sealed_firelock(&firelocks, event.locked) {
+                    air_lock_component.access_lights = AccessLightsStatus::Denied;
+                    air_lock_component.denied_timer_option = Some(denied_timer());
+                    match event.handle_option {
+                        Some(t) => {
+                            net_airlocks.send(NetAirLock {
+                                handle: t,
+                                message: ReliableServerMessage::ChatMessage(
+                                    sealed_firelock_notice(&examinable_component.name.get_name()),
+                                ),
+                            });
+                        }
+                        None => {}
+                    }
+                    continue;
+                }
+
                 air_lock_component.locked_status = LockedStatus::Open;
                 let personal_update_text = "[font=".to_owned()
                     + FURTHER_ITALIC_FONT
@@ -136,6 +182,16 @@ pub fn air_lock_events(
     for event in air_lock_lock_close_event.iter() {
         match air_lock_query.get_mut(event.locked) {
             Ok((mut air_lock_component, _air_lock_entity, mut examinable_component, _children)) => {
+                // Staff locking a sealed firelock keep it locked once the seal lifts.
+                if event.locker != event.locked {
+                    match firelocks.get_mut(event.locked) {
+                        Ok(mut firelock_component) => {
+                            firelock_component.sealed_lock = false;
+                        }
+                        Err(_rr) => {}
+                    }
+                }
+
                 air_lock_component.locked_status = LockedStatus::Closed;
                 let personal_update_text = "[font=".to_owned()
                     + FURTHER_ITALIC_FONT
@@ -197,7 +253,10 @@ pub fn air_lock_events(
                 }
             }
             LockedStatus::Closed => {
-                if !matches!(air_lock_component.access_lights, AccessLightsStatus::Denied) {
+                if !matches!(
+                    air_lock_component.access_lights,
+                    AccessLightsStatus::Denied | AccessLightsStatus::Warning
+                ) {
                     air_lock_component.access_lights = AccessLightsStatus::Denied;
                 }
             }
@@ -360,25 +419,22 @@ pub fn air_lock_events(
 
         match request.opener_option {
             Some(opener) => {
-                let pawn_space_access_component_result =
-                    pawn_query.get_component::<ShipAuthorization>(opener);
-                let pawn_space_access_component;
+                let pawn_inventory_component_result = pawn_query.get_component::<Inventory>(opener);
+                let pawn_inventory_component;
 
-                match pawn_space_access_component_result {
+                match pawn_inventory_component_result {
                     Ok(result) => {
-                        pawn_space_access_component = result;
+                        pawn_inventory_component = result;
                     }
                     Err(_err) => {
                         continue;
                     }
                 }
 
+                let id_card_access = get_id_card_access(pawn_inventory_component, &id_cards);
+
                 for space_permission in &air_lock_component.access_permissions {
-                    if pawn_space_access_component
-                        .access
-                        .contains(space_permission)
-                        == true
-                    {
+                    if id_card_access.contains(space_permission) == true {
                         pawn_has_permission = true;
                         break;
                     }
@@ -410,7 +466,7 @@ pub fn air_lock_events(
             if AtmosphericsResource::is_id_out_of_range(cell_id2) {
                 continue;
             }
-            let atmos_id = get_atmos_index(cell_id2);
+            let atmos_id = atmospherics_resource.get_cell_atmos_index(cell_id);
             let atmospherics = atmospherics_resource
                 .atmospherics
                 .get_mut(atmos_id)
@@ -494,25 +550,24 @@ pub fn air_lock_events(
 
                 match request.interacter_option {
                     Some(interacter) => {
-                        let pawn_space_access_component_result =
-                            pawn_query.get_component::<ShipAuthorization>(interacter);
-                        let pawn_space_access_component;
+                        let pawn_inventory_component_result =
+                            pawn_query.get_component::<Inventory>(interacter);
+                        let pawn_inventory_component;
 
-                        match pawn_space_access_component_result {
+                        match pawn_inventory_component_result {
                             Ok(result) => {
-                                pawn_space_access_component = result;
+                                pawn_inventory_component = result;
                             }
                             Err(_err) => {
                                 continue;
                             }
                         }
 
+                        let id_card_access =
+                            get_id_card_access(pawn_inventory_component, &id_cards);
+
                         for space_permission in &air_lock_component.access_permissions {
-                            if pawn_space_access_component
-                                .access
-                                .contains(space_permission)
-                                == true
-                            {
+                            if id_card_access.contains(space_permission) == true {
                                 pawn_has_permission = true;
                                 break;
                             }
@@ -535,7 +590,7 @@ pub fn air_lock_events(
                 if AtmosphericsResource::is_id_out_of_range(cell_id2) {
                     continue;
                 }
-                let atmos_id = get_atmos_index(cell_id2);
+                let atmos_id = atmospherics_resource.get_cell_atmos_index(cell_id);
                 let atmospherics = atmospherics_resource
                     .atmospherics
                     .get_mut(atmos_id)
@@ -80,6 +80,22 @@ pub fn air_lock_update(
                     ),
                 );
             }
+            AccessLightsStatus::Warning => {
+                door_left_data.insert(
+                    "emissiveTexture".to_string(),
+                    EntityUpdateData::String(
+                        "/content/entities/securityAirLock1/warningDoorLeftEmissive.png"
+                            .to_string(),
+                    ),
+                );
+                door_right_data.insert(
+                    "emissiveTexture".to_string(),
+                    EntityUpdateData::String(
+                        "/content/entities/securityAirLock1/warningDoorRightEmissive.png"
+                            .to_string(),
+                    ),
+                );
+            }
         }
 
         entity_updates_component
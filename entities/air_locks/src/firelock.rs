@@ -0,0 +1,288 @@
+use atmospherics::diffusion::AtmosphericsResource;
+use bevy::{
+    core::{Time, Timer},
+    prelude::{Component, Entity, EventReader, EventWriter, Query, Res, Transform},
+};
+use pawn::pawn::{get_id_card_access, ShipAuthorization, ShipAuthorizationEnum};
+use api::{
+    atmospherics::Atmospherics,
+    chat::{FURTHER_ITALIC_FONT, WARNING_COLOR},
+    data::{LockedStatus, Vec2Int, Vec3Int},
+    examinable::Examinable,
+    gridmap::world_to_cell_id,
+    inventory::Inventory,
+    network::ReliableServerMessage,
+};
+
+use crate::{
+    air_lock::{denied_timer, AccessLightsStatus, AirLock},
+    air_lock_added::{AirLockLockClosed, AirLockUnlock, InputAirLockToggleOpen},
+    net::NetAirLock,
+};
+
+// Kpa and kelvin difference between opposite sides of the firelock that make it seal.
+const FIRELOCK_PRESSURE_THRESHOLD: f32 = 20.;
+const FIRELOCK_TEMPERATURE_THRESHOLD: f32 = 30.;
+// Seconds a manual override keeps the firelock from sealing again.
+const FIRELOCK_OVERRIDE_DURATION: f32 = 30.;
+
+#[derive(Component)]
+pub struct Firelock {
+    pub sealed: bool,
+    // Whether the lock on the firelock came from sealing it, only that lock gets lifted again.
+    pub sealed_lock: bool,
+    pub override_permissions: Vec<ShipAuthorizationEnum>,
+    pub override_timer_option: Option<Timer>,
+}
+
+impl Default for Firelock {
+    fn default() -> Self {
+        Self {
+            sealed: false,
+            sealed_lock: false,
+            override_permissions: vec![
+                ShipAuthorizationEnum::Security,
+                ShipAuthorizationEnum::Command,
+            ],
+            override_timer_option: None,
+        }
+    }
+}
+
+// The reply to unlocking or locking open a sealed firelock any other way than overriding it.
+pub fn sealed_firelock_notice(name: &str) -> String {
+    "[font=".to_string()
+        + FURTHER_ITALIC_FONT
+        + "][color="
+        + WARNING_COLOR
+        + "]The "
+        + name
+        + " is sealed, it has to be overridden.[/color][/font]"
+}
+
+pub struct InputFirelockOverride {
+    pub handle_option: Option<u64>,
+    pub firelock: Entity,
+    pub overrider: Entity,
+}
+
+fn get_atmospherics(
+    atmospherics_resource: &AtmosphericsResource,
+    cell_id: Vec3Int,
+) -> Option<&Atmospherics> {
+    if AtmosphericsResource::is_id_out_of_range(Vec2Int {
+        x: cell_id.x,
+        y: cell_id.z,
+    }) {
+        return None;
+    }
+    let atmospherics = atmospherics_resource
+        .atmospherics
+        .get(atmospherics_resource.get_cell_atmos_index(cell_id))
+        .unwrap();
+    if atmospherics.blocked {
+        None
+    } else {
+        Some(atmospherics)
+    }
+}
+
+// Compares the cells on opposite sides of the firelock, the firelock cell itself is blocked while closed.
+fn exceeds_thresholds(atmospherics_resource: &AtmosphericsResource, cell_id: Vec3Int) -> bool {
+    let sides = [
+        (
+            Vec3Int {
+                x: cell_id.x + 1,
+                ..cell_id
+            },
+            Vec3Int {
+                x: cell_id.x - 1,
+                ..cell_id
+            },
+        ),
+        (
+            Vec3Int {
+                z: cell_id.z + 1,
+                ..cell_id
+            },
+            Vec3Int {
+                z: cell_id.z - 1,
+                ..cell_id
+            },
+        ),
+    ];
+
+    for (side_a, side_b) in sides {
+        let atmospherics_a = get_atmospherics(atmospherics_resource, side_a);
+        let atmospherics_b = get_atmospherics(atmospherics_resource, side_b);
+
+        for atmospherics in [atmospherics_a, atmospherics_b] {
+            match atmospherics {
+                Some(a) => {
+                    if a.burning {
+                        return true;
+                    }
+                }
+                None => {}
+            }
+        }
+
+        match (atmospherics_a, atmospherics_b) {
+            (Some(a), Some(b)) => {
+                if (a.get_pressure() - b.get_pressure()).abs() > FIRELOCK_PRESSURE_THRESHOLD
+                    || (a.temperature - b.temperature).abs() > FIRELOCK_TEMPERATURE_THRESHOLD
+                {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+pub fn firelock_monitor(
+    mut firelocks: Query<(Entity, &Transform, &mut Firelock, &mut AirLock)>,
+    atmospherics_resource: Res<AtmosphericsResource>,
+    time: Res<Time>,
+    mut air_lock_lock_closed_event: EventWriter<AirLockLockClosed>,
+    mut air_lock_unlock_event: EventWriter<AirLockUnlock>,
+) {
+    for (firelock_entity, transform, mut firelock_component, mut air_lock_component) in
+        firelocks.iter_mut()
+    {
+        let mut overridden = false;
+
+        match firelock_component.override_timer_option.as_mut() {
+            Some(timer) => {
+                timer.tick(time.delta());
+                overridden = !timer.finished();
+            }
+            None => {}
+        }
+
+        if !overridden {
+            firelock_component.override_timer_option = None;
+        }
+
+        let cell_id = world_to_cell_id(transform.translation);
+        let exceeded = exceeds_thresholds(&atmospherics_resource, cell_id);
+
+        if exceeded && !overridden && !firelock_component.sealed {
+            firelock_component.sealed = true;
+            // A lock put on by staff beforehand stays theirs.
+            firelock_component.sealed_lock =
+                !matches!(air_lock_component.locked_status, LockedStatus::Closed);
+            if firelock_component.sealed_lock {
+                air_lock_lock_closed_event.send(AirLockLockClosed {
+                    handle_option: None,
+                    locked: firelock_entity,
+                    locker: firelock_entity,
+                });
+            }
+        } else if !exceeded && firelock_component.sealed {
+            firelock_component.sealed = false;
+            if firelock_component.sealed_lock {
+                firelock_component.sealed_lock = false;
+                air_lock_unlock_event.send(AirLockUnlock {
+                    handle_option: None,
+                    locked: firelock_entity,
+                    locker: firelock_entity,
+                });
+            }
+        }
+
+        if firelock_component.sealed
+            && matches!(air_lock_component.locked_status, LockedStatus::Closed)
+            && !matches!(
+                air_lock_component.access_lights,
+                AccessLightsStatus::Warning
+            )
+        {
+            air_lock_component.access_lights = AccessLightsStatus::Warning;
+        }
+    }
+}
+
+pub fn firelock_override(
+    mut override_events: EventReader<InputFirelockOverride>,
+    mut firelocks: Query<(&mut Firelock, &mut AirLock, &Examinable)>,
+    inventories: Query<&Inventory>,
+    id_cards: Query<&ShipAuthorization>,
+    mut air_lock_unlock_event: EventWriter<AirLockUnlock>,
+    mut air_lock_toggle_open_event: EventWriter<InputAirLockToggleOpen>,
+    mut net_airlocks: EventWriter<NetAirLock>,
+) {
+    for event in override_events.iter() {
+        let (mut firelock_component, mut air_lock_component, examinable_component) =
+            match firelocks.get_mut(event.firelock) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
+        let mut has_permission = false;
+
+        match inventories.get(event.overrider) {
+            Ok(inventory_component) => {
+                let id_card_access = get_id_card_access(inventory_component, &id_cards);
+                for permission in firelock_component.override_permissions.iter() {
+                    if id_card_access.contains(permission) {
+                        has_permission = true;
+                        break;
+                    }
+                }
+            }
+            Err(_rr) => {}
+        }
+
+        let personal_update_text;
+
+        if has_permission {
+            firelock_component.sealed = false;
+            firelock_component.sealed_lock = false;
+            firelock_component.override_timer_option =
+                Some(Timer::from_seconds(FIRELOCK_OVERRIDE_DURATION, false));
+
+            air_lock_unlock_event.send(AirLockUnlock {
+                handle_option: None,
+                locked: event.firelock,
+                locker: event.overrider,
+            });
+            air_lock_toggle_open_event.send(InputAirLockToggleOpen {
+                handle_option: event.handle_option,
+                opener: event.overrider,
+                opened: event.firelock.to_bits(),
+            });
+
+            personal_update_text = "[font=".to_string()
+                + FURTHER_ITALIC_FONT
+                + "]You override the "
+                + &examinable_component.name.get_name()
+                + ".[/font]";
+        } else {
+            air_lock_component.access_lights = AccessLightsStatus::Denied;
+            air_lock_component.denied_timer_option = Some(denied_timer());
+
+            personal_update_text = "[font=".to_string()
+                + FURTHER_ITALIC_FONT
+                + "][color="
+                + WARNING_COLOR
+                + "]You are not authorized to override the "
+                + &examinable_component.name.get_name()
+                + ".[/color][/font]";
+        }
+
+        match event.handle_option {
+            Some(handle) => {
+                net_airlocks.send(NetAirLock {
+                    handle,
+                    message: ReliableServerMessage::ChatMessage(personal_update_text),
+                });
+            }
+            None => {}
+        }
+    }
+}
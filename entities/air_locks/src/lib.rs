@@ -4,6 +4,7 @@ pub mod air_lock_added;
 pub mod air_lock_events;
 pub mod air_lock_tick_timers;
 pub mod entity_update;
+pub mod firelock;
 pub mod net;
 pub mod physics_events;
 pub mod plugin;
@@ -26,11 +26,12 @@ use super::{
     air_lock_events::air_lock_events,
     air_lock_tick_timers::air_lock_tick_timers,
     entity_update::air_lock_update,
+    firelock::{firelock_monitor, firelock_override, InputFirelockOverride},
     net::NetAirLock,
     spawn::{
         default_summon_air_lock, summon_air_lock, summon_raw_air_lock, AirlockSummoner,
-        BRIDGE_AIRLOCK_ENTITY_NAME, GOVERNMENT_AIRLOCK_ENTITY_NAME, SECURITY_AIRLOCK_ENTITY_NAME,
-        VACUUM_AIRLOCK_ENTITY_NAME,
+        BRIDGE_AIRLOCK_ENTITY_NAME, FIRELOCK_ENTITY_NAME, GOVERNMENT_AIRLOCK_ENTITY_NAME,
+        SECURITY_AIRLOCK_ENTITY_NAME, VACUUM_AIRLOCK_ENTITY_NAME,
     },
 };
 use bevy::app::CoreStage::PostUpdate;
@@ -52,6 +53,9 @@ impl Plugin for AirLocksPlugin {
             .add_system(air_lock_events)
             .add_system(air_lock_default_map_added)
             .add_system(physics_events)
+            .add_event::<InputFirelockOverride>()
+            .add_system(firelock_monitor)
+            .add_system(firelock_override)
             .add_event::<AirLockLockClosed>()
             .add_event::<AirLockUnlock>()
             .add_event::<SpawnEvent<AirlockSummoner>>()
@@ -130,4 +134,15 @@ pub fn content_initialization(mut entity_data: ResMut<EntityDataResource>) {
     };
 
     initialize_entity_data(&mut entity_data, entity_properties);
+
+    let entity_properties = EntityDataProperties {
+        name: FIRELOCK_ENTITY_NAME.to_string(),
+        id: entity_data.get_id_inc(),
+        grid_item: Some(GridItemData {
+            transform_offset: Transform::identity(),
+            can_be_built_with_grid_item: vec![],
+        }),
+    };
+
+    initialize_entity_data(&mut entity_data, entity_properties);
 }
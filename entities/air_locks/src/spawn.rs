@@ -30,6 +30,11 @@ impl BaseEntitySummonable<NoData> for AirlockSummoner {
             description = "An air lock with ".to_string()
                 + "danger markings"
                 + ". On the other side is nothing but space.";
+        } else if spawn_data.entity_name == FIRELOCK_ENTITY_NAME {
+            sub_name = "fire";
+            description = "An air lock with ".to_string()
+                + "hazard stripes"
+                + ". It seals itself when it senses fire or a dangerous pressure difference.";
         } else {
             warn!("Unrecognized airlock sub-type {}", spawn_data.entity_name);
             sub_name = "ERR";
@@ -47,6 +52,47 @@ impl BaseEntitySummonable<NoData> for AirlockSummoner {
                 + "]It is fully operational.[/color][/font]",
         );
 
+        let mut tab_actions = vec![
+            TabAction {
+                id: "actions::air_locks/toggleopen".to_string(),
+                text: "Toggle Open".to_string(),
+                tab_list_priority: 100,
+                prerequisite_check: Arc::new(toggle_open_action),
+                belonging_entity: Some(spawn_data.entity),
+            },
+            TabAction {
+                id: "actions::air_locks/lockopen".to_string(),
+                text: "Lock Open".to_string(),
+                tab_list_priority: 99,
+                prerequisite_check: Arc::new(lock_open_action),
+                belonging_entity: Some(spawn_data.entity),
+            },
+            TabAction {
+                id: "actions::air_locks/lockclosed".to_string(),
+                text: "Lock Closed".to_string(),
+                tab_list_priority: 98,
+                prerequisite_check: Arc::new(lock_closed_action),
+                belonging_entity: Some(spawn_data.entity),
+            },
+            TabAction {
+                id: "actions::air_locks/unlock".to_string(),
+                text: "Unlock".to_string(),
+                tab_list_priority: 97,
+                prerequisite_check: Arc::new(unlock_action),
+                belonging_entity: Some(spawn_data.entity),
+            },
+        ];
+
+        if spawn_data.entity_name == FIRELOCK_ENTITY_NAME {
+            tab_actions.push(TabAction {
+                id: "actions::air_locks/firelockoverride".to_string(),
+                text: "Override".to_string(),
+                tab_list_priority: 96,
+                prerequisite_check: Arc::new(firelock_override_action),
+                belonging_entity: Some(spawn_data.entity),
+            });
+        }
+
         BaseEntityBundle {
             default_transform: get_default_transform(),
             examinable: Examinable {
@@ -60,38 +106,7 @@ impl BaseEntitySummonable<NoData> for AirlockSummoner {
             },
             entity_name: spawn_data.entity_name.to_string(),
             entity_group: EntityGroup::AirLock,
-            tab_actions_option: Some(TabActions {
-                tab_actions: vec![
-                    TabAction {
-                        id: "actions::air_locks/toggleopen".to_string(),
-                        text: "Toggle Open".to_string(),
-                        tab_list_priority: 100,
-                        prerequisite_check: Arc::new(toggle_open_action),
-                        belonging_entity: Some(spawn_data.entity),
-                    },
-                    TabAction {
-                        id: "actions::air_locks/lockopen".to_string(),
-                        text: "Lock Open".to_string(),
-                        tab_list_priority: 99,
-                        prerequisite_check: Arc::new(lock_open_action),
-                        belonging_entity: Some(spawn_data.entity),
-                    },
-                    TabAction {
-                        id: "actions::air_locks/lockclosed".to_string(),
-                        text: "Lock Closed".to_string(),
-                        tab_list_priority: 98,
-                        prerequisite_check: Arc::new(lock_closed_action),
-                        belonging_entity: Some(spawn_data.entity),
-                    },
-                    TabAction {
-                        id: "actions::air_locks/unlock".to_string(),
-                        text: "Unlock".to_string(),
-                        tab_list_priority: 97,
-                        prerequisite_check: Arc::new(unlock_action),
-                        belonging_entity: Some(spawn_data.entity),
-                    },
-                ],
-            }),
+            tab_actions_option: Some(TabActions { tab_actions }),
             health: Health {
                 is_combat_obstacle: true,
                 is_reach_obstacle: true,
@@ -111,7 +126,7 @@ use entity::{
     entity_data::RawSpawnEvent,
     spawn::{BaseEntityBundle, BaseEntitySummonable, DefaultSpawnEvent, SpawnData, SpawnEvent},
 };
-use pawn::pawn::ShipAuthorizationEnum;
+use pawn::pawn::{get_raw_entity_access, ShipAuthorizationEnum};
 use rigid_body::spawn::{RigidBodyBundle, RigidBodySummonable};
 use api::{
     chat::{FURTHER_ITALIC_FONT, HEALTHY_COLOR},
@@ -123,7 +138,10 @@ use api::{
     tab_actions::{TabAction, TabActions},
 };
 
-use super::actions::{lock_closed_action, lock_open_action, toggle_open_action, unlock_action};
+use super::actions::{
+    firelock_override_action, lock_closed_action, lock_open_action, toggle_open_action,
+    unlock_action,
+};
 
 pub const DEFAULT_AIR_LOCK_Y: f32 = 1.;
 
@@ -142,7 +160,7 @@ impl RigidBodySummonable<NoData> for AirlockSummoner {
     }
 }
 
-use super::air_lock::AirLock;
+use super::{air_lock::AirLock, firelock::Firelock};
 
 pub struct AirlockSummoner;
 
@@ -151,12 +169,43 @@ pub fn summon_air_lock<T: Send + Sync + 'static>(
     mut airlock_spawns: EventReader<SpawnEvent<T>>,
 ) {
     for spawn_event in airlock_spawns.iter() {
+        let mut access_permissions = get_default_access_permissions(&spawn_event.spawn_data);
+
+        match &spawn_event.spawn_data.raw_entity_option {
+            Some(raw_entity) => match get_raw_entity_access(&raw_entity.data) {
+                Some(access) => {
+                    access_permissions = access;
+                }
+                None => {}
+            },
+            None => {}
+        }
+
         commands
             .entity(spawn_event.spawn_data.entity)
             .insert(AirLock {
-                access_permissions: vec![ShipAuthorizationEnum::Security],
+                access_permissions,
                 ..Default::default()
             });
+
+        if spawn_event.spawn_data.entity_name == FIRELOCK_ENTITY_NAME {
+            commands
+                .entity(spawn_event.spawn_data.entity)
+                .insert(Firelock::default());
+        }
+    }
+}
+
+// Used when the map entity data doesn't override access.
+fn get_default_access_permissions(spawn_data: &SpawnData) -> Vec<ShipAuthorizationEnum> {
+    if spawn_data.entity_name == BRIDGE_AIRLOCK_ENTITY_NAME
+        || spawn_data.entity_name == GOVERNMENT_AIRLOCK_ENTITY_NAME
+    {
+        vec![ShipAuthorizationEnum::Command]
+    } else if spawn_data.entity_name == FIRELOCK_ENTITY_NAME {
+        vec![ShipAuthorizationEnum::Common]
+    } else {
+        vec![ShipAuthorizationEnum::Security]
     }
 }
 
@@ -164,6 +213,7 @@ pub const SECURITY_AIRLOCK_ENTITY_NAME: &str = "securityAirLock1";
 pub const BRIDGE_AIRLOCK_ENTITY_NAME: &str = "bridgeAirLock";
 pub const GOVERNMENT_AIRLOCK_ENTITY_NAME: &str = "governmentAirLock";
 pub const VACUUM_AIRLOCK_ENTITY_NAME: &str = "vacuumAirLock";
+pub const FIRELOCK_ENTITY_NAME: &str = "firelock";
 
 pub fn default_summon_air_lock(
     mut default_spawner: EventReader<DefaultSpawnEvent>,
@@ -171,9 +221,10 @@ pub fn default_summon_air_lock(
 ) {
     for spawn_event in default_spawner.iter() {
         if spawn_event.spawn_data.entity_name != SECURITY_AIRLOCK_ENTITY_NAME
-            || spawn_event.spawn_data.entity_name != BRIDGE_AIRLOCK_ENTITY_NAME
-            || spawn_event.spawn_data.entity_name != GOVERNMENT_AIRLOCK_ENTITY_NAME
-            || spawn_event.spawn_data.entity_name != VACUUM_AIRLOCK_ENTITY_NAME
+            && spawn_event.spawn_data.entity_name != BRIDGE_AIRLOCK_ENTITY_NAME
+            && spawn_event.spawn_data.entity_name != GOVERNMENT_AIRLOCK_ENTITY_NAME
+            && spawn_event.spawn_data.entity_name != VACUUM_AIRLOCK_ENTITY_NAME
+            && spawn_event.spawn_data.entity_name != FIRELOCK_ENTITY_NAME
         {
             continue;
         }
@@ -195,6 +246,7 @@ pub fn summon_raw_air_lock(
             && spawn_event.raw_entity.entity_type != BRIDGE_AIRLOCK_ENTITY_NAME
             && spawn_event.raw_entity.entity_type != GOVERNMENT_AIRLOCK_ENTITY_NAME
             && spawn_event.raw_entity.entity_type != VACUUM_AIRLOCK_ENTITY_NAME
+            && spawn_event.raw_entity.entity_type != FIRELOCK_ENTITY_NAME
         {
             continue;
         }
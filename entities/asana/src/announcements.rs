@@ -0,0 +1,76 @@
+use bevy::{
+    math::Vec3,
+    prelude::{warn, Entity, EventReader, EventWriter, Query, Res, Transform},
+};
+use connected_player::{
+    chat::{new_chat_message, Communicator, MessagingPlayerState, NetChatMessage, Radio},
+    chat_filter::LogChatMessage,
+    language::{KnownLanguages, Languages},
+    radio::RadioChannels,
+};
+use pawn::pawn::{PersistentPlayerData, ShipJob};
+use api::{
+    asana::AsanaAnnouncement,
+    chat::JOB_CONTROL_WORD,
+    data::{ConnectedPlayer, HandleToEntity},
+};
+
+use crate::config::AsanaConfig;
+
+pub fn asana_announcements(
+    mut asana_announcements: EventReader<AsanaAnnouncement>,
+    asana_config: Res<AsanaConfig>,
+    mut net_new_chat_message_event: EventWriter<NetChatMessage>,
+    handle_to_entity: Res<HandleToEntity>,
+    radio_pawns: Query<(Entity, &Radio, &Transform, &PersistentPlayerData)>,
+    global_listeners: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    radio_channels: Res<RadioChannels>,
+    mut log_chat_message: EventWriter<LogChatMessage>,
+    languages: Res<Languages>,
+    known_languages: Query<&KnownLanguages>,
+) {
+    for announcement in asana_announcements.iter() {
+        let channel_id = match &announcement.channel_option {
+            Some(channel_id) => channel_id,
+            None => &asana_config.default_channel,
+        };
+
+        let channel_prefix = match radio_channels.get(channel_id) {
+            Some(channel) => channel.prefix.clone(),
+            None => {
+                warn!(
+                    "ASANA cannot announce on unknown radio channel {}.",
+                    channel_id
+                );
+                continue;
+            }
+        };
+
+        let sensed_by_vec: Vec<Entity> = vec![];
+
+        new_chat_message(
+            &mut net_new_chat_message_event,
+            &handle_to_entity,
+            &sensed_by_vec,
+            &sensed_by_vec,
+            Vec3::ZERO,
+            "ASANA".to_string(),
+            &ShipJob {
+                id: "control".to_string(),
+                name: JOB_CONTROL_WORD.to_string(),
+            },
+            channel_prefix + &announcement.message,
+            Communicator::Machine,
+            true,
+            &radio_pawns,
+            &radio_channels,
+            &languages,
+            &known_languages,
+            &global_listeners,
+            &mut log_chat_message,
+            None,
+            None,
+            &MessagingPlayerState::Alive,
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::{fs, path::Path};
+
+use bevy::prelude::{info, ResMut};
+use serde::Deserialize;
+
+#[derive(Deserialize, Default)]
+pub struct ArrivalAnnouncementData {
+    pub enabled: bool,
+    pub channel: Option<String>,
+    pub delay: f32,
+    // {job} and {name} are replaced with those of the arriving crew member.
+    pub message: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct HullBreachAnnouncementData {
+    pub enabled: bool,
+    pub channel: Option<String>,
+    // Seconds before another breach gets announced, a breach tends to remove several cells at once.
+    pub cooldown: f32,
+    // {x} and {y} are replaced with the breached cell.
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+pub struct PhaseAnnouncementData {
+    // Name of the RoundState that triggers the announcement.
+    pub phase: String,
+    pub channel: Option<String>,
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+pub struct ScheduledAnnouncementData {
+    pub channel: Option<String>,
+    // Seconds after the round started.
+    pub delay: f32,
+    pub repeat: Option<f32>,
+    pub message: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct AsanaConfig {
+    pub default_channel: String,
+    pub arrivals: ArrivalAnnouncementData,
+    pub hull_breach: HullBreachAnnouncementData,
+    pub round_phases: Vec<PhaseAnnouncementData>,
+    pub scheduled: Vec<ScheduledAnnouncementData>,
+}
+
+pub fn startup_asana_config(mut asana_config: ResMut<AsanaConfig>) {
+    let config_json = Path::new("data").join("asana").join("asana.json");
+    let config_raw_json: String = fs::read_to_string(config_json)
+        .expect("startup_asana_config() Error reading asana.json from drive.");
+    *asana_config = serde_json::from_str(&config_raw_json)
+        .expect("startup_asana_config() Error parsing asana.json String.");
+
+    info!(
+        "Loaded ASANA config with {} scheduled announcements.",
+        asana_config.scheduled.len()
+    );
+}
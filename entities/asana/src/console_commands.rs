@@ -0,0 +1,112 @@
+use bevy::prelude::{EventReader, EventWriter, Query, Res, ResMut};
+use connected_player::radio::RadioChannels;
+use console_commands::commands::{AllConsoleCommands, NetConsoleCommands};
+use networking::messages::InputConsoleCommand;
+use api::{
+    asana::AsanaAnnouncement,
+    chat::escape_bb,
+    console_commands::{
+        ConsoleCommandVariant, ConsoleCommandVariantValues, CONSOLE_ERROR_COLOR,
+        CONSOLE_SUCCESS_COLOR,
+    },
+    data::ConnectedPlayer,
+    network::ReliableServerMessage,
+};
+
+pub fn initialize_console_commands(mut commands: ResMut<AllConsoleCommands>) {
+    commands.list.push((
+        "announce".to_string(),
+        "Have ASANA announce a message on a radio channel, \"default\" uses the configured channel."
+            .to_string(),
+        vec![
+            ("channel".to_string(), ConsoleCommandVariant::String),
+            ("message".to_string(), ConsoleCommandVariant::String),
+        ],
+    ));
+}
+
+pub fn asana_console_commands(
+    mut queue: EventReader<InputConsoleCommand>,
+    connected_players: Query<&ConnectedPlayer>,
+    radio_channels: Res<RadioChannels>,
+    mut net_console_commands: EventWriter<NetConsoleCommands>,
+    mut asana_announcements: EventWriter<AsanaAnnouncement>,
+) {
+    for console_command_event in queue.iter() {
+        if console_command_event.command_name != "announce" {
+            continue;
+        }
+
+        let handle;
+        match console_command_event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        match connected_players.get(console_command_event.entity) {
+            Ok(connected_player) => {
+                if !connected_player.rcon {
+                    net_console_commands.send(NetConsoleCommands {
+                        handle,
+                        message: ReliableServerMessage::ConsoleWriteLine(
+                            "[color=".to_string()
+                                + CONSOLE_ERROR_COLOR
+                                + "]RCON status denied.[/color]",
+                        ),
+                    });
+                    continue;
+                }
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let (channel, message) = match (
+            console_command_event.command_arguments.get(0),
+            console_command_event.command_arguments.get(1),
+        ) {
+            (
+                Some(ConsoleCommandVariantValues::String(channel)),
+                Some(ConsoleCommandVariantValues::String(message)),
+            ) => (channel.clone(), message.clone()),
+            _ => {
+                continue;
+            }
+        };
+
+        let channel_option = if channel == "default" {
+            None
+        } else if radio_channels.get(&channel).is_some() {
+            Some(channel.clone())
+        } else {
+            net_console_commands.send(NetConsoleCommands {
+                handle,
+                message: ReliableServerMessage::ConsoleWriteLine(
+                    "[color=".to_string()
+                        + CONSOLE_ERROR_COLOR
+                        + "]Unknown radio channel "
+                        + &escape_bb(channel, true, true)
+                        + ".[/color]",
+                ),
+            });
+            continue;
+        };
+
+        asana_announcements.send(AsanaAnnouncement {
+            message,
+            channel_option,
+        });
+
+        net_console_commands.send(NetConsoleCommands {
+            handle,
+            message: ReliableServerMessage::ConsoleWriteLine(
+                "[color=".to_string() + CONSOLE_SUCCESS_COLOR + "]Announced.[/color]",
+            ),
+        });
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::{
+    core::Time,
+    prelude::{EventReader, EventWriter, Local, Res},
+};
+use api::{
+    asana::AsanaAnnouncement,
+    atmospherics::HullBreach,
+    rounds::{RoundState, RoundStateChanged, RoundStatus},
+};
+
+use crate::config::AsanaConfig;
+
+pub fn asana_hull_breach_announcements(
+    mut hull_breaches: EventReader<HullBreach>,
+    asana_config: Res<AsanaConfig>,
+    round_status: Res<RoundStatus>,
+    time: Res<Time>,
+    mut last_announced_option: Local<Option<f64>>,
+    mut asana_announcements: EventWriter<AsanaAnnouncement>,
+) {
+    for hull_breach in hull_breaches.iter() {
+        if !asana_config.hull_breach.enabled || round_status.state != RoundState::InProgress {
+            continue;
+        }
+
+        let now = time.seconds_since_startup();
+
+        match *last_announced_option {
+            Some(last_announced) => {
+                if now - last_announced < asana_config.hull_breach.cooldown as f64 {
+                    continue;
+                }
+            }
+            None => {}
+        }
+
+        *last_announced_option = Some(now);
+
+        asana_announcements.send(AsanaAnnouncement {
+            message: asana_config
+                .hull_breach
+                .message
+                .replace("{x}", &hull_breach.cell_id.x.to_string())
+                .replace("{y}", &hull_breach.cell_id.y.to_string()),
+            channel_option: asana_config.hull_breach.channel.clone(),
+        });
+    }
+}
+
+pub fn asana_round_phase_announcements(
+    mut round_state_changed: EventReader<RoundStateChanged>,
+    asana_config: Res<AsanaConfig>,
+    mut asana_announcements: EventWriter<AsanaAnnouncement>,
+) {
+    for state_changed in round_state_changed.iter() {
+        let phase = format!("{:?}", state_changed.new);
+
+        for phase_announcement in asana_config.round_phases.iter() {
+            if phase_announcement.phase != phase {
+                continue;
+            }
+
+            asana_announcements.send(AsanaAnnouncement {
+                message: phase_announcement.message.clone(),
+                channel_option: phase_announcement.channel.clone(),
+            });
+        }
+    }
+}
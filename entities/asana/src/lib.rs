@@ -1,2 +1,7 @@
+pub mod announcements;
+pub mod config;
+pub mod console_commands;
+pub mod events;
 pub mod plugin;
+pub mod scheduled;
 pub mod tick_asana_boarding_announcements;
@@ -1,11 +1,57 @@
-use bevy::prelude::{App, Plugin};
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, SystemLabel};
+use api::{
+    asana::AsanaAnnouncement, console_commands::ConsoleCommandsLabels, data::StartupLabels,
+    rounds::RoundsLabels,
+};
 
-use super::tick_asana_boarding_announcements::tick_asana_boarding_announcements;
+use crate::{
+    announcements::asana_announcements,
+    config::{startup_asana_config, AsanaConfig},
+    console_commands::{asana_console_commands, initialize_console_commands},
+    events::{asana_hull_breach_announcements, asana_round_phase_announcements},
+    scheduled::{
+        reset_scheduled_announcements, tick_scheduled_announcements, ScheduledAnnouncements,
+    },
+};
+
+use super::tick_asana_boarding_announcements::{
+    queue_asana_boarding_announcements, tick_asana_boarding_announcements,
+};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum AsanaLabels {
+    // Systems sending AsanaAnnouncement events.
+    Queue,
+}
 
 pub struct AsanaPlugin;
 
 impl Plugin for AsanaPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(tick_asana_boarding_announcements);
+        app.init_resource::<AsanaConfig>()
+            .init_resource::<ScheduledAnnouncements>()
+            .add_event::<AsanaAnnouncement>()
+            .add_startup_system(startup_asana_config.label(StartupLabels::MiscResources))
+            .add_startup_system(
+                initialize_console_commands
+                    .before(ConsoleCommandsLabels::Finalize)
+                    .label(StartupLabels::ConsoleCommands),
+            )
+            .add_system(queue_asana_boarding_announcements)
+            .add_system(tick_asana_boarding_announcements.label(AsanaLabels::Queue))
+            .add_system(asana_hull_breach_announcements.label(AsanaLabels::Queue))
+            .add_system(
+                asana_round_phase_announcements
+                    .after(RoundsLabels::Controller)
+                    .label(AsanaLabels::Queue),
+            )
+            .add_system(
+                reset_scheduled_announcements
+                    .after(RoundsLabels::Controller)
+                    .before(AsanaLabels::Queue),
+            )
+            .add_system(tick_scheduled_announcements.label(AsanaLabels::Queue))
+            .add_system(asana_console_commands.label(AsanaLabels::Queue))
+            .add_system(asana_announcements.after(AsanaLabels::Queue));
     }
 }
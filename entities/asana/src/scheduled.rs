@@ -0,0 +1,74 @@
+use bevy::{
+    core::{Time, Timer},
+    prelude::{EventReader, EventWriter, Res, ResMut},
+};
+use api::{
+    asana::AsanaAnnouncement,
+    rounds::{RoundState, RoundStateChanged, RoundStatus},
+};
+
+use crate::config::AsanaConfig;
+
+// One timer per entry of AsanaConfig::scheduled, None once a non repeating announcement went out.
+#[derive(Default)]
+pub struct ScheduledAnnouncements {
+    pub timers: Vec<Option<Timer>>,
+}
+
+pub fn reset_scheduled_announcements(
+    mut round_state_changed: EventReader<RoundStateChanged>,
+    asana_config: Res<AsanaConfig>,
+    mut scheduled_announcements: ResMut<ScheduledAnnouncements>,
+) {
+    for state_changed in round_state_changed.iter() {
+        if state_changed.new == RoundState::InProgress {
+            scheduled_announcements.timers = asana_config
+                .scheduled
+                .iter()
+                .map(|scheduled| Some(Timer::from_seconds(scheduled.delay, false)))
+                .collect();
+        } else {
+            scheduled_announcements.timers.clear();
+        }
+    }
+}
+
+pub fn tick_scheduled_announcements(
+    time: Res<Time>,
+    round_status: Res<RoundStatus>,
+    asana_config: Res<AsanaConfig>,
+    mut scheduled_announcements: ResMut<ScheduledAnnouncements>,
+    mut asana_announcements: EventWriter<AsanaAnnouncement>,
+) {
+    if round_status.state != RoundState::InProgress {
+        return;
+    }
+
+    for (i, timer_option) in scheduled_announcements.timers.iter_mut().enumerate() {
+        let scheduled = match asana_config.scheduled.get(i) {
+            Some(scheduled) => scheduled,
+            None => {
+                continue;
+            }
+        };
+
+        let finished = match timer_option {
+            Some(timer) => timer.tick(time.delta()).just_finished(),
+            None => false,
+        };
+
+        if !finished {
+            continue;
+        }
+
+        asana_announcements.send(AsanaAnnouncement {
+            message: scheduled.message.clone(),
+            channel_option: scheduled.channel.clone(),
+        });
+
+        *timer_option = match scheduled.repeat {
+            Some(repeat) => Some(Timer::from_seconds(repeat, false)),
+            None => None,
+        };
+    }
+}
@@ -1,22 +1,38 @@
 use bevy::{
-    core::Time,
-    math::Vec3,
-    prelude::{Entity, EventWriter, Query, Res, ResMut, Transform},
+    core::{Time, Timer},
+    prelude::{EventReader, EventWriter, Res, ResMut},
 };
-use connected_player::{
-    boarding::BoardingAnnouncements,
-    chat::{new_chat_message, Communicator, MessagingPlayerState, NetChatMessage, Radio},
-};
-use pawn::pawn::{PersistentPlayerData, ShipJobsEnum};
-use api::data::{ConnectedPlayer, HandleToEntity};
+use connected_player::boarding::{BoardingAnnouncements, NewArrival};
+use api::asana::AsanaAnnouncement;
+
+use crate::config::AsanaConfig;
+
+pub fn queue_asana_boarding_announcements(
+    mut new_arrivals: EventReader<NewArrival>,
+    asana_config: Res<AsanaConfig>,
+    mut asana_boarding_announcements: ResMut<BoardingAnnouncements>,
+) {
+    for new_arrival in new_arrivals.iter() {
+        if !asana_config.arrivals.enabled {
+            continue;
+        }
+
+        asana_boarding_announcements.announcements.push((
+            asana_config
+                .arrivals
+                .message
+                .replace("{job}", &new_arrival.job_title)
+                .replace("{name}", &new_arrival.character_name),
+            Timer::from_seconds(asana_config.arrivals.delay, false),
+        ));
+    }
+}
 
 pub fn tick_asana_boarding_announcements(
-    mut net_new_chat_message_event: EventWriter<NetChatMessage>,
-    handle_to_entity: Res<HandleToEntity>,
-    radio_pawns: Query<(Entity, &Radio, &Transform, &PersistentPlayerData)>,
     mut asana_boarding_announcements: ResMut<BoardingAnnouncements>,
     time: Res<Time>,
-    global_listeners: Query<(&ConnectedPlayer, &PersistentPlayerData)>,
+    asana_config: Res<AsanaConfig>,
+    mut asana_announcements: EventWriter<AsanaAnnouncement>,
 ) {
     let mut done_messages: Vec<usize> = vec![];
 
@@ -26,25 +42,10 @@ pub fn tick_asana_boarding_announcements(
         &mut asana_boarding_announcements.announcements
     {
         if announcement_timer.tick(time.delta()).just_finished() {
-            let sensed_by_vec: Vec<Entity> = vec![];
-
-            new_chat_message(
-                &mut net_new_chat_message_event,
-                &handle_to_entity,
-                &sensed_by_vec,
-                &sensed_by_vec,
-                Vec3::ZERO,
-                "ASANA".to_string(),
-                ShipJobsEnum::Control,
-                announcement_message.to_string(),
-                Communicator::Machine,
-                true,
-                &radio_pawns,
-                &global_listeners,
-                None,
-                None,
-                &MessagingPlayerState::Alive,
-            );
+            asana_announcements.send(AsanaAnnouncement {
+                message: announcement_message.clone(),
+                channel_option: asana_config.arrivals.channel.clone(),
+            });
 
             done_messages.push(j);
         }
@@ -52,7 +53,8 @@ pub fn tick_asana_boarding_announcements(
         j += 1;
     }
 
-    for j in done_messages {
+    // Removed back to front so the remaining indices stay valid.
+    for j in done_messages.into_iter().rev() {
         asana_boarding_announcements.announcements.remove(j);
     }
 }
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use bevy::prelude::{Entity, EventReader, EventWriter, Query, Res, Transform};
+use networking::messages::TextTreeInputSelection;
+use api::{
+    chat::FURTHER_ITALIC_FONT,
+    data::{EntityDataResource, HandleToEntity},
+    data_link::DataLink,
+    entity_updates::EntityData,
+    gridmap::{CellData, GridMapType},
+    inventory::Inventory,
+    network::{ReliableServerMessage, TextTreeBit},
+    pawn::REACH_DISTANCE,
+    tab_actions::QueuedTabActions,
+};
+
+use crate::{
+    machinery::{AtmosMachinery, MAX_TARGET_PRESSURE, MIN_TARGET_PRESSURE},
+    net::NetAtmosMachinery,
+};
+
+pub const TOGGLE_TAB_ACTION_ID: &str = "actions::atmos_machinery/toggle";
+pub const SET_PRESSURE_TAB_ACTION_ID: &str = "actions::atmos_machinery/setpressure";
+const SET_PRESSURE_MENU_ID: &str = "textselection::atmos_machinery/setpressure";
+
+const PRESSURE_OPTIONS: [f32; 6] = [10., 50., 80., 101.325, 120., 150.];
+
+pub fn atmos_machinery_action(
+    _self_tab_entity: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    _inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    entity_id_bits_option.is_some() && distance < REACH_DISTANCE
+}
+
+fn machinery_notice(text: &str) -> ReliableServerMessage {
+    ReliableServerMessage::ChatMessage(
+        "[font=".to_string() + FURTHER_ITALIC_FONT + "]" + text + "[/font]",
+    )
+}
+
+fn pressure_label(pressure: f32) -> String {
+    format!("{:.1} kPa", pressure)
+}
+
+pub fn atmos_machinery_actions(
+    queue: Res<QueuedTabActions>,
+    mut machinery: Query<&mut AtmosMachinery>,
+    mut net_atmos_machinery: EventWriter<NetAtmosMachinery>,
+) {
+    for queued in queue.queue.iter() {
+        if queued.tab_id != TOGGLE_TAB_ACTION_ID && queued.tab_id != SET_PRESSURE_TAB_ACTION_ID {
+            continue;
+        }
+
+        let machinery_entity;
+
+        match queued.target_entity_option {
+            Some(bits) => {
+                machinery_entity = Entity::from_bits(bits);
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let mut machinery_component;
+
+        match machinery.get_mut(machinery_entity) {
+            Ok(component) => {
+                machinery_component = component;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        if queued.tab_id == TOGGLE_TAB_ACTION_ID {
+            machinery_component.enabled = !machinery_component.enabled;
+
+            let mut text = match machinery_component.enabled {
+                true => "You switch the ".to_string() + machinery_component.get_name() + " on.",
+                false => "You switch the ".to_string() + machinery_component.get_name() + " off.",
+            };
+
+            if machinery_component.enabled
+                && machinery_component.machinery_type.is_canister()
+                && machinery_component.contents <= 0.
+            {
+                text = text + " Nothing comes out, it is empty.";
+            }
+
+            match queued.handle_option {
+                Some(handle) => {
+                    net_atmos_machinery.send(NetAtmosMachinery {
+                        handle,
+                        message: machinery_notice(&text),
+                    });
+                }
+                None => {}
+            }
+        } else {
+            let handle;
+
+            match queued.handle_option {
+                Some(h) => {
+                    handle = h;
+                }
+                None => {
+                    continue;
+                }
+            }
+
+            let mut text_tree_selection_map = HashMap::new();
+            text_tree_selection_map.insert(
+                "main".to_string(),
+                TextTreeBit::Final(
+                    PRESSURE_OPTIONS
+                        .iter()
+                        .map(|pressure| pressure_label(*pressure))
+                        .collect(),
+                ),
+            );
+
+            net_atmos_machinery.send(NetAtmosMachinery {
+                handle,
+                message: ReliableServerMessage::TextTreeSelection(
+                    Some(machinery_entity.to_bits()),
+                    SET_PRESSURE_TAB_ACTION_ID.to_string(),
+                    SET_PRESSURE_MENU_ID.to_string(),
+                    "Target Pressure".to_string(),
+                    text_tree_selection_map,
+                ),
+            });
+        }
+    }
+}
+
+pub fn atmos_machinery_pressure_selection(
+    mut text_tree_input_selection_events: EventReader<TextTreeInputSelection>,
+    handle_to_entity: Res<HandleToEntity>,
+    transforms: Query<&Transform>,
+    mut machinery: Query<(&mut AtmosMachinery, &Transform)>,
+    mut net_atmos_machinery: EventWriter<NetAtmosMachinery>,
+) {
+    for event in text_tree_input_selection_events.iter() {
+        if event.menu_id != SET_PRESSURE_MENU_ID {
+            continue;
+        }
+
+        let machinery_entity;
+
+        match event.belonging_entity {
+            Some(bits) => {
+                machinery_entity = Entity::from_bits(bits);
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let user_transform;
+
+        match handle_to_entity.map.get(&event.handle) {
+            Some(entity) => match transforms.get(*entity) {
+                Ok(transform) => {
+                    user_transform = transform;
+                }
+                Err(_rr) => {
+                    continue;
+                }
+            },
+            None => {
+                continue;
+            }
+        }
+
+        let (mut machinery_component, machinery_transform) =
+            match machinery.get_mut(machinery_entity) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
+        if user_transform
+            .translation
+            .distance(machinery_transform.translation)
+            > REACH_DISTANCE
+        {
+            continue;
+        }
+
+        let mut selected_pressure_option = None;
+
+        for pressure in PRESSURE_OPTIONS {
+            if pressure_label(pressure) == event.menu_selection {
+                selected_pressure_option = Some(pressure);
+                break;
+            }
+        }
+
+        let selected_pressure;
+
+        match selected_pressure_option {
+            Some(pressure) => {
+                selected_pressure = pressure.clamp(MIN_TARGET_PRESSURE, MAX_TARGET_PRESSURE);
+            }
+            None => {
+                continue;
+            }
+        }
+
+        machinery_component.target_pressure = selected_pressure;
+
+        net_atmos_machinery.send(NetAtmosMachinery {
+            handle: event.handle,
+            message: machinery_notice(
+                &("You set the ".to_string()
+                    + machinery_component.get_name()
+                    + " to "
+                    + &pressure_label(selected_pressure)
+                    + "."),
+            ),
+        });
+    }
+}
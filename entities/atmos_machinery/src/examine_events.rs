@@ -0,0 +1,103 @@
+use atmospherics::diffusion::AtmosphericsResource;
+use bevy::prelude::{Entity, Query, Res, ResMut, Transform};
+use networking::messages::ExamineEntityMessages;
+use api::{
+    chat::{ATMOSPHERICS_TEXT_COLOR, FURTHER_ITALIC_FONT},
+    data::Vec2Int,
+    gridmap::world_to_cell_id,
+    senser::{Senser, SensingAbility},
+};
+
+use crate::machinery::{AtmosMachinery, MachineryType};
+
+pub fn examine_atmos_machinery(
+    mut examine_entity_events: ResMut<ExamineEntityMessages>,
+    criteria_query: Query<&Senser>,
+    machinery: Query<(&AtmosMachinery, &Transform)>,
+    atmospherics_resource: Res<AtmosphericsResource>,
+) {
+    for examine_event in examine_entity_events.messages.iter_mut() {
+        let examiner_senser_component;
+
+        match criteria_query.get(examine_event.entity) {
+            Ok(senser) => {
+                examiner_senser_component = senser;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let (machinery_component, transform) =
+            match machinery.get(Entity::from_bits(examine_event.examine_entity_bits)) {
+                Ok(components) => components,
+                Err(_rr) => {
+                    continue;
+                }
+            };
+
+        let state = match machinery_component.enabled {
+            true => "on",
+            false => "off",
+        };
+
+        let mut examine_text = "[font=".to_string()
+            + FURTHER_ITALIC_FONT
+            + "]"
+            + "\nIt is switched "
+            + state
+            + ", set to "
+            + &machinery_component.target_pressure.floor().to_string()
+            + " kpa.";
+
+        if machinery_component.machinery_type.is_canister() {
+            let gas = match machinery_component.machinery_type {
+                MachineryType::FuelCanister => "fuel",
+                _ => "air",
+            };
+            if machinery_component.contents <= 0. {
+                examine_text = examine_text + "\nIt is empty.";
+            } else {
+                examine_text = examine_text
+                    + "\nIt holds "
+                    + &machinery_component.contents.floor().to_string()
+                    + " mol of "
+                    + gas
+                    + ".";
+            }
+        }
+
+        examine_text = examine_text + "[/font]";
+
+        if examiner_senser_component
+            .sensing_abilities
+            .contains(&SensingAbility::AtmosphericsSensor)
+        {
+            let cell_id_3 = world_to_cell_id(transform.translation);
+            let cell_id = Vec2Int {
+                x: cell_id_3.x,
+                y: cell_id_3.z,
+            };
+
+            if !AtmosphericsResource::is_id_out_of_range(cell_id) {
+                let atmospherics = atmospherics_resource
+                    .atmospherics
+                    .get(atmospherics_resource.get_cell_atmos_index(cell_id_3))
+                    .unwrap();
+
+                examine_text = examine_text
+                    + "[font="
+                    + FURTHER_ITALIC_FONT
+                    + "][color="
+                    + ATMOSPHERICS_TEXT_COLOR
+                    + "]"
+                    + "\nSurrounding pressure: "
+                    + &atmospherics.get_pressure().floor().to_string()
+                    + " kpa"
+                    + "[/color][/font]";
+            }
+        }
+
+        examine_event.message = examine_event.message.clone() + &examine_text + "\n";
+    }
+}
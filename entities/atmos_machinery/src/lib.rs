@@ -0,0 +1,6 @@
+pub mod actions;
+pub mod examine_events;
+pub mod machinery;
+pub mod net;
+pub mod plugin;
+pub mod spawn;
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use atmospherics::{diffusion::AtmosphericsResource, effects::ATMOS_EFFECT_SPEED};
+use bevy::prelude::{Component, Entity, EventReader, Query, RemovedComponents, ResMut, Transform};
+use api::{
+    atmospherics::{get_amount_for_pressure, AtmosEffect, EffectType, CELCIUS_KELVIN_OFFSET},
+    data::Vec2Int,
+    gridmap::world_to_cell_id,
+    rounds::ResetWorld,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MachineryType {
+    GasCanister,
+    FuelCanister,
+    AirVent,
+    AirScrubber,
+}
+
+impl MachineryType {
+    // Canisters carry their gas with them and run dry.
+    pub fn is_canister(&self) -> bool {
+        matches!(
+            self,
+            MachineryType::GasCanister | MachineryType::FuelCanister
+        )
+    }
+}
+
+#[derive(Component)]
+pub struct AtmosMachinery {
+    pub machinery_type: MachineryType,
+    pub enabled: bool,
+    // Kpa the machinery pumps its cell towards.
+    pub target_pressure: f32,
+    // Mol of air left inside, only canisters run dry.
+    pub contents: f32,
+}
+
+pub const DEFAULT_TARGET_PRESSURE: f32 = 101.325;
+// Fuel canisters push into rooms that are already at standard pressure.
+pub const FUEL_CANISTER_TARGET_PRESSURE: f32 = 120.;
+pub const CANISTER_CONTENTS: f32 = 2500.;
+// Scrubbers can't pump below this, an AtmosEffect remover with a target of 0 mol removes nothing.
+pub const MIN_TARGET_PRESSURE: f32 = 1.;
+pub const MAX_TARGET_PRESSURE: f32 = 200.;
+
+// Mol moved per diffusion step.
+const CANISTER_RATE: f32 = 0.8;
+const VENT_RATE: f32 = 0.4;
+const SCRUBBER_RATE: f32 = 0.4;
+
+impl AtmosMachinery {
+    pub fn new(machinery_type: MachineryType) -> Self {
+        Self {
+            machinery_type,
+            enabled: false,
+            target_pressure: match machinery_type {
+                MachineryType::FuelCanister => FUEL_CANISTER_TARGET_PRESSURE,
+                _ => DEFAULT_TARGET_PRESSURE,
+            },
+            contents: match machinery_type.is_canister() {
+                true => CANISTER_CONTENTS,
+                false => 0.,
+            },
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.machinery_type {
+            MachineryType::GasCanister => "gas canister",
+            MachineryType::FuelCanister => "fuel canister",
+            MachineryType::AirVent => "air vent",
+            MachineryType::AirScrubber => "air scrubber",
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.enabled && (!self.machinery_type.is_canister() || self.contents > 0.)
+    }
+
+    fn get_rate(&self) -> f32 {
+        match self.machinery_type {
+            MachineryType::GasCanister | MachineryType::FuelCanister => CANISTER_RATE,
+            MachineryType::AirVent => VENT_RATE,
+            MachineryType::AirScrubber => SCRUBBER_RATE,
+        }
+    }
+
+    // atmos_effects moves target_amount * amount_speed per step, the speed is scaled so the rate stays the same for any target.
+    pub fn get_atmos_effect(&self) -> AtmosEffect {
+        let target_amount = get_amount_for_pressure(
+            self.target_pressure.max(MIN_TARGET_PRESSURE),
+            20. + CELCIUS_KELVIN_OFFSET,
+        );
+
+        AtmosEffect {
+            target_temperature: 20. + CELCIUS_KELVIN_OFFSET,
+            temperature_speed: 0.,
+            heater: false,
+
+            target_amount,
+            amount_speed: self.get_rate() / (target_amount * ATMOS_EFFECT_SPEED),
+            remover: self.machinery_type == MachineryType::AirScrubber,
+            fuel_ratio: match self.machinery_type {
+                MachineryType::FuelCanister => 1.,
+                _ => 0.,
+            },
+        }
+    }
+}
+
+// The atmospherics index each machinery has its AtmosEffect registered on.
+#[derive(Default)]
+pub struct AtmosMachineryCells {
+    pub cells: HashMap<Entity, usize>,
+}
+
+fn remove_machinery_effect(
+    atmospherics_resource: &mut AtmosphericsResource,
+    entity: Entity,
+    atmos_index: usize,
+) {
+    atmospherics_resource
+        .atmospherics
+        .get_mut(atmos_index)
+        .unwrap()
+        .effects
+        .remove(&EffectType::Entity(entity));
+}
+
+pub fn atmos_machinery_effects(
+    mut machinery: Query<(Entity, &mut AtmosMachinery, &Transform)>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    mut machinery_cells: ResMut<AtmosMachineryCells>,
+) {
+    for (entity, mut machinery_component, transform) in machinery.iter_mut() {
+        let cell_id_3 = world_to_cell_id(transform.translation);
+        let cell_id = Vec2Int {
+            x: cell_id_3.x,
+            y: cell_id_3.z,
+        };
+
+        let active =
+            machinery_component.is_active() && !AtmosphericsResource::is_id_out_of_range(cell_id);
+        let atmos_index = atmospherics_resource.get_cell_atmos_index(cell_id_3);
+
+        // Portable machinery takes its effect along when it is moved.
+        match machinery_cells.cells.get(&entity) {
+            Some(registered_atmos_index) => {
+                if *registered_atmos_index != atmos_index || !active {
+                    remove_machinery_effect(
+                        &mut atmospherics_resource,
+                        entity,
+                        *registered_atmos_index,
+                    );
+                    machinery_cells.cells.remove(&entity);
+                }
+            }
+            None => {}
+        }
+
+        if !active {
+            continue;
+        }
+
+        let atmos_effect = machinery_component.get_atmos_effect();
+
+        let atmospherics = atmospherics_resource
+            .atmospherics
+            .get_mut(atmos_index)
+            .unwrap();
+
+        if machinery_component.machinery_type.is_canister()
+            && !atmospherics.blocked
+            && atmospherics.amount < atmos_effect.target_amount
+        {
+            machinery_component.contents =
+                (machinery_component.contents - machinery_component.get_rate()).max(0.);
+        }
+
+        atmospherics
+            .effects
+            .insert(EffectType::Entity(entity), atmos_effect);
+        machinery_cells.cells.insert(entity, atmos_index);
+    }
+}
+
+pub fn atmos_machinery_removed(
+    removed_machinery: RemovedComponents<AtmosMachinery>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+    mut machinery_cells: ResMut<AtmosMachineryCells>,
+) {
+    for entity in removed_machinery.iter() {
+        match machinery_cells.cells.remove(&entity) {
+            Some(atmos_index) => {
+                remove_machinery_effect(&mut atmospherics_resource, entity, atmos_index);
+            }
+            None => {}
+        }
+    }
+}
+
+// The atmospherics get reset along with the world, so there are no effects left to remove
+// once the despawned machinery shows up in atmos_machinery_removed.
+pub fn reset_atmos_machinery_cells(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut machinery_cells: ResMut<AtmosMachineryCells>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    machinery_cells.cells.clear();
+}
@@ -0,0 +1,14 @@
+use api::network::{PendingMessage, PendingNetworkMessage, ReliableServerMessage};
+
+pub struct NetAtmosMachinery {
+    pub handle: u64,
+    pub message: ReliableServerMessage,
+}
+impl PendingMessage for NetAtmosMachinery {
+    fn get_message(&self) -> PendingNetworkMessage {
+        PendingNetworkMessage {
+            handle: self.handle,
+            message: self.message.clone(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use atmospherics::{diffusion::DIFFUSION_STEP, plugin::AtmosphericsLabels};
+use bevy::{
+    core::FixedTimestep,
+    prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut, SystemSet},
+};
+use entity::{
+    entity_data::initialize_entity_data,
+    spawn::{summon_base_entity, SpawnEvent},
+};
+use networking::messages::net_system;
+use rigid_body::spawn::summon_rigid_body;
+use api::{
+    data::{
+        EntityDataProperties, EntityDataResource, PostUpdateLabels, StartupLabels, SummoningLabels,
+    },
+    examinable::ExamineLabels,
+    rounds::RoundsLabels,
+    tab_actions::TabActionsQueueLabels,
+};
+
+use crate::{
+    actions::{atmos_machinery_actions, atmos_machinery_pressure_selection},
+    examine_events::examine_atmos_machinery,
+    machinery::{
+        atmos_machinery_effects, atmos_machinery_removed, reset_atmos_machinery_cells,
+        AtmosMachineryCells,
+    },
+    net::NetAtmosMachinery,
+    spawn::{
+        default_summon_atmos_machinery, summon_atmos_machinery, summon_raw_atmos_machinery,
+        AtmosMachinerySummoner, AIR_SCRUBBER_ENTITY_NAME, AIR_VENT_ENTITY_NAME,
+        FUEL_CANISTER_ENTITY_NAME, GAS_CANISTER_ENTITY_NAME,
+    },
+};
+
+use bevy::app::CoreStage::PostUpdate;
+
+pub struct AtmosMachineryPlugin;
+
+impl Plugin for AtmosMachineryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NetAtmosMachinery>()
+            .init_resource::<AtmosMachineryCells>()
+            .add_system_set(
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(1. / DIFFUSION_STEP))
+                    .with_system(atmos_machinery_effects.before(AtmosphericsLabels::Effects)),
+            )
+            .add_system_to_stage(PostUpdate, atmos_machinery_removed)
+            .add_system(
+                reset_atmos_machinery_cells
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
+            .add_system(atmos_machinery_actions.after(TabActionsQueueLabels::TabAction))
+            .add_system(atmos_machinery_pressure_selection)
+            .add_system(examine_atmos_machinery.after(ExamineLabels::Default))
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetAtmosMachinery>),
+            )
+            .add_event::<SpawnEvent<AtmosMachinerySummoner>>()
+            .add_startup_system(content_initialization.before(StartupLabels::BuildGridmap))
+            .add_system(summon_atmos_machinery.after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (summon_base_entity::<AtmosMachinerySummoner>)
+                    .after(SummoningLabels::TriggerSummon),
+            )
+            .add_system(
+                (summon_rigid_body::<AtmosMachinerySummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system((summon_raw_atmos_machinery).after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (default_summon_atmos_machinery)
+                    .label(SummoningLabels::DefaultSummon)
+                    .after(SummoningLabels::NormalSummon),
+            );
+    }
+}
+
+pub fn content_initialization(mut entity_data: ResMut<EntityDataResource>) {
+    for name in [
+        GAS_CANISTER_ENTITY_NAME,
+        FUEL_CANISTER_ENTITY_NAME,
+        AIR_VENT_ENTITY_NAME,
+        AIR_SCRUBBER_ENTITY_NAME,
+    ] {
+        let entity_properties = EntityDataProperties {
+            name: name.to_string(),
+            id: entity_data.get_id_inc(),
+            ..Default::default()
+        };
+        initialize_entity_data(&mut entity_data, entity_properties);
+    }
+}
@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use bevy::{
+    math::Vec3,
+    prelude::{Commands, EventReader, EventWriter, Transform},
+};
+use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Friction};
+use entity::{
+    entity_data::RawSpawnEvent,
+    spawn::{BaseEntityBundle, BaseEntitySummonable, DefaultSpawnEvent, SpawnData, SpawnEvent},
+};
+use rigid_body::{
+    rigid_body::STANDARD_BODY_FRICTION,
+    spawn::{RigidBodyBundle, RigidBodySummonable},
+};
+use api::{
+    converters::string_transform_to_transform,
+    data::NoData,
+    examinable::{Examinable, RichName},
+    health::Health,
+    tab_actions::{TabAction, TabActions},
+};
+
+use crate::{
+    actions::{atmos_machinery_action, SET_PRESSURE_TAB_ACTION_ID, TOGGLE_TAB_ACTION_ID},
+    machinery::{AtmosMachinery, MachineryType},
+};
+
+pub const GAS_CANISTER_ENTITY_NAME: &str = "gasCanister";
+pub const FUEL_CANISTER_ENTITY_NAME: &str = "fuelCanister";
+pub const AIR_VENT_ENTITY_NAME: &str = "airVent";
+pub const AIR_SCRUBBER_ENTITY_NAME: &str = "airScrubber";
+
+pub fn get_machinery_type(entity_name: &str) -> Option<MachineryType> {
+    match entity_name {
+        GAS_CANISTER_ENTITY_NAME => Some(MachineryType::GasCanister),
+        FUEL_CANISTER_ENTITY_NAME => Some(MachineryType::FuelCanister),
+        AIR_VENT_ENTITY_NAME => Some(MachineryType::AirVent),
+        AIR_SCRUBBER_ENTITY_NAME => Some(MachineryType::AirScrubber),
+        _ => None,
+    }
+}
+
+fn get_entity_name(machinery_type: MachineryType) -> &'static str {
+    match machinery_type {
+        MachineryType::GasCanister => GAS_CANISTER_ENTITY_NAME,
+        MachineryType::FuelCanister => FUEL_CANISTER_ENTITY_NAME,
+        MachineryType::AirVent => AIR_VENT_ENTITY_NAME,
+        MachineryType::AirScrubber => AIR_SCRUBBER_ENTITY_NAME,
+    }
+}
+
+pub fn get_default_transform() -> Transform {
+    Transform::identity()
+}
+
+pub struct AtmosMachinerySummoner {
+    pub machinery_type: MachineryType,
+}
+
+impl BaseEntitySummonable<NoData> for AtmosMachinerySummoner {
+    fn get_bundle(&self, spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
+        let template_examine_text = match self.machinery_type {
+            MachineryType::GasCanister => {
+                "A portable canister filled with breathable air. It can be opened to refill a room."
+            }
+            MachineryType::FuelCanister => {
+                "A portable canister filled with fuel gas. Keep it away from open flames."
+            }
+            MachineryType::AirVent => "A vent that pumps air into the room it is in.",
+            MachineryType::AirScrubber => "A scrubber that pulls air out of the room it is in.",
+        }
+        .to_string();
+        let mut examine_map = BTreeMap::new();
+        examine_map.insert(0, template_examine_text);
+
+        let tab_actions_option = Some(TabActions {
+            tab_actions: vec![
+                TabAction {
+                    id: TOGGLE_TAB_ACTION_ID.to_string(),
+                    text: "Toggle".to_string(),
+                    tab_list_priority: 100,
+                    prerequisite_check: Arc::new(atmos_machinery_action),
+                    belonging_entity: Some(spawn_data.entity),
+                },
+                TabAction {
+                    id: SET_PRESSURE_TAB_ACTION_ID.to_string(),
+                    text: "Set Pressure".to_string(),
+                    tab_list_priority: 99,
+                    prerequisite_check: Arc::new(atmos_machinery_action),
+                    belonging_entity: Some(spawn_data.entity),
+                },
+            ],
+        });
+
+        BaseEntityBundle {
+            default_transform: get_default_transform(),
+            examinable: Examinable {
+                assigned_texts: examine_map,
+                name: RichName {
+                    name: AtmosMachinery::new(self.machinery_type)
+                        .get_name()
+                        .to_string(),
+                    n: self.machinery_type == MachineryType::AirVent
+                        || self.machinery_type == MachineryType::AirScrubber,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            entity_name: get_entity_name(self.machinery_type).to_string(),
+            tab_actions_option,
+            health: Health {
+                is_combat_obstacle: self.machinery_type.is_canister(),
+                is_reach_obstacle: self.machinery_type.is_canister(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl RigidBodySummonable<NoData> for AtmosMachinerySummoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> RigidBodyBundle {
+        let mut friction = Friction::coefficient(STANDARD_BODY_FRICTION);
+        friction.combine_rule = CoefficientCombineRule::Min;
+
+        // Canisters can be pushed around, vents and scrubbers are mounted flat on the floor.
+        match self.machinery_type {
+            MachineryType::GasCanister | MachineryType::FuelCanister => RigidBodyBundle {
+                collider: Collider::cuboid(0.3, 0.6, 0.3),
+                collider_transform: Transform::from_translation(Vec3::new(0., 0.6, 0.)),
+                collider_friction: friction,
+                rigidbody_dynamic: true,
+                collision_events: true,
+            },
+            _ => RigidBodyBundle {
+                collider: Collider::cuboid(0.4, 0.05, 0.4),
+                collider_transform: Transform::from_translation(Vec3::new(0., 0.05, 0.)),
+                collider_friction: friction,
+                rigidbody_dynamic: false,
+                collision_events: true,
+            },
+        }
+    }
+}
+
+pub fn summon_atmos_machinery(
+    mut commands: Commands,
+    mut spawn_events: EventReader<SpawnEvent<AtmosMachinerySummoner>>,
+) {
+    for spawn_event in spawn_events.iter() {
+        commands
+            .entity(spawn_event.spawn_data.entity)
+            .insert(AtmosMachinery::new(spawn_event.summoner.machinery_type));
+    }
+}
+
+pub fn summon_raw_atmos_machinery(
+    mut spawn_events: EventReader<RawSpawnEvent>,
+    mut summon_atmos_machinery: EventWriter<SpawnEvent<AtmosMachinerySummoner>>,
+    mut commands: Commands,
+) {
+    for spawn_event in spawn_events.iter() {
+        let machinery_type;
+
+        match get_machinery_type(&spawn_event.raw_entity.entity_type) {
+            Some(t) => {
+                machinery_type = t;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let entity_transform = string_transform_to_transform(&spawn_event.raw_entity.transform);
+
+        summon_atmos_machinery.send(SpawnEvent {
+            spawn_data: SpawnData {
+                entity_transform: entity_transform,
+                default_map_spawn: true,
+                entity_name: spawn_event.raw_entity.entity_type.clone(),
+                entity: commands.spawn().id(),
+                raw_entity_option: Some(spawn_event.raw_entity.clone()),
+                ..Default::default()
+            },
+            summoner: AtmosMachinerySummoner { machinery_type },
+        });
+    }
+}
+
+pub fn default_summon_atmos_machinery(
+    mut default_spawner: EventReader<DefaultSpawnEvent>,
+    mut spawner: EventWriter<SpawnEvent<AtmosMachinerySummoner>>,
+) {
+    for spawn_event in default_spawner.iter() {
+        let machinery_type;
+
+        match get_machinery_type(&spawn_event.spawn_data.entity_name) {
+            Some(t) => {
+                machinery_type = t;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        spawner.send(SpawnEvent {
+            spawn_data: spawn_event.spawn_data.clone(),
+            summoner: AtmosMachinerySummoner { machinery_type },
+        });
+    }
+}
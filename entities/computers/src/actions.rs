@@ -0,0 +1,45 @@
+use bevy::prelude::{Entity, EventWriter, Query, Res};
+use api::{
+    data::EntityDataResource,
+    data_link::{DataLink, DataLinkType},
+    entity_updates::EntityData,
+    gridmap::{CellData, GridMapType},
+    inventory::Inventory,
+    pawn::REACH_DISTANCE,
+    tab_actions::QueuedTabActions,
+};
+
+use super::remote_doors::InputRemoteDoors;
+
+pub fn remote_doors_action(
+    _self_tab_entity: Option<Entity>,
+    _entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    _inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    data_link_component: &DataLink,
+) -> bool {
+    distance < REACH_DISTANCE
+        && data_link_component
+            .links
+            .contains(&DataLinkType::RemoteLock)
+}
+
+pub fn computers_actions(
+    queue: Res<QueuedTabActions>,
+    mut input_remote_doors_event: EventWriter<InputRemoteDoors>,
+) {
+    for queued in queue.queue.iter() {
+        if queued.tab_id == "actions::computers/remotedoors" {
+            if queued.target_entity_option.is_some() {
+                input_remote_doors_event.send(InputRemoteDoors {
+                    handle_option: queued.handle_option,
+                    user: queued.player_entity,
+                    computer: Entity::from_bits(queued.target_entity_option.unwrap()),
+                });
+            }
+        }
+    }
+}
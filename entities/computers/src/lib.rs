@@ -1,3 +1,6 @@
+pub mod actions;
 pub mod computer;
+pub mod net;
 pub mod plugin;
+pub mod remote_doors;
 pub mod spawn;
@@ -1,24 +1,57 @@
-use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut};
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut, SystemSet};
 use entity::{
     entity_data::initialize_entity_data,
     spawn::{summon_base_entity, SpawnEvent},
 };
+use networking::messages::net_system;
 use rigid_body::spawn::summon_rigid_body;
-use api::data::{EntityDataProperties, EntityDataResource, StartupLabels, SummoningLabels};
+use api::{
+    data::{
+        EntityDataProperties, EntityDataResource, PostUpdateLabels, StartupLabels, SummoningLabels,
+    },
+    rounds::RoundsLabels,
+    tab_actions::TabActionsQueueLabels,
+};
 
 use super::{
+    actions::computers_actions,
     computer::computer_added,
+    net::NetComputer,
+    remote_doors::{
+        end_remote_doors_sessions, remote_doors, reset_remote_doors_sessions, InputRemoteDoors,
+        RemoteDoorsLabels, RemoteDoorsSessions,
+    },
     spawn::{
         default_summon_computer, summon_computer, summon_raw_computer, ComputerSummoner,
         BRIDGE_COMPUTER_ENTITY_NAME,
     },
 };
 
+use bevy::app::CoreStage::PostUpdate;
+
 pub struct ComputersPlugin;
 
 impl Plugin for ComputersPlugin {
     fn build(&self, app: &mut App) {
         app.add_system(computer_added)
+            .add_event::<InputRemoteDoors>()
+            .add_event::<NetComputer>()
+            .init_resource::<RemoteDoorsSessions>()
+            .add_system(computers_actions.after(TabActionsQueueLabels::TabAction))
+            .add_system(end_remote_doors_sessions.label(RemoteDoorsLabels::EndSessions))
+            .add_system(remote_doors.after(RemoteDoorsLabels::EndSessions))
+            .add_system(
+                reset_remote_doors_sessions
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetComputer>),
+            )
             .add_event::<SpawnEvent<ComputerSummoner>>()
             .add_startup_system(content_initialization.before(StartupLabels::BuildGridmap))
             .add_system(summon_computer::<ComputerSummoner>.after(SummoningLabels::TriggerSummon))
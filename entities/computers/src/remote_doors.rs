@@ -0,0 +1,496 @@
+use std::collections::HashMap;
+
+use air_locks::{
+    air_lock::{AirLock, AirLockStatus},
+    air_lock_added::{AirLockLockClosed, AirLockLockOpen, AirLockUnlock, InputAirLockToggleOpen},
+};
+use bevy::prelude::{
+    Entity, EventReader, EventWriter, Query, Res, ResMut, SystemLabel, Transform, With,
+};
+use counter_windows::counter_window_events::{
+    CounterWindow, CounterWindowLockClosed, CounterWindowLockOpen, CounterWindowStatus,
+    CounterWindowUnlock, InputCounterWindowToggleOpen,
+};
+use networking::messages::TextTreeInputSelection;
+use pawn::pawn::{get_id_card_access, ShipAuthorization, ShipAuthorizationEnum};
+use api::{
+    chat::{FURTHER_ITALIC_FONT, WARNING_COLOR},
+    data::HandleToEntity,
+    examinable::Examinable,
+    inventory::Inventory,
+    network::{ReliableServerMessage, TextTreeBit},
+    pawn::REACH_DISTANCE,
+    rounds::ResetWorld,
+};
+
+use super::{computer::Computer, net::NetComputer};
+
+pub const REMOTE_DOORS_COMPUTER_TYPE: &str = "remoteDoors";
+// Doors further away from the computer than this are not listed.
+pub const REMOTE_DOORS_RANGE: f32 = 40.;
+
+const REMOTE_DOORS_TAB_ACTION_ID: &str = "actions::computers/remotedoors";
+const REMOTE_DOORS_LIST_MENU_ID: &str = "textselection::computers/remotedoorslist";
+const REMOTE_DOORS_COMMANDS_MENU_ID: &str = "textselection::computers/remotedoorcommands";
+
+const COMMAND_OPEN: &str = "Open";
+const COMMAND_CLOSE: &str = "Close";
+const COMMAND_LOCK_OPEN: &str = "Lock Open";
+const COMMAND_LOCK_CLOSED: &str = "Lock Closed";
+const COMMAND_UNLOCK: &str = "Unlock";
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum RemoteDoorsLabels {
+    EndSessions,
+}
+
+pub struct InputRemoteDoors {
+    pub handle_option: Option<u64>,
+    pub user: Entity,
+    pub computer: Entity,
+}
+
+#[derive(Clone, Copy)]
+pub enum RemoteDoor {
+    AirLock(Entity),
+    CounterWindow(Entity),
+}
+
+pub struct RemoteDoorsSession {
+    pub user: Entity,
+    pub computer: Entity,
+    pub doors: Vec<(String, RemoteDoor)>,
+    pub selected_door_option: Option<RemoteDoor>,
+}
+
+// Keyed by connection handle, a player only ever has one remote doors menu open.
+#[derive(Default)]
+pub struct RemoteDoorsSessions {
+    pub sessions: HashMap<u64, RemoteDoorsSession>,
+}
+
+fn has_door_access(
+    access_permissions: &Vec<ShipAuthorizationEnum>,
+    id_card_access: &Vec<ShipAuthorizationEnum>,
+) -> bool {
+    for permission in access_permissions.iter() {
+        if id_card_access.contains(permission) {
+            return true;
+        }
+    }
+    false
+}
+
+fn remote_doors_notice(text: &str) -> ReliableServerMessage {
+    ReliableServerMessage::ChatMessage(
+        "[font=".to_string() + FURTHER_ITALIC_FONT + "]" + text + "[/font]",
+    )
+}
+
+pub fn remote_doors(
+    mut input_remote_doors_events: EventReader<InputRemoteDoors>,
+    mut text_tree_input_selection_events: EventReader<TextTreeInputSelection>,
+    air_lock_events: (
+        EventWriter<InputAirLockToggleOpen>,
+        EventWriter<AirLockLockOpen>,
+        EventWriter<AirLockLockClosed>,
+        EventWriter<AirLockUnlock>,
+    ),
+    counter_window_events: (
+        EventWriter<InputCounterWindowToggleOpen>,
+        EventWriter<CounterWindowLockOpen>,
+        EventWriter<CounterWindowLockClosed>,
+        EventWriter<CounterWindowUnlock>,
+    ),
+    computers: Query<(&Computer, &Transform)>,
+    air_locks: Query<(Entity, &AirLock, &Transform, &Examinable)>,
+    counter_windows: Query<(Entity, &CounterWindow, &Transform, &Examinable)>,
+    users: Query<&Inventory>,
+    id_cards: Query<&ShipAuthorization>,
+    mut remote_doors_sessions: ResMut<RemoteDoorsSessions>,
+    mut net_computer: EventWriter<NetComputer>,
+) {
+    let (
+        mut air_lock_toggle_open_event,
+        mut air_lock_lock_open_event,
+        mut air_lock_lock_closed_event,
+        mut air_lock_unlock_event,
+    ) = air_lock_events;
+    let (
+        mut counter_window_toggle_open_event,
+        mut counter_window_lock_open_event,
+        mut counter_window_lock_closed_event,
+        mut counter_window_unlock_event,
+    ) = counter_window_events;
+
+    for event in input_remote_doors_events.iter() {
+        let handle;
+
+        match event.handle_option {
+            Some(h) => {
+                handle = h;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let computer_transform;
+
+        match computers.get(event.computer) {
+            Ok((computer_component, transform)) => {
+                if computer_component.computer_type != REMOTE_DOORS_COMPUTER_TYPE {
+                    continue;
+                }
+                computer_transform = transform;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let mut doors_in_range = vec![];
+
+        for (entity, _air_lock_component, transform, examinable_component) in air_locks.iter() {
+            let distance = transform
+                .translation
+                .distance(computer_transform.translation);
+            if distance < REMOTE_DOORS_RANGE {
+                doors_in_range.push((
+                    distance,
+                    examinable_component.name.get_name().to_string(),
+                    RemoteDoor::AirLock(entity),
+                ));
+            }
+        }
+        for (entity, _counter_window_component, transform, examinable_component) in
+            counter_windows.iter()
+        {
+            let distance = transform
+                .translation
+                .distance(computer_transform.translation);
+            if distance < REMOTE_DOORS_RANGE {
+                doors_in_range.push((
+                    distance,
+                    examinable_component.name.get_name().to_string(),
+                    RemoteDoor::CounterWindow(entity),
+                ));
+            }
+        }
+
+        if doors_in_range.is_empty() {
+            net_computer.send(NetComputer {
+                handle,
+                message: remote_doors_notice("There are no doors in range of this computer."),
+            });
+            continue;
+        }
+
+        doors_in_range.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let mut doors = vec![];
+        let mut text_options = vec![];
+
+        // Numbered so identically named doors stay distinguishable.
+        for (i, (distance, name, door)) in doors_in_range.into_iter().enumerate() {
+            let label =
+                (i + 1).to_string() + ". " + &name + " (" + &(distance as u32).to_string() + "m)";
+            text_options.push(label.clone());
+            doors.push((label, door));
+        }
+
+        remote_doors_sessions.sessions.insert(
+            handle,
+            RemoteDoorsSession {
+                user: event.user,
+                computer: event.computer,
+                doors,
+                selected_door_option: None,
+            },
+        );
+
+        let mut text_tree_selection_map = HashMap::new();
+        text_tree_selection_map.insert("main".to_string(), TextTreeBit::Final(text_options));
+
+        net_computer.send(NetComputer {
+            handle,
+            message: ReliableServerMessage::TextTreeSelection(
+                Some(event.computer.to_bits()),
+                REMOTE_DOORS_TAB_ACTION_ID.to_string(),
+                REMOTE_DOORS_LIST_MENU_ID.to_string(),
+                "Remote Doors".to_string(),
+                text_tree_selection_map,
+            ),
+        });
+    }
+
+    for event in text_tree_input_selection_events.iter() {
+        if event.menu_id != REMOTE_DOORS_LIST_MENU_ID
+            && event.menu_id != REMOTE_DOORS_COMMANDS_MENU_ID
+        {
+            continue;
+        }
+
+        let session;
+
+        match remote_doors_sessions.sessions.get_mut(&event.handle) {
+            Some(s) => {
+                session = s;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        if event.belonging_entity != Some(session.computer.to_bits()) {
+            continue;
+        }
+
+        let user_entity = session.user;
+        let user_inventory;
+
+        match users.get(user_entity) {
+            Ok(inventory) => {
+                user_inventory = inventory;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        if event.menu_id == REMOTE_DOORS_LIST_MENU_ID {
+            let mut selected_door_option = None;
+
+            for (label, door) in session.doors.iter() {
+                if label == &event.menu_selection {
+                    selected_door_option = Some(*door);
+                    break;
+                }
+            }
+
+            if selected_door_option.is_none() {
+                continue;
+            }
+
+            session.selected_door_option = selected_door_option;
+
+            let mut text_tree_selection_map = HashMap::new();
+            text_tree_selection_map.insert(
+                "main".to_string(),
+                TextTreeBit::Final(vec![
+                    COMMAND_OPEN.to_string(),
+                    COMMAND_CLOSE.to_string(),
+                    COMMAND_LOCK_OPEN.to_string(),
+                    COMMAND_LOCK_CLOSED.to_string(),
+                    COMMAND_UNLOCK.to_string(),
+                ]),
+            );
+
+            net_computer.send(NetComputer {
+                handle: event.handle,
+                message: ReliableServerMessage::TextTreeSelection(
+                    Some(session.computer.to_bits()),
+                    REMOTE_DOORS_TAB_ACTION_ID.to_string(),
+                    REMOTE_DOORS_COMMANDS_MENU_ID.to_string(),
+                    event.menu_selection.clone(),
+                    text_tree_selection_map,
+                ),
+            });
+            continue;
+        }
+
+        let selected_door;
+
+        match session.selected_door_option {
+            Some(door) => {
+                selected_door = door;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let id_card_access = get_id_card_access(user_inventory, &id_cards);
+        let command = event.menu_selection.as_str();
+
+        match selected_door {
+            RemoteDoor::AirLock(door_entity) => {
+                let air_lock_component;
+
+                match air_locks.get(door_entity) {
+                    Ok((_entity, component, _transform, _examinable)) => {
+                        air_lock_component = component;
+                    }
+                    Err(_rr) => {
+                        continue;
+                    }
+                }
+
+                if !has_door_access(&air_lock_component.access_permissions, &id_card_access) {
+                    net_computer.send(NetComputer {
+                        handle: event.handle,
+                        message: ReliableServerMessage::ChatMessage(
+                            "[font=".to_string()
+                                + FURTHER_ITALIC_FONT
+                                + "][color="
+                                + WARNING_COLOR
+                                + "]Access denied.[/color][/font]",
+                        ),
+                    });
+                    continue;
+                }
+
+                if command == COMMAND_OPEN || command == COMMAND_CLOSE {
+                    let is_open = matches!(air_lock_component.status, AirLockStatus::Open);
+                    if is_open == (command == COMMAND_CLOSE) {
+                        air_lock_toggle_open_event.send(InputAirLockToggleOpen {
+                            handle_option: Some(event.handle),
+                            opener: user_entity,
+                            opened: door_entity.to_bits(),
+                        });
+                    }
+                } else if command == COMMAND_LOCK_OPEN {
+                    air_lock_lock_open_event.send(AirLockLockOpen {
+                        handle_option: Some(event.handle),
+                        locked: door_entity,
+                        locker: user_entity,
+                    });
+                } else if command == COMMAND_LOCK_CLOSED {
+                    air_lock_lock_closed_event.send(AirLockLockClosed {
+                        handle_option: Some(event.handle),
+                        locked: door_entity,
+                        locker: user_entity,
+                    });
+                } else if command == COMMAND_UNLOCK {
+                    air_lock_unlock_event.send(AirLockUnlock {
+                        handle_option: Some(event.handle),
+                        locked: door_entity,
+                        locker: user_entity,
+                    });
+                }
+            }
+            RemoteDoor::CounterWindow(door_entity) => {
+                let counter_window_component;
+
+                match counter_windows.get(door_entity) {
+                    Ok((_entity, component, _transform, _examinable)) => {
+                        counter_window_component = component;
+                    }
+                    Err(_rr) => {
+                        continue;
+                    }
+                }
+
+                if !has_door_access(
+                    &counter_window_component.access_permissions,
+                    &id_card_access,
+                ) {
+                    net_computer.send(NetComputer {
+                        handle: event.handle,
+                        message: ReliableServerMessage::ChatMessage(
+                            "[font=".to_string()
+                                + FURTHER_ITALIC_FONT
+                                + "][color="
+                                + WARNING_COLOR
+                                + "]Access denied.[/color][/font]",
+                        ),
+                    });
+                    continue;
+                }
+
+                if command == COMMAND_OPEN || command == COMMAND_CLOSE {
+                    let is_open =
+                        matches!(counter_window_component.status, CounterWindowStatus::Open);
+                    if is_open == (command == COMMAND_CLOSE) {
+                        counter_window_toggle_open_event.send(InputCounterWindowToggleOpen {
+                            handle_option: Some(event.handle),
+                            opener: user_entity,
+                            opened: door_entity.to_bits(),
+                        });
+                    }
+                } else if command == COMMAND_LOCK_OPEN {
+                    counter_window_lock_open_event.send(CounterWindowLockOpen {
+                        handle_option: Some(event.handle),
+                        locked: door_entity,
+                        locker: user_entity,
+                    });
+                } else if command == COMMAND_LOCK_CLOSED {
+                    counter_window_lock_closed_event.send(CounterWindowLockClosed {
+                        handle_option: Some(event.handle),
+                        locked: door_entity,
+                        locker: user_entity,
+                    });
+                } else if command == COMMAND_UNLOCK {
+                    counter_window_unlock_event.send(CounterWindowUnlock {
+                        handle_option: Some(event.handle),
+                        locked: door_entity,
+                        locker: user_entity,
+                    });
+                }
+            }
+        }
+    }
+}
+
+// Sessions end once their player disconnects, gets a new pawn or walks away from the computer.
+pub fn end_remote_doors_sessions(
+    mut remote_doors_sessions: ResMut<RemoteDoorsSessions>,
+    handle_to_entity: Res<HandleToEntity>,
+    computers: Query<&Transform, With<Computer>>,
+    users: Query<&Transform>,
+    mut net_computer: EventWriter<NetComputer>,
+) {
+    let mut ended = vec![];
+
+    for (handle, session) in remote_doors_sessions.sessions.iter() {
+        match handle_to_entity.map.get(handle) {
+            Some(entity) => {
+                if *entity != session.user {
+                    ended.push((*handle, false));
+                    continue;
+                }
+            }
+            None => {
+                ended.push((*handle, false));
+                continue;
+            }
+        }
+
+        match (users.get(session.user), computers.get(session.computer)) {
+            (Ok(user_transform), Ok(computer_transform)) => {
+                if user_transform
+                    .translation
+                    .distance(computer_transform.translation)
+                    > REACH_DISTANCE
+                {
+                    ended.push((*handle, true));
+                }
+            }
+            _ => {
+                ended.push((*handle, true));
+            }
+        }
+    }
+
+    for (handle, notify) in ended {
+        remote_doors_sessions.sessions.remove(&handle);
+
+        if notify {
+            net_computer.send(NetComputer {
+                handle,
+                message: remote_doors_notice("You are no longer using the remote doors computer."),
+            });
+        }
+    }
+}
+
+pub fn reset_remote_doors_sessions(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut remote_doors_sessions: ResMut<RemoteDoorsSessions>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    remote_doors_sessions.sessions.clear();
+}
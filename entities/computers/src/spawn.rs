@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 pub fn get_default_transform() -> Transform {
     Transform::from_matrix(Mat4::from_scale_rotation_translation(
@@ -9,11 +9,25 @@ pub fn get_default_transform() -> Transform {
 }
 
 impl BaseEntitySummonable<NoData> for ComputerSummoner {
-    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
+    fn get_bundle(&self, spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
         let template_examine_text = "A computer used by bridge personnel.".to_string();
         let mut examine_map = BTreeMap::new();
         examine_map.insert(0, template_examine_text);
 
+        let mut tab_actions_option = None;
+
+        if self.computer_type == REMOTE_DOORS_COMPUTER_TYPE {
+            tab_actions_option = Some(TabActions {
+                tab_actions: vec![TabAction {
+                    id: "actions::computers/remotedoors".to_string(),
+                    text: "Remote Doors".to_string(),
+                    tab_list_priority: 100,
+                    prerequisite_check: Arc::new(remote_doors_action),
+                    belonging_entity: Some(spawn_data.entity),
+                }],
+            });
+        }
+
         BaseEntityBundle {
             default_transform: get_default_transform(),
             examinable: Examinable {
@@ -26,6 +40,7 @@ impl BaseEntitySummonable<NoData> for ComputerSummoner {
                 ..Default::default()
             },
             entity_name: BRIDGE_COMPUTER_ENTITY_NAME.to_string(),
+            tab_actions_option,
             health: Health {
                 is_combat_obstacle: true,
                 is_reach_obstacle: true,
@@ -57,8 +72,11 @@ use api::{
     data::NoData,
     examinable::{Examinable, RichName},
     health::Health,
+    tab_actions::{TabAction, TabActions},
 };
 
+use super::{actions::remote_doors_action, remote_doors::REMOTE_DOORS_COMPUTER_TYPE};
+
 impl RigidBodySummonable<NoData> for ComputerSummoner {
     fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> RigidBodyBundle {
         let mut friction = Friction::coefficient(STANDARD_BODY_FRICTION);
@@ -8,12 +8,8 @@ use bevy::{
         Transform, Without,
     },
 };
-use doryen_fov::FovAlgorithm;
 use entity::spawn::{DefaultSpawnEvent, SpawnData};
-use gridmap::{
-    build::spawn_main_cell,
-    fov::{DoryenMap, FOV_DISTANCE},
-};
+use gridmap::{build::spawn_main_cell, fov::DoryenMap};
 use networking::messages::InputConstructionOptionsSelection;
 use physics::physics::RigidBodyDisabled;
 use api::{
@@ -22,9 +18,9 @@ use api::{
     data::{ConnectedPlayer, EntityDataResource, HandleToEntity, Vec2Int, Vec3Int},
     entity_updates::EntityData,
     gridmap::{
-        cell_id_to_world, get_atmos_index, to_doryen_coordinates, world_to_cell_id,
-        AdjacentTileDirection, CellData, EntityGridData, GridMapType, GridmapData, GridmapDetails1,
-        GridmapMain, RemoveCell,
+        cell_id_to_world, get_deck, get_floor_layer, get_wall_layer, is_wall_layer,
+        to_doryen_coordinates, world_to_cell_id, AdjacentTileDirection, CellData, EntityGridData,
+        GridMapType, GridmapData, GridmapDetails1, GridmapMain, RemoveCell,
     },
     health::{CellUpdate, StructureHealth},
     network::{ReliableServerMessage, TextTreeBit},
@@ -594,17 +590,24 @@ pub fn construction_tool(
 
         let mut target_cell_id = input_cell.clone();
 
+        // Decks are fixed when the map is loaded.
+        let deck = get_deck(input_cell.y);
+
+        if deck >= gridmap_main.decks {
+            continue;
+        }
+
         if !construction_is_entity {
             match gridmap_main.grid_data.get(&input_cell) {
                 Some(_input_cell_data) => {
-                    target_cell_id.y = 0;
+                    target_cell_id.y = get_wall_layer(deck);
                 }
                 None => {
-                    target_cell_id.y = -1;
+                    target_cell_id.y = get_floor_layer(deck);
                 }
             }
         } else {
-            target_cell_id.y = 0;
+            target_cell_id.y = get_wall_layer(deck);
         }
 
         match gridmap_details1.data.get(&target_cell_id) {
@@ -726,7 +729,7 @@ pub fn construction_tool(
                 y: cell_id.z,
             };
 
-            if cell_id_2 == target_cell_id_2 {
+            if cell_id_2 == target_cell_id_2 && get_deck(cell_id.y) == deck {
                 let name = entity_data_component.entity_name.clone();
                 blockers.push(name);
             }
@@ -826,7 +829,7 @@ pub fn construction_tool(
                 }
 
                 // Spawn cell, check build_gridmap_from_data for more info.
-                if target_cell_id.y == 0 {
+                if is_wall_layer(target_cell_id.y) {
                     if cell_properties.floor_cell {
                         let personal_update_text = "[font=".to_owned()
                             + FURTHER_ITALIC_FONT
@@ -858,7 +861,7 @@ pub fn construction_tool(
                         .non_fov_blocking_cells_list
                         .contains(target_item_id)
                     {
-                        fov_map.map.set_transparent(coords.0, coords.1, false);
+                        fov_map.decks[deck].set_transparent(coords.0, coords.1, false);
                     }
 
                     new_entity = Some(entity_op);
@@ -897,15 +900,13 @@ pub fn construction_tool(
 
                 // Update atmospherics.
 
+                let atmos_id = atmospherics_resource.get_cell_atmos_index(target_cell_id);
                 let mut atmospherics = atmospherics_resource
                     .atmospherics
-                    .get_mut(get_atmos_index(Vec2Int {
-                        x: target_cell_id.x,
-                        y: target_cell_id.z,
-                    }))
+                    .get_mut(atmos_id)
                     .unwrap();
 
-                if target_cell_id.y == 0 {
+                if is_wall_layer(target_cell_id.y) {
                     let properties = gridmap_data
                         .main_cell_properties
                         .get(&cell_data.item)
@@ -1090,19 +1091,10 @@ pub fn construction_tool(
             None => {}
         }
 
-        // Send netcode message to all clients who see this tile that it has been updated.
+        // Sensers with this cell in their window recompute their fov next update.
         for (mut senser_component, _connected_player_component) in sensers.iter_mut() {
-            if senser_component.fov.is_in_fov(coords.0, coords.1) {
-                senser_component.fov.clear_fov();
-                let coords =
-                    to_doryen_coordinates(senser_component.cell_id.x, senser_component.cell_id.y);
-                senser_component.fov.compute_fov(
-                    &mut fov_map.map,
-                    coords.0,
-                    coords.1,
-                    FOV_DISTANCE,
-                    true,
-                );
+            if senser_component.is_cell_in_fov_window(&target_cell_id) {
+                senser_component.fov.invalidated = true;
             }
         }
 
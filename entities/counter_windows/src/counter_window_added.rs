@@ -11,7 +11,7 @@ pub fn counter_window_added(
         if AtmosphericsResource::is_id_out_of_range(cell_id2) {
             continue;
         }
-        let atmos_id = get_atmos_index(cell_id2);
+        let atmos_id = atmospherics_resource.get_cell_atmos_index(cell_id);
         let atmospherics = atmospherics_resource
             .atmospherics
             .get_mut(atmos_id)
@@ -32,7 +32,7 @@ use api::{
     data::Vec2Int,
     entity_updates::EntityData,
     examinable::{Examinable, RichName},
-    gridmap::{get_atmos_index, world_to_cell_id, EntityGridData, GridmapMain},
+    gridmap::{world_to_cell_id, EntityGridData, GridmapMain},
 };
 
 use super::{
@@ -63,11 +63,7 @@ pub fn counter_window_default_map_added(
     ) in default_counter_windows.iter_mut()
     {
         let cell_id = world_to_cell_id(rigid_body_position_component.translation.into());
-        let cell_id2 = Vec2Int {
-            x: cell_id.x,
-            y: cell_id.z,
-        };
-        map_data.data.insert(cell_id2, GREEN_MAP_TILE_COUNTER);
+        map_data.insert(cell_id, GREEN_MAP_TILE_COUNTER);
 
         gridmap_main.entity_data.insert(
             cell_id,
@@ -8,7 +8,7 @@ use bevy::{
     },
 };
 use bevy_rapier3d::prelude::{Collider, CollisionGroups};
-use pawn::pawn::{Pawn, ShipAuthorization, ShipAuthorizationEnum};
+use pawn::pawn::{get_id_card_access, Pawn, ShipAuthorization, ShipAuthorizationEnum};
 use physics::physics::{get_bit_masks, ColliderGroup};
 use sfx::{builder::sfx_builder, entity_update::SfxAutoDestroyTimers};
 use api::{
@@ -16,7 +16,8 @@ use api::{
     data::{AirLockCloseRequest, LockedStatus, Vec2Int},
     entity_updates::EntityGroup,
     examinable::Examinable,
-    gridmap::{get_atmos_index, world_to_cell_id},
+    gridmap::{world_to_cell_id},
+    inventory::Inventory,
     network::ReliableServerMessage,
 };
 use sounds::{
@@ -47,7 +48,8 @@ pub fn counter_window_events(
     )>,
     mut counter_window_colliders: Query<&mut CollisionGroups, With<Collider>>,
     counter_window_sensor_query: Query<&CounterWindowSensor>,
-    pawn_query: Query<(&Pawn, &ShipAuthorization)>,
+    pawn_query: Query<(&Pawn, &Inventory)>,
+    id_cards: Query<&ShipAuthorization>,
     mut auto_destroy_timers: ResMut<SfxAutoDestroyTimers>,
     mut commands: Commands,
     mut atmospherics_resource: ResMut<AtmosphericsResource>,
@@ -445,25 +447,22 @@ pub fn counter_window_events(
 
         match request.opener_option {
             Some(opener) => {
-                let pawn_space_access_component_result =
-                    pawn_query.get_component::<ShipAuthorization>(opener);
-                let pawn_space_access_component;
+                let pawn_inventory_component_result = pawn_query.get_component::<Inventory>(opener);
+                let pawn_inventory_component;
 
-                match pawn_space_access_component_result {
+                match pawn_inventory_component_result {
                     Ok(result) => {
-                        pawn_space_access_component = result;
+                        pawn_inventory_component = result;
                     }
                     Err(_err) => {
                         continue;
                     }
                 }
 
+                let id_card_access = get_id_card_access(pawn_inventory_component, &id_cards);
+
                 for space_permission in &counter_window_component.access_permissions {
-                    if pawn_space_access_component
-                        .access
-                        .contains(space_permission)
-                        == true
-                    {
+                    if id_card_access.contains(space_permission) == true {
                         pawn_has_permission = true;
                         break;
                     }
@@ -539,7 +538,7 @@ pub fn counter_window_events(
             if AtmosphericsResource::is_id_out_of_range(cell_id2) {
                 continue;
             }
-            let atmos_id = get_atmos_index(cell_id2);
+            let atmos_id = atmospherics_resource.get_cell_atmos_index(cell_id);
             let atmospherics = atmospherics_resource
                 .atmospherics
                 .get_mut(atmos_id)
@@ -592,25 +591,24 @@ pub fn counter_window_events(
 
                 match request.interacter_option {
                     Some(interacter) => {
-                        let pawn_space_access_component_result =
-                            pawn_query.get_component::<ShipAuthorization>(interacter);
-                        let pawn_space_access_component;
+                        let pawn_inventory_component_result =
+                            pawn_query.get_component::<Inventory>(interacter);
+                        let pawn_inventory_component;
 
-                        match pawn_space_access_component_result {
+                        match pawn_inventory_component_result {
                             Ok(result) => {
-                                pawn_space_access_component = result;
+                                pawn_inventory_component = result;
                             }
                             Err(_err) => {
                                 continue;
                             }
                         }
 
+                        let id_card_access =
+                            get_id_card_access(pawn_inventory_component, &id_cards);
+
                         for space_permission in &counter_window_component.access_permissions {
-                            if pawn_space_access_component
-                                .access
-                                .contains(space_permission)
-                                == true
-                            {
+                            if id_card_access.contains(space_permission) == true {
                                 pawn_has_permission = true;
                                 break;
                             }
@@ -635,7 +633,7 @@ pub fn counter_window_events(
                 if AtmosphericsResource::is_id_out_of_range(cell_id2) {
                     continue;
                 }
-                let atmos_id = get_atmos_index(cell_id2);
+                let atmos_id = atmospherics_resource.get_cell_atmos_index(cell_id);
                 let atmospherics = atmospherics_resource
                     .atmospherics
                     .get_mut(atmos_id)
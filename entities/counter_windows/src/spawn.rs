@@ -97,7 +97,7 @@ use entity::{
     entity_data::RawSpawnEvent,
     spawn::{BaseEntityBundle, BaseEntitySummonable, DefaultSpawnEvent, SpawnData, SpawnEvent},
 };
-use pawn::pawn::ShipAuthorizationEnum;
+use pawn::pawn::{get_raw_entity_access, ShipAuthorizationEnum};
 use physics::physics::{get_bit_masks, ColliderGroup};
 use rigid_body::spawn::{RigidBodyBundle, RigidBodySummonable};
 use api::{
@@ -145,10 +145,28 @@ pub fn summon_counter_window<T: Send + Sync + 'static>(
     mut spawn_events: EventReader<SpawnEvent<T>>,
 ) {
     for spawn_event in spawn_events.iter() {
+        let mut access_permissions;
+
+        if spawn_event.spawn_data.entity_name == BRIDGE_COUNTER_WINDOW_ENTITY_NAME {
+            access_permissions = vec![ShipAuthorizationEnum::Command];
+        } else {
+            access_permissions = vec![ShipAuthorizationEnum::Security];
+        }
+
+        match &spawn_event.spawn_data.raw_entity_option {
+            Some(raw_entity) => match get_raw_entity_access(&raw_entity.data) {
+                Some(access) => {
+                    access_permissions = access;
+                }
+                None => {}
+            },
+            None => {}
+        }
+
         commands
             .entity(spawn_event.spawn_data.entity)
             .insert(CounterWindow {
-                access_permissions: vec![ShipAuthorizationEnum::Security],
+                access_permissions,
                 ..Default::default()
             });
 
@@ -0,0 +1,3 @@
+pub mod headset;
+pub mod plugin;
+pub mod spawn;
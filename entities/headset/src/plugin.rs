@@ -0,0 +1,47 @@
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut};
+use entity::{
+    entity_data::initialize_entity_data,
+    spawn::{summon_base_entity, SpawnEvent},
+};
+use inventory_item::spawn::summon_inventory_item;
+use rigid_body::spawn::summon_rigid_body;
+use api::data::{
+    EntityDataProperties, EntityDataResource, StartupLabels, SummoningLabels, HEADSET_ENTITY_NAME,
+};
+
+use super::spawn::{default_summon_headset, summon_headset, summon_raw_headset, HeadsetSummoner};
+
+pub struct HeadsetPlugin;
+
+impl Plugin for HeadsetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(content_initialization.before(StartupLabels::InitEntities))
+            .add_system(summon_headset::<HeadsetSummoner>.after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (summon_base_entity::<HeadsetSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system(
+                (summon_rigid_body::<HeadsetSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system(
+                (summon_inventory_item::<HeadsetSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system((summon_raw_headset).after(SummoningLabels::TriggerSummon))
+            .add_event::<SpawnEvent<HeadsetSummoner>>()
+            .add_system(
+                (default_summon_headset)
+                    .label(SummoningLabels::DefaultSummon)
+                    .after(SummoningLabels::NormalSummon),
+            );
+    }
+}
+
+pub fn content_initialization(mut entity_data: ResMut<EntityDataResource>) {
+    let entity_properties = EntityDataProperties {
+        name: HEADSET_ENTITY_NAME.to_string(),
+        id: entity_data.get_id_inc(),
+        ..Default::default()
+    };
+
+    initialize_entity_data(&mut entity_data, entity_properties);
+}
@@ -0,0 +1,208 @@
+use bevy::math::Mat4;
+use bevy::math::Quat;
+use bevy::math::Vec3;
+use bevy::prelude::warn;
+use bevy::prelude::Commands;
+use bevy::prelude::EventReader;
+use bevy::prelude::EventWriter;
+use bevy::prelude::Transform;
+use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Friction};
+use connected_player::radio::Headset;
+use entity::entity_data::RawSpawnEvent;
+use entity::spawn::BaseEntityBundle;
+use entity::spawn::BaseEntitySummonable;
+use entity::spawn::DefaultSpawnEvent;
+use entity::spawn::SpawnData;
+use entity::spawn::SpawnEvent;
+use inventory_item::item::InventoryItem;
+use inventory_item::spawn::InventoryItemBundle;
+use inventory_item::spawn::InventoryItemSummonable;
+use rigid_body::rigid_body::STANDARD_BODY_FRICTION;
+use rigid_body::spawn::RigidBodyBundle;
+use rigid_body::spawn::RigidBodySummonable;
+use serde::Deserialize;
+use api::combat::DamageFlag;
+use api::combat::DamageModel;
+use api::converters::string_transform_to_transform;
+use api::data::NoData;
+use api::data::HEADSET_ENTITY_NAME;
+use api::examinable::Examinable;
+use api::examinable::RichName;
+use api::inventory::SlotType;
+
+use super::headset::HeadsetItem;
+
+use std::collections::BTreeMap;
+
+pub fn get_default_transform() -> Transform {
+    Transform::identity()
+}
+
+impl BaseEntitySummonable<NoData> for HeadsetSummoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
+        let mut examine_map = BTreeMap::new();
+        examine_map.insert(
+            0,
+            "A radio headset. Its encryption keys decide which channels it can tune into."
+                .to_string(),
+        );
+        BaseEntityBundle {
+            default_transform: get_default_transform(),
+            examinable: Examinable {
+                assigned_texts: examine_map,
+                name: RichName {
+                    name: "headset".to_string(),
+                    n: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            entity_name: HEADSET_ENTITY_NAME.to_string(),
+            ..Default::default()
+        }
+    }
+}
+use std::collections::HashMap;
+
+impl InventoryItemSummonable for HeadsetSummoner {
+    fn get_bundle(&self, spawn_data: &SpawnData) -> InventoryItemBundle {
+        let mut attachment_transforms = HashMap::new();
+
+        attachment_transforms.insert(
+            "left_hand".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_axis_angle(Vec3::new(1., 0., 0.), 1.570796327),
+                Vec3::new(0., -0.003, -0.108),
+            )),
+        );
+
+        attachment_transforms.insert(
+            "right_hand".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_axis_angle(Vec3::new(1., 0., 0.), 1.570796327),
+                Vec3::new(0.064, -0.019, 0.065),
+            )),
+        );
+
+        let mut melee_damage_flags = HashMap::new();
+        melee_damage_flags.insert(0, DamageFlag::SoftDamage);
+
+        InventoryItemBundle {
+            inventory_item: InventoryItem {
+                in_inventory_of_entity: spawn_data.holder_entity_option,
+                attachment_transforms: attachment_transforms,
+                drop_transform: get_default_transform(),
+                slot_type: SlotType::Headset,
+                combat_melee_damage_model: DamageModel {
+                    brute: 1.,
+                    damage_flags: melee_damage_flags,
+                    ..Default::default()
+                },
+                throw_force_factor: 1.,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl RigidBodySummonable<NoData> for HeadsetSummoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> RigidBodyBundle {
+        let mut friction = Friction::coefficient(STANDARD_BODY_FRICTION);
+        friction.combine_rule = CoefficientCombineRule::Multiply;
+
+        RigidBodyBundle {
+            collider: Collider::cuboid(0.05, 0.03, 0.05),
+            collider_transform: Transform::from_translation(Vec3::new(0., 0.03, 0.)),
+            collider_friction: friction,
+
+            ..Default::default()
+        }
+    }
+}
+
+pub struct HeadsetSummoner;
+
+#[derive(Deserialize)]
+struct RawHeadsetData {
+    channels: Vec<String>,
+}
+
+// Headsets handed out by jobs get their channels from the pawn summoner,
+// headsets placed on the map take them from their entities.json data.
+pub fn summon_headset<T: Send + Sync + 'static>(
+    mut commands: Commands,
+    mut spawn_events: EventReader<SpawnEvent<T>>,
+) {
+    for spawn_event in spawn_events.iter() {
+        commands
+            .entity(spawn_event.spawn_data.entity)
+            .insert(HeadsetItem);
+
+        match &spawn_event.spawn_data.raw_entity_option {
+            Some(raw_entity) => {
+                if raw_entity.data.is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<RawHeadsetData>(&raw_entity.data) {
+                    Ok(raw_headset_data) => {
+                        commands
+                            .entity(spawn_event.spawn_data.entity)
+                            .insert(Headset {
+                                channels: raw_headset_data.channels,
+                            });
+                    }
+                    Err(_) => {
+                        warn!(
+                            "Couldn't parse channel data of map headset: {}",
+                            raw_entity.data
+                        );
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+pub fn summon_raw_headset(
+    mut spawn_events: EventReader<RawSpawnEvent>,
+    mut summon_headset: EventWriter<SpawnEvent<HeadsetSummoner>>,
+    mut commands: Commands,
+) {
+    for spawn_event in spawn_events.iter() {
+        if spawn_event.raw_entity.entity_type != HEADSET_ENTITY_NAME {
+            continue;
+        }
+
+        let entity_transform = string_transform_to_transform(&spawn_event.raw_entity.transform);
+
+        summon_headset.send(SpawnEvent {
+            spawn_data: SpawnData {
+                entity_transform: entity_transform,
+                default_map_spawn: true,
+                entity_name: spawn_event.raw_entity.entity_type.clone(),
+                entity: commands.spawn().id(),
+                raw_entity_option: Some(spawn_event.raw_entity.clone()),
+                ..Default::default()
+            },
+            summoner: HeadsetSummoner,
+        });
+    }
+}
+
+pub fn default_summon_headset(
+    mut default_spawner: EventReader<DefaultSpawnEvent>,
+    mut spawner: EventWriter<SpawnEvent<HeadsetSummoner>>,
+) {
+    for spawn_event in default_spawner.iter() {
+        if spawn_event.spawn_data.entity_name != HEADSET_ENTITY_NAME {
+            continue;
+        }
+        spawner.send(SpawnEvent {
+            spawn_data: spawn_event.spawn_data.clone(),
+            summoner: HeadsetSummoner,
+        });
+    }
+}
@@ -0,0 +1,3 @@
+pub mod id_card;
+pub mod plugin;
+pub mod spawn;
@@ -0,0 +1,45 @@
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut};
+use entity::{
+    entity_data::initialize_entity_data,
+    spawn::{summon_base_entity, SpawnEvent},
+};
+use inventory_item::spawn::summon_inventory_item;
+use rigid_body::spawn::summon_rigid_body;
+use api::data::{
+    EntityDataProperties, EntityDataResource, StartupLabels, SummoningLabels, ID_CARD_ENTITY_NAME,
+};
+
+use super::spawn::{default_summon_id_card, summon_id_card, summon_raw_id_card, IdCardSummoner};
+
+pub struct IdCardPlugin;
+
+impl Plugin for IdCardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(content_initialization.before(StartupLabels::InitEntities))
+            .add_system(summon_id_card::<IdCardSummoner>.after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (summon_base_entity::<IdCardSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system((summon_rigid_body::<IdCardSummoner>).after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (summon_inventory_item::<IdCardSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system((summon_raw_id_card).after(SummoningLabels::TriggerSummon))
+            .add_event::<SpawnEvent<IdCardSummoner>>()
+            .add_system(
+                (default_summon_id_card)
+                    .label(SummoningLabels::DefaultSummon)
+                    .after(SummoningLabels::NormalSummon),
+            );
+    }
+}
+
+pub fn content_initialization(mut entity_data: ResMut<EntityDataResource>) {
+    let entity_properties = EntityDataProperties {
+        name: ID_CARD_ENTITY_NAME.to_string(),
+        id: entity_data.get_id_inc(),
+        ..Default::default()
+    };
+
+    initialize_entity_data(&mut entity_data, entity_properties);
+}
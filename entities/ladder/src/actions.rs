@@ -0,0 +1,143 @@
+use bevy::{
+    math::Vec3,
+    prelude::{Entity, EventWriter, Query, Res, Transform, With, Without},
+};
+use bevy_rapier3d::prelude::{RigidBody, Velocity};
+use api::{
+    data::EntityDataResource,
+    data_link::DataLink,
+    entity_updates::EntityData,
+    gridmap::{
+        get_deck, get_deck_floor_height, world_to_cell_id, CellData, GridMapType, GridmapMain,
+    },
+    inventory::Inventory,
+    pawn::REACH_DISTANCE,
+    tab_actions::QueuedTabActions,
+};
+
+use crate::{
+    ladder::{ladder_notice, Ladder, Ladders},
+    net::NetLadder,
+};
+
+pub const CLIMB_UP_TAB_ACTION_ID: &str = "actions::ladder/climbup";
+pub const CLIMB_DOWN_TAB_ACTION_ID: &str = "actions::ladder/climbdown";
+
+// Pawns get put down in front of the ladder they climbed to, slightly above its floor.
+const CLIMB_OFFSET: f32 = 0.75;
+const CLIMB_HEIGHT_OFFSET: f32 = 1.;
+
+pub fn ladder_action(
+    _self_tab_entity: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    _inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    entity_id_bits_option.is_some() && distance < REACH_DISTANCE
+}
+
+pub fn ladder_actions(
+    queue: Res<QueuedTabActions>,
+    ladder_transforms: Query<&Transform, With<Ladder>>,
+    ladders: Res<Ladders>,
+    gridmap_main: Res<GridmapMain>,
+    mut climbers: Query<(&mut Transform, &mut Velocity), (With<RigidBody>, Without<Ladder>)>,
+    mut net_ladder: EventWriter<NetLadder>,
+) {
+    for queued in queue.queue.iter() {
+        let up;
+
+        if queued.tab_id == CLIMB_UP_TAB_ACTION_ID {
+            up = true;
+        } else if queued.tab_id == CLIMB_DOWN_TAB_ACTION_ID {
+            up = false;
+        } else {
+            continue;
+        }
+
+        let ladder_entity;
+
+        match queued.target_entity_option {
+            Some(bits) => {
+                ladder_entity = Entity::from_bits(bits);
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let ladder_transform;
+
+        match ladder_transforms.get(ladder_entity) {
+            Ok(transform) => {
+                ladder_transform = *transform;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let cell_id = world_to_cell_id(ladder_transform.translation.into());
+        let deck = get_deck(cell_id.y);
+
+        let target_deck_option;
+
+        if up {
+            target_deck_option = Some(deck + 1).filter(|d| *d < gridmap_main.decks);
+        } else {
+            target_deck_option = deck.checked_sub(1);
+        }
+
+        let target_option = target_deck_option.and_then(|target_deck| {
+            ladders
+                .get_on_deck(cell_id, target_deck)
+                .map(|t| (target_deck, t))
+        });
+
+        let text;
+
+        match target_option {
+            Some((target_deck, target_ladder)) => match climbers.get_mut(queued.player_entity) {
+                Ok((mut climber_transform, mut climber_velocity)) => {
+                    let mut translation = target_ladder.transform.translation
+                        + target_ladder.transform.rotation * Vec3::Z * CLIMB_OFFSET;
+                    translation.y = get_deck_floor_height(target_deck) + CLIMB_HEIGHT_OFFSET;
+                    // Rapier picks up the changed transform of the body, the velocity it had
+                    // while climbing would carry it off the ladder on the other deck.
+                    climber_transform.translation = translation;
+                    *climber_velocity = Velocity::zero();
+
+                    if up {
+                        text = "You climb up the ladder.";
+                    } else {
+                        text = "You climb down the ladder.";
+                    }
+                }
+                Err(_rr) => {
+                    continue;
+                }
+            },
+            None => {
+                if up {
+                    text = "The ladder does not lead up to anywhere.";
+                } else {
+                    text = "The ladder does not lead down to anywhere.";
+                }
+            }
+        }
+
+        match queued.handle_option {
+            Some(handle) => {
+                net_ladder.send(NetLadder {
+                    handle,
+                    message: ladder_notice(text),
+                });
+            }
+            None => {}
+        }
+    }
+}
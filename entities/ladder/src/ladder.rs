@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use atmospherics::diffusion::AtmosphericsResource;
+use bevy::prelude::{
+    Added, Component, Entity, EventReader, Query, RemovedComponents, Res, ResMut, Transform,
+};
+use api::{
+    chat::FURTHER_ITALIC_FONT,
+    data::{Vec2Int, Vec3Int},
+    gridmap::{get_deck, get_wall_layer, world_to_cell_id},
+    network::ReliableServerMessage,
+    rounds::ResetWorld,
+};
+
+#[derive(Component, Default)]
+pub struct Ladder;
+
+pub struct LadderData {
+    pub entity: Entity,
+    pub transform: Transform,
+}
+
+// Ladders by the wall layer cell they stand in.
+#[derive(Default)]
+pub struct Ladders {
+    pub ladders: HashMap<Vec3Int, LadderData>,
+}
+
+impl Ladders {
+    // The ladder at the same x and z on another deck.
+    pub fn get_on_deck(&self, cell_id: Vec3Int, deck: usize) -> Option<&LadderData> {
+        self.ladders.get(&Vec3Int {
+            y: get_wall_layer(deck),
+            ..cell_id
+        })
+    }
+}
+
+pub fn ladder_notice(text: &str) -> ReliableServerMessage {
+    ReliableServerMessage::ChatMessage(
+        "[font=".to_string() + FURTHER_ITALIC_FONT + "]" + text + "[/font]",
+    )
+}
+
+// Opens or closes the hatch shafts between a ladder and the ladders right above and below it.
+fn set_vertical_passages(
+    ladders: &Ladders,
+    atmospherics_resource: &mut AtmosphericsResource,
+    cell_id: Vec3Int,
+    passage: bool,
+) {
+    if AtmosphericsResource::is_id_out_of_range(Vec2Int {
+        x: cell_id.x,
+        y: cell_id.z,
+    }) {
+        return;
+    }
+
+    let deck = get_deck(cell_id.y);
+
+    let mut lower_decks = vec![];
+
+    if deck > 0 && ladders.get_on_deck(cell_id, deck - 1).is_some() {
+        lower_decks.push(deck - 1);
+    }
+    if ladders.get_on_deck(cell_id, deck + 1).is_some() {
+        lower_decks.push(deck);
+    }
+
+    for lower_deck in lower_decks {
+        if lower_deck + 1 >= atmospherics_resource.decks {
+            continue;
+        }
+        let atmos_id = atmospherics_resource.get_cell_atmos_index(Vec3Int {
+            y: get_wall_layer(lower_deck),
+            ..cell_id
+        });
+        atmospherics_resource.atmospherics[atmos_id].vertical_passage = passage;
+    }
+}
+
+// Two ladders stacked on top of each other form a hatch shaft that air passes through.
+pub fn ladder_added(
+    ladders_query: Query<(Entity, &Transform), Added<Ladder>>,
+    mut ladders: ResMut<Ladders>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+) {
+    for (ladder_entity, ladder_transform) in ladders_query.iter() {
+        let cell_id = world_to_cell_id(ladder_transform.translation.into());
+        let cell_id = Vec3Int {
+            y: get_wall_layer(get_deck(cell_id.y)),
+            ..cell_id
+        };
+
+        ladders.ladders.insert(
+            cell_id,
+            LadderData {
+                entity: ladder_entity,
+                transform: *ladder_transform,
+            },
+        );
+
+        set_vertical_passages(&ladders, &mut atmospherics_resource, cell_id, true);
+    }
+}
+
+// Runs in PostUpdate, removed components are gone again by the next Update.
+pub fn ladder_removed(
+    removed_ladders: RemovedComponents<Ladder>,
+    mut ladders: ResMut<Ladders>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+) {
+    for removed_entity in removed_ladders.iter() {
+        let mut removed_cell_id_option = None;
+
+        for (cell_id, ladder_data) in ladders.ladders.iter() {
+            if ladder_data.entity == removed_entity {
+                removed_cell_id_option = Some(*cell_id);
+                break;
+            }
+        }
+
+        match removed_cell_id_option {
+            Some(cell_id) => {
+                set_vertical_passages(&ladders, &mut atmospherics_resource, cell_id, false);
+                ladders.ladders.remove(&cell_id);
+            }
+            None => {}
+        }
+    }
+}
+
+// A rebuilt map comes with fresh atmospherics, the shafts of ladders that survived get restored.
+pub fn rebuild_ladder_passages(
+    ladders: Res<Ladders>,
+    mut atmospherics_resource: ResMut<AtmosphericsResource>,
+) {
+    for cell_id in ladders.ladders.keys() {
+        set_vertical_passages(&ladders, &mut atmospherics_resource, *cell_id, true);
+    }
+}
+
+pub fn reset_ladders(
+    mut reset_world_events: EventReader<ResetWorld>,
+    mut ladders: ResMut<Ladders>,
+) {
+    if reset_world_events.iter().count() == 0 {
+        return;
+    }
+
+    ladders.ladders.clear();
+}
@@ -0,0 +1,5 @@
+pub mod actions;
+pub mod ladder;
+pub mod net;
+pub mod plugin;
+pub mod spawn;
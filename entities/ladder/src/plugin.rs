@@ -0,0 +1,76 @@
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut, SystemSet};
+use entity::{
+    entity_data::initialize_entity_data,
+    spawn::{summon_base_entity, SpawnEvent},
+};
+use networking::messages::net_system;
+use rigid_body::spawn::summon_rigid_body;
+use api::{
+    data::{
+        EntityDataProperties, EntityDataResource, PostUpdateLabels, StartupLabels, SummoningLabels,
+    },
+    rounds::{map_rebuild_requested, RoundsLabels},
+    tab_actions::TabActionsQueueLabels,
+};
+
+use crate::{
+    actions::ladder_actions,
+    ladder::{ladder_added, ladder_removed, rebuild_ladder_passages, reset_ladders, Ladders},
+    net::NetLadder,
+    spawn::{
+        default_summon_ladder, summon_raw_ladder, summon_ladder, LadderSummoner, LADDER_ENTITY_NAME,
+    },
+};
+
+use bevy::app::CoreStage::PostUpdate;
+
+pub struct LadderPlugin;
+
+impl Plugin for LadderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Ladders>()
+            .add_event::<NetLadder>()
+            .add_system(ladder_actions.after(TabActionsQueueLabels::TabAction))
+            .add_system(ladder_added.after(RoundsLabels::RebuildAtmospherics))
+            .add_system(
+                rebuild_ladder_passages
+                    .with_run_criteria(map_rebuild_requested)
+                    .after(RoundsLabels::RebuildAtmospherics),
+            )
+            .add_system(
+                reset_ladders
+                    .label(RoundsLabels::ResetWorld)
+                    .after(RoundsLabels::Controller),
+            )
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetLadder>),
+            )
+            .add_system_to_stage(PostUpdate, ladder_removed)
+            .add_event::<SpawnEvent<LadderSummoner>>()
+            .add_startup_system(content_initialization.before(StartupLabels::BuildGridmap))
+            .add_system(summon_ladder.after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (summon_base_entity::<LadderSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system((summon_rigid_body::<LadderSummoner>).after(SummoningLabels::TriggerSummon))
+            .add_system((summon_raw_ladder).after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (default_summon_ladder)
+                    .label(SummoningLabels::DefaultSummon)
+                    .after(SummoningLabels::NormalSummon),
+            );
+    }
+}
+
+pub fn content_initialization(mut entity_data: ResMut<EntityDataResource>) {
+    let entity_properties = EntityDataProperties {
+        name: LADDER_ENTITY_NAME.to_string(),
+        id: entity_data.get_id_inc(),
+        ..Default::default()
+    };
+    initialize_entity_data(&mut entity_data, entity_properties);
+}
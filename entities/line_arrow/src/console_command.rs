@@ -6,7 +6,7 @@ use bevy::{
 use entity::spawn::{SpawnData, SpawnEvent};
 use networking::messages::InputConsoleCommand;
 use api::{
-    console_commands::ConsoleCommandVariantValues, data::HandleToEntity,
+    combat::DebugHitbox, console_commands::ConsoleCommandVariantValues, data::HandleToEntity,
     load_entity::NetUnloadEntity, sensable::Sensable,
 };
 
@@ -147,3 +147,30 @@ pub fn point_arrow(
         }
     }
 }
+
+// Seconds rewound hitboxes stay pointed at.
+const REWOUND_HITBOX_ARROW_DURATION: f32 = 3.;
+
+pub fn rewound_hitbox_arrows(
+    mut debug_hitboxes: EventReader<DebugHitbox>,
+    mut commands: Commands,
+    mut spawn_event: EventWriter<SpawnEvent<LineArrowSummoner>>,
+) {
+    for debug_hitbox in debug_hitboxes.iter() {
+        let mut passed_transform = Transform::identity();
+        passed_transform.translation = debug_hitbox.translation;
+
+        spawn_event.send(SpawnEvent {
+            spawn_data: SpawnData {
+                entity_transform: passed_transform,
+                correct_transform: false,
+                entity_name: LINE_ARROW_ENTITY_NAME.to_string(),
+                entity: commands.spawn().id(),
+                ..Default::default()
+            },
+            summoner: LineArrowSummoner {
+                duration: REWOUND_HITBOX_ARROW_DURATION,
+            },
+        });
+    }
+}
@@ -9,7 +9,7 @@ use api::{
     data::{EntityDataProperties, EntityDataResource, StartupLabels, SummoningLabels},
 };
 
-use crate::console_command::entity_console_commands;
+use crate::console_command::{entity_console_commands, rewound_hitbox_arrows};
 
 use super::{
     console_command::point_arrow,
@@ -26,6 +26,7 @@ impl Plugin for LineArrowPlugin {
                 .label(StartupLabels::ConsoleCommands),
         )
         .add_system(entity_console_commands.label(SummoningLabels::TriggerSummon))
+        .add_system(rewound_hitbox_arrows.label(SummoningLabels::TriggerSummon))
         .add_startup_system(content_initialization.before(StartupLabels::InitEntities))
         .add_system((summon_base_entity::<LineArrowSummoner>).after(SummoningLabels::TriggerSummon))
         .add_system(summon_line_arrow::<LineArrowSummoner>.after(SummoningLabels::TriggerSummon))
@@ -0,0 +1,3 @@
+pub mod magazine_b1;
+pub mod plugin;
+pub mod spawn;
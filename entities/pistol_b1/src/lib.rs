@@ -0,0 +1,3 @@
+pub mod pistol_b1;
+pub mod plugin;
+pub mod spawn;
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+pub fn get_default_transform() -> Transform {
+    Transform::from_matrix(Mat4::from_scale_rotation_translation(
+        Vec3::new(1., 1., 1.),
+        Quat::from_axis_angle(Vec3::new(-0.00000035355248, 0.707105, 0.7071085), 3.1415951),
+        Vec3::new(0., 0.116, 0.),
+    ))
+}
+
+impl BaseEntitySummonable<NoData> for PistolB1Summoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
+        let mut examine_map = BTreeMap::new();
+        examine_map.insert(
+            0,
+            "A standard issue ballistic pistol. It is a lethal weapon.".to_string(),
+        );
+
+        BaseEntityBundle {
+            default_transform: get_default_transform(),
+            examinable: Examinable {
+                assigned_texts: examine_map,
+                name: RichName {
+                    name: "ballistic pistol".to_string(),
+                    n: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            entity_name: PISTOL_B1_ENTITY_NAME.to_string(),
+
+            ..Default::default()
+        }
+    }
+}
+use std::{collections::HashMap, sync::Arc};
+
+pub const PISTOL_B1_PROJECTILE_RANGE: f32 = 50.;
+pub const PISTOL_B1_MAGAZINE_CAPACITY: u16 = 12;
+pub const PISTOL_B1_MUZZLE_VELOCITY: f32 = 60.;
+pub const PISTOL_B1_PENETRATION: f32 = 1.;
+
+impl InventoryItemSummonable for PistolB1Summoner {
+    fn get_bundle(&self, spawn_data: &SpawnData) -> InventoryItemBundle {
+        let mut attachment_transforms = HashMap::new();
+
+        attachment_transforms.insert(
+            "left_hand".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_axis_angle(Vec3::new(-0.5695359, -0.7159382, 0.4038085), 2.4144572),
+                Vec3::new(-0.031, 0.033, 0.011),
+            )),
+        );
+
+        attachment_transforms.insert(
+            "right_hand".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_xyzw(0.611671, 0.396847, 0.530651, 0.432181),
+                Vec3::new(0.077, -0.067, -0.045),
+            )),
+        );
+
+        attachment_transforms.insert(
+            "holster".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_axis_angle(Vec3::new(0.004467, 0.0995011, -0.9950274), 3.0523109),
+                Vec3::new(0., 0.132, 0.05),
+            )),
+        );
+
+        let mut melee_damage_flags = HashMap::new();
+        melee_damage_flags.insert(0, DamageFlag::SoftDamage);
+
+        InventoryItemBundle {
+            inventory_item: InventoryItem {
+                in_inventory_of_entity: spawn_data.holder_entity_option,
+                attachment_transforms: attachment_transforms,
+                drop_transform: get_default_transform(),
+                slot_type: SlotType::Holster,
+                combat_attack_animation: CombatAttackAnimation::PistolShot,
+                combat_type: CombatType::Projectile(ProjectileType::Ballistic(
+                    PISTOL_B1_MUZZLE_VELOCITY,
+                    PISTOL_B1_PROJECTILE_RANGE,
+                    PISTOL_B1_PENETRATION,
+                )),
+                combat_melee_damage_model: DamageModel {
+                    brute: 9.,
+                    damage_flags: melee_damage_flags,
+                    ..Default::default()
+                },
+                combat_projectile_damage_model: Some(DamageModel {
+                    brute: 15.,
+                    ..Default::default()
+                }),
+                combat_standard_animation: CombatStandardAnimation::PistolStance,
+                combat_projectile_sound_set: Some(CombatSoundSet::default_ballistic_projectiles()),
+                combat_projectile_text_set: Some(InventoryItem::get_default_ballistic_words()),
+                trigger_projectile_text_set: Some(InventoryItem::get_default_trigger_weapon_words()),
+                active_slot_tab_actions: vec![TabAction {
+                    id: RELOAD_TAB_ACTION_ID.to_string(),
+                    text: "Reload".to_string(),
+                    tab_list_priority: 50,
+                    prerequisite_check: Arc::new(reload_action),
+                    belonging_entity: spawn_data.held_entity_option,
+                }],
+                ..Default::default()
+            },
+        }
+    }
+}
+use bevy::math::Mat4;
+use bevy::math::Quat;
+use bevy::math::Vec3;
+use bevy::prelude::Commands;
+use bevy::prelude::EventReader;
+use bevy::prelude::EventWriter;
+use bevy::prelude::Transform;
+use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Friction};
+use entity::entity_data::RawSpawnEvent;
+use entity::spawn::BaseEntityBundle;
+use entity::spawn::BaseEntitySummonable;
+use entity::spawn::DefaultSpawnEvent;
+use entity::spawn::SpawnData;
+use entity::spawn::SpawnEvent;
+use inventory_item::item::InventoryItem;
+use inventory_item::spawn::InventoryItemBundle;
+use inventory_item::spawn::InventoryItemSummonable;
+use rigid_body::rigid_body::STANDARD_BODY_FRICTION;
+use rigid_body::spawn::RigidBodyBundle;
+use rigid_body::spawn::RigidBodySummonable;
+use api::combat::Ammunition;
+use api::combat::AmmunitionType;
+use api::combat::CombatAttackAnimation;
+use api::combat::CombatStandardAnimation;
+use api::combat::CombatType;
+use api::combat::DamageFlag;
+use api::combat::DamageModel;
+use api::combat::Magazine;
+use api::combat::ProjectileType;
+use api::converters::string_transform_to_transform;
+use api::data::NoData;
+use api::data::PISTOL_B1_ENTITY_NAME;
+use api::examinable::Examinable;
+use api::examinable::RichName;
+use api::inventory::SlotType;
+use api::tab_actions::TabAction;
+use combat::ammunition::{reload_action, RELOAD_TAB_ACTION_ID};
+use sounds::shared::CombatSoundSet;
+
+impl RigidBodySummonable<NoData> for PistolB1Summoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> RigidBodyBundle {
+        let mut friction = Friction::coefficient(STANDARD_BODY_FRICTION);
+        friction.combine_rule = CoefficientCombineRule::Multiply;
+
+        RigidBodyBundle {
+            collider: Collider::cuboid(0.047, 0.219, 0.199),
+            collider_transform: Transform::from_translation(Vec3::new(0., 0.087, 0.)),
+            collider_friction: friction,
+
+            ..Default::default()
+        }
+    }
+}
+
+use super::pistol_b1::PistolB1;
+
+pub struct PistolB1Summoner;
+
+pub fn summon_pistol_b1<T: Send + Sync + 'static>(
+    mut commands: Commands,
+    mut spawn_events: EventReader<SpawnEvent<T>>,
+) {
+    for spawn_event in spawn_events.iter() {
+        commands
+            .entity(spawn_event.spawn_data.entity)
+            .insert_bundle((
+                PistolB1,
+                Ammunition {
+                    loaded: Magazine::new(AmmunitionType::Rounds, PISTOL_B1_MAGAZINE_CAPACITY),
+                },
+            ));
+    }
+}
+
+pub fn summon_raw_pistol_b1(
+    mut spawn_events: EventReader<RawSpawnEvent>,
+    mut summon_pistol_b1: EventWriter<SpawnEvent<PistolB1Summoner>>,
+    mut commands: Commands,
+) {
+    for spawn_event in spawn_events.iter() {
+        if spawn_event.raw_entity.entity_type != PISTOL_B1_ENTITY_NAME {
+            continue;
+        }
+
+        let entity_transform = string_transform_to_transform(&spawn_event.raw_entity.transform);
+
+        summon_pistol_b1.send(SpawnEvent {
+            spawn_data: SpawnData {
+                entity_transform: entity_transform,
+                default_map_spawn: true,
+                entity_name: spawn_event.raw_entity.entity_type.clone(),
+                entity: commands.spawn().id(),
+                raw_entity_option: Some(spawn_event.raw_entity.clone()),
+                ..Default::default()
+            },
+            summoner: PistolB1Summoner,
+        });
+    }
+}
+
+pub fn default_summon_pistol_b1(
+    mut default_spawner: EventReader<DefaultSpawnEvent>,
+    mut spawner: EventWriter<SpawnEvent<PistolB1Summoner>>,
+) {
+    for spawn_event in default_spawner.iter() {
+        if spawn_event.spawn_data.entity_name != PISTOL_B1_ENTITY_NAME {
+            continue;
+        }
+        spawner.send(SpawnEvent {
+            spawn_data: spawn_event.spawn_data.clone(),
+            summoner: PistolB1Summoner,
+        });
+    }
+}
@@ -33,9 +33,10 @@ impl BaseEntitySummonable<NoData> for PistolL1Summoner {
         }
     }
 }
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 pub const PISTOL_L1_PROJECTILE_RANGE: f32 = 50.;
+pub const PISTOL_L1_CHARGE_CAPACITY: u16 = 20;
 
 impl InventoryItemSummonable for PistolL1Summoner {
     fn get_bundle(&self, spawn_data: &SpawnData) -> InventoryItemBundle {
@@ -101,6 +102,13 @@ impl InventoryItemSummonable for PistolL1Summoner {
                 combat_projectile_sound_set: Some(CombatSoundSet::default_laser_projectiles()),
                 combat_projectile_text_set: Some(InventoryItem::get_default_laser_words()),
                 trigger_projectile_text_set: Some(InventoryItem::get_default_trigger_weapon_words()),
+                active_slot_tab_actions: vec![TabAction {
+                    id: RELOAD_TAB_ACTION_ID.to_string(),
+                    text: "Reload".to_string(),
+                    tab_list_priority: 50,
+                    prerequisite_check: Arc::new(reload_action),
+                    belonging_entity: spawn_data.held_entity_option,
+                }],
                 ..Default::default()
             },
         }
@@ -126,11 +134,14 @@ use inventory_item::spawn::InventoryItemSummonable;
 use rigid_body::rigid_body::STANDARD_BODY_FRICTION;
 use rigid_body::spawn::RigidBodyBundle;
 use rigid_body::spawn::RigidBodySummonable;
+use api::combat::Ammunition;
+use api::combat::AmmunitionType;
 use api::combat::CombatAttackAnimation;
 use api::combat::CombatStandardAnimation;
 use api::combat::CombatType;
 use api::combat::DamageFlag;
 use api::combat::DamageModel;
+use api::combat::Magazine;
 use api::combat::ProjectileType;
 use api::converters::string_transform_to_transform;
 use api::data::NoData;
@@ -138,6 +149,8 @@ use api::data::PISTOL_L1_ENTITY_NAME;
 use api::examinable::Examinable;
 use api::examinable::RichName;
 use api::inventory::SlotType;
+use api::tab_actions::TabAction;
+use combat::ammunition::{reload_action, RELOAD_TAB_ACTION_ID};
 use sounds::shared::CombatSoundSet;
 
 impl RigidBodySummonable<NoData> for PistolL1Summoner {
@@ -166,7 +179,12 @@ pub fn summon_pistol_l1<T: Send + Sync + 'static>(
     for spawn_event in spawn_events.iter() {
         commands
             .entity(spawn_event.spawn_data.entity)
-            .insert(PistolL1);
+            .insert_bundle((
+                PistolL1,
+                Ammunition {
+                    loaded: Magazine::new(AmmunitionType::Charge, PISTOL_L1_CHARGE_CAPACITY),
+                },
+            ));
     }
 }
 
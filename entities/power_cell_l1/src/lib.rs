@@ -0,0 +1,3 @@
+pub mod plugin;
+pub mod power_cell_l1;
+pub mod spawn;
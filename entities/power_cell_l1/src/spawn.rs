@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+
+pub fn get_default_transform() -> Transform {
+    Transform::identity()
+}
+
+impl BaseEntitySummonable<NoData> for PowerCellL1Summoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
+        let mut examine_map = BTreeMap::new();
+        examine_map.insert(
+            0,
+            "A rechargeable power cell for laser pistols.".to_string(),
+        );
+        BaseEntityBundle {
+            default_transform: get_default_transform(),
+            examinable: Examinable {
+                assigned_texts: examine_map,
+                name: RichName {
+                    name: "power cell".to_string(),
+                    n: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            entity_name: POWER_CELL_L1_ENTITY_NAME.to_string(),
+            ..Default::default()
+        }
+    }
+}
+use std::collections::HashMap;
+
+impl InventoryItemSummonable for PowerCellL1Summoner {
+    fn get_bundle(&self, spawn_data: &SpawnData) -> InventoryItemBundle {
+        let mut attachment_transforms = HashMap::new();
+
+        attachment_transforms.insert(
+            "left_hand".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_axis_angle(Vec3::new(1., 0., 0.), 1.570796327),
+                Vec3::new(0., -0.003, -0.108),
+            )),
+        );
+
+        attachment_transforms.insert(
+            "right_hand".to_string(),
+            Transform::from_matrix(Mat4::from_scale_rotation_translation(
+                Vec3::new(0.5, 0.5, 0.5),
+                Quat::from_axis_angle(Vec3::new(1., 0., 0.), 1.570796327),
+                Vec3::new(0.064, -0.019, 0.065),
+            )),
+        );
+
+        let mut melee_damage_flags = HashMap::new();
+        melee_damage_flags.insert(0, DamageFlag::SoftDamage);
+
+        InventoryItemBundle {
+            inventory_item: InventoryItem {
+                in_inventory_of_entity: spawn_data.holder_entity_option,
+                attachment_transforms: attachment_transforms,
+                drop_transform: get_default_transform(),
+                slot_type: SlotType::Generic,
+                combat_melee_damage_model: DamageModel {
+                    brute: 1.,
+                    damage_flags: melee_damage_flags,
+                    ..Default::default()
+                },
+                throw_force_factor: 1.,
+                ..Default::default()
+            },
+        }
+    }
+}
+use bevy::math::Mat4;
+use bevy::math::Quat;
+use bevy::math::Vec3;
+use bevy::prelude::Commands;
+use bevy::prelude::EventReader;
+use bevy::prelude::EventWriter;
+use bevy::prelude::Transform;
+use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Friction};
+use entity::entity_data::RawSpawnEvent;
+use entity::spawn::BaseEntityBundle;
+use entity::spawn::BaseEntitySummonable;
+use entity::spawn::DefaultSpawnEvent;
+use entity::spawn::SpawnData;
+use entity::spawn::SpawnEvent;
+use inventory_item::item::InventoryItem;
+use inventory_item::spawn::InventoryItemBundle;
+use inventory_item::spawn::InventoryItemSummonable;
+use rigid_body::rigid_body::STANDARD_BODY_FRICTION;
+use rigid_body::spawn::RigidBodyBundle;
+use rigid_body::spawn::RigidBodySummonable;
+use api::combat::AmmunitionType;
+use api::combat::DamageFlag;
+use api::combat::DamageModel;
+use api::combat::Magazine;
+use api::converters::string_transform_to_transform;
+use api::data::NoData;
+use api::data::POWER_CELL_L1_ENTITY_NAME;
+use api::examinable::Examinable;
+use api::examinable::RichName;
+use api::inventory::SlotType;
+
+use super::power_cell_l1::PowerCellL1;
+
+pub const POWER_CELL_L1_CAPACITY: u16 = 20;
+
+impl RigidBodySummonable<NoData> for PowerCellL1Summoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> RigidBodyBundle {
+        let mut friction = Friction::coefficient(STANDARD_BODY_FRICTION);
+        friction.combine_rule = CoefficientCombineRule::Multiply;
+
+        RigidBodyBundle {
+            collider: Collider::cuboid(0.03, 0.05, 0.03),
+            collider_transform: Transform::from_translation(Vec3::new(0., 0.05, 0.)),
+            collider_friction: friction,
+
+            ..Default::default()
+        }
+    }
+}
+
+pub struct PowerCellL1Summoner;
+
+pub fn summon_power_cell_l1<T: Send + Sync + 'static>(
+    mut commands: Commands,
+    mut spawn_events: EventReader<SpawnEvent<T>>,
+) {
+    for spawn_event in spawn_events.iter() {
+        commands
+            .entity(spawn_event.spawn_data.entity)
+            .insert_bundle((
+                PowerCellL1,
+                Magazine::new(AmmunitionType::Charge, POWER_CELL_L1_CAPACITY),
+            ));
+    }
+}
+
+pub fn summon_raw_power_cell_l1(
+    mut spawn_events: EventReader<RawSpawnEvent>,
+    mut summon_power_cell_l1: EventWriter<SpawnEvent<PowerCellL1Summoner>>,
+    mut commands: Commands,
+) {
+    for spawn_event in spawn_events.iter() {
+        if spawn_event.raw_entity.entity_type != POWER_CELL_L1_ENTITY_NAME {
+            continue;
+        }
+
+        let entity_transform = string_transform_to_transform(&spawn_event.raw_entity.transform);
+
+        summon_power_cell_l1.send(SpawnEvent {
+            spawn_data: SpawnData {
+                entity_transform: entity_transform,
+                default_map_spawn: true,
+                entity_name: spawn_event.raw_entity.entity_type.clone(),
+                entity: commands.spawn().id(),
+                raw_entity_option: Some(spawn_event.raw_entity.clone()),
+                ..Default::default()
+            },
+            summoner: PowerCellL1Summoner,
+        });
+    }
+}
+
+pub fn default_summon_power_cell_l1(
+    mut default_spawner: EventReader<DefaultSpawnEvent>,
+    mut spawner: EventWriter<SpawnEvent<PowerCellL1Summoner>>,
+) {
+    for spawn_event in default_spawner.iter() {
+        if spawn_event.spawn_data.entity_name != POWER_CELL_L1_ENTITY_NAME {
+            continue;
+        }
+        spawner.send(SpawnEvent {
+            spawn_data: spawn_event.spawn_data.clone(),
+            summoner: PowerCellL1Summoner,
+        });
+    }
+}
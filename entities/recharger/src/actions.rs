@@ -0,0 +1,135 @@
+use bevy::prelude::{Entity, EventWriter, Query, Res};
+use api::{
+    combat::{Ammunition, AmmunitionType, Magazine},
+    data::EntityDataResource,
+    data_link::DataLink,
+    entity_updates::EntityData,
+    examinable::Examinable,
+    gridmap::{CellData, GridMapType},
+    inventory::Inventory,
+    pawn::REACH_DISTANCE,
+    tab_actions::QueuedTabActions,
+};
+
+use crate::{
+    net::NetRecharger,
+    recharger::{recharger_notice, RechargeSession, Recharger},
+};
+
+pub const RECHARGE_TAB_ACTION_ID: &str = "actions::recharger/recharge";
+
+pub fn recharger_action(
+    _self_tab_entity: Option<Entity>,
+    entity_id_bits_option: Option<u64>,
+    _cell_id_option: Option<(GridMapType, i16, i16, i16, Option<&CellData>)>,
+    distance: f32,
+    inventory_component: &Inventory,
+    _entity_data_resource: &EntityDataResource,
+    _entity_datas: &Query<&EntityData>,
+    _data_link_component: &DataLink,
+) -> bool {
+    entity_id_bits_option.is_some()
+        && distance < REACH_DISTANCE
+        && inventory_component.get_active_slot_entity().is_some()
+}
+
+// Power cells and weapons with a power cell loaded can be recharged.
+fn is_rechargeable(
+    item: Entity,
+    ammunition: &Query<&Ammunition>,
+    magazines: &Query<&Magazine>,
+) -> bool {
+    match ammunition.get(item) {
+        Ok(ammunition_component) => {
+            ammunition_component.loaded.ammunition_type == AmmunitionType::Charge
+        }
+        Err(_rr) => match magazines.get(item) {
+            Ok(magazine_component) => magazine_component.ammunition_type == AmmunitionType::Charge,
+            Err(_rr) => false,
+        },
+    }
+}
+
+pub fn recharger_actions(
+    queue: Res<QueuedTabActions>,
+    mut rechargers: Query<&mut Recharger>,
+    inventories: Query<&Inventory>,
+    ammunition: Query<&Ammunition>,
+    magazines: Query<&Magazine>,
+    examinables: Query<&Examinable>,
+    mut net_recharger: EventWriter<NetRecharger>,
+) {
+    for queued in queue.queue.iter() {
+        if queued.tab_id != RECHARGE_TAB_ACTION_ID {
+            continue;
+        }
+
+        let recharger_entity;
+
+        match queued.target_entity_option {
+            Some(bits) => {
+                recharger_entity = Entity::from_bits(bits);
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let mut recharger_component;
+
+        match rechargers.get_mut(recharger_entity) {
+            Ok(component) => {
+                recharger_component = component;
+            }
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let item;
+
+        match inventories.get(queued.player_entity) {
+            Ok(inventory_component) => match inventory_component.get_active_slot_entity() {
+                Some(entity) => {
+                    item = entity;
+                }
+                None => {
+                    continue;
+                }
+            },
+            Err(_rr) => {
+                continue;
+            }
+        }
+
+        let item_name = match examinables.get(item) {
+            Ok(examinable_component) => examinable_component.name.get_name().to_string(),
+            Err(_rr) => "item".to_string(),
+        };
+
+        let text;
+
+        if recharger_component.session_option.is_some() {
+            text = "The recharger is already charging something.".to_string();
+        } else if !is_rechargeable(item, &ammunition, &magazines) {
+            text = "The recharger cannot charge the ".to_string() + &item_name + ".";
+        } else {
+            recharger_component.session_option = Some(RechargeSession::new(
+                item,
+                queued.player_entity,
+                queued.handle_option,
+            ));
+            text = "You start recharging the ".to_string() + &item_name + ".";
+        }
+
+        match queued.handle_option {
+            Some(handle) => {
+                net_recharger.send(NetRecharger {
+                    handle,
+                    message: recharger_notice(&text),
+                });
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod actions;
+pub mod net;
+pub mod plugin;
+pub mod recharger;
+pub mod spawn;
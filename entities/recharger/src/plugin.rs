@@ -0,0 +1,66 @@
+use bevy::prelude::{App, ParallelSystemDescriptorCoercion, Plugin, ResMut, SystemSet};
+use entity::{
+    entity_data::initialize_entity_data,
+    spawn::{summon_base_entity, SpawnEvent},
+};
+use networking::messages::net_system;
+use rigid_body::spawn::summon_rigid_body;
+use api::{
+    data::{
+        EntityDataProperties, EntityDataResource, PostUpdateLabels, StartupLabels, SummoningLabels,
+    },
+    tab_actions::TabActionsQueueLabels,
+};
+
+use crate::{
+    actions::recharger_actions,
+    net::NetRecharger,
+    recharger::recharger_tick,
+    spawn::{
+        default_summon_recharger, summon_raw_recharger, summon_recharger, RechargerSummoner,
+        RECHARGER_ENTITY_NAME,
+    },
+};
+
+use bevy::app::CoreStage::PostUpdate;
+
+pub struct RechargerPlugin;
+
+impl Plugin for RechargerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NetRecharger>()
+            .add_system(recharger_actions.after(TabActionsQueueLabels::TabAction))
+            .add_system(recharger_tick)
+            .add_system_set_to_stage(
+                PostUpdate,
+                SystemSet::new()
+                    .after(PostUpdateLabels::VisibleChecker)
+                    .label(PostUpdateLabels::Net)
+                    .with_system(net_system::<NetRecharger>),
+            )
+            .add_event::<SpawnEvent<RechargerSummoner>>()
+            .add_startup_system(content_initialization.before(StartupLabels::BuildGridmap))
+            .add_system(summon_recharger.after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (summon_base_entity::<RechargerSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system(
+                (summon_rigid_body::<RechargerSummoner>).after(SummoningLabels::TriggerSummon),
+            )
+            .add_system((summon_raw_recharger).after(SummoningLabels::TriggerSummon))
+            .add_system(
+                (default_summon_recharger)
+                    .label(SummoningLabels::DefaultSummon)
+                    .after(SummoningLabels::NormalSummon),
+            );
+    }
+}
+
+pub fn content_initialization(mut entity_data: ResMut<EntityDataResource>) {
+    let entity_properties = EntityDataProperties {
+        name: RECHARGER_ENTITY_NAME.to_string(),
+        id: entity_data.get_id_inc(),
+        ..Default::default()
+    };
+    initialize_entity_data(&mut entity_data, entity_properties);
+}
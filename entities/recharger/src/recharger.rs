@@ -0,0 +1,149 @@
+use bevy::{
+    core::{Time, Timer},
+    prelude::{Component, Entity, EventWriter, Query, Res, Transform},
+};
+use api::{
+    chat::FURTHER_ITALIC_FONT,
+    combat::{Ammunition, Magazine},
+    examinable::Examinable,
+    inventory::Inventory,
+    network::ReliableServerMessage,
+    pawn::REACH_DISTANCE,
+};
+
+use crate::net::NetRecharger;
+
+// Seconds it takes to recharge one shot worth of charge.
+const RECHARGE_INTERVAL: f32 = 0.5;
+
+pub struct RechargeSession {
+    pub item: Entity,
+    pub holder: Entity,
+    pub handle_option: Option<u64>,
+    pub timer: Timer,
+}
+
+#[derive(Component, Default)]
+pub struct Recharger {
+    pub session_option: Option<RechargeSession>,
+}
+
+impl RechargeSession {
+    pub fn new(item: Entity, holder: Entity, handle_option: Option<u64>) -> Self {
+        Self {
+            item,
+            holder,
+            handle_option,
+            timer: Timer::from_seconds(RECHARGE_INTERVAL, true),
+        }
+    }
+}
+
+// Returns whether the magazine is now fully charged.
+fn recharge(magazine: &mut Magazine) -> bool {
+    if magazine.rounds < magazine.capacity {
+        magazine.rounds += 1;
+    }
+    magazine.rounds >= magazine.capacity
+}
+
+pub fn recharger_notice(text: &str) -> ReliableServerMessage {
+    ReliableServerMessage::ChatMessage(
+        "[font=".to_string() + FURTHER_ITALIC_FONT + "]" + text + "[/font]",
+    )
+}
+
+pub fn recharger_tick(
+    mut rechargers: Query<(&mut Recharger, &Transform)>,
+    holders: Query<(&Inventory, &Transform)>,
+    mut ammunition: Query<&mut Ammunition>,
+    mut magazines: Query<&mut Magazine>,
+    examinables: Query<&Examinable>,
+    time: Res<Time>,
+    mut net_recharger: EventWriter<NetRecharger>,
+) {
+    for (mut recharger_component, recharger_transform) in rechargers.iter_mut() {
+        let session;
+
+        match recharger_component.session_option.as_mut() {
+            Some(s) => {
+                session = s;
+            }
+            None => {
+                continue;
+            }
+        }
+
+        let item_name = match examinables.get(session.item) {
+            Ok(examinable_component) => examinable_component.name.get_name().to_string(),
+            Err(_rr) => "item".to_string(),
+        };
+
+        // The item has to stay in the active hand of its holder, within reach of the recharger.
+        let mut interrupted = true;
+
+        match holders.get(session.holder) {
+            Ok((inventory_component, holder_transform)) => {
+                if inventory_component.get_active_slot_entity() == Some(session.item)
+                    && holder_transform
+                        .translation
+                        .distance(recharger_transform.translation)
+                        < REACH_DISTANCE
+                {
+                    interrupted = false;
+                }
+            }
+            Err(_rr) => {}
+        }
+
+        let mut text_option = None;
+        let mut finished = false;
+
+        if interrupted {
+            text_option = Some("You stop recharging the ".to_string() + &item_name + ".");
+            finished = true;
+        } else {
+            session.timer.tick(time.delta());
+
+            if session.timer.just_finished() {
+                let full;
+
+                match ammunition.get_mut(session.item) {
+                    Ok(mut ammunition_component) => {
+                        full = recharge(&mut ammunition_component.loaded);
+                    }
+                    Err(_rr) => match magazines.get_mut(session.item) {
+                        Ok(mut magazine_component) => {
+                            full = recharge(&mut *magazine_component);
+                        }
+                        Err(_rr) => {
+                            full = true;
+                        }
+                    },
+                }
+
+                if full {
+                    text_option = Some("The ".to_string() + &item_name + " is fully charged.");
+                    finished = true;
+                }
+            }
+        }
+
+        match text_option {
+            Some(text) => match session.handle_option {
+                Some(handle) => {
+                    net_recharger.send(NetRecharger {
+                        handle,
+                        message: recharger_notice(&text),
+                    });
+                }
+                None => {}
+            },
+            None => {}
+        }
+
+        if finished {
+            recharger_component.session_option = None;
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use bevy::{
+    math::Vec3,
+    prelude::{Commands, EventReader, EventWriter, Transform},
+};
+use bevy_rapier3d::prelude::{CoefficientCombineRule, Collider, Friction};
+use entity::{
+    entity_data::RawSpawnEvent,
+    spawn::{BaseEntityBundle, BaseEntitySummonable, DefaultSpawnEvent, SpawnData, SpawnEvent},
+};
+use rigid_body::{
+    rigid_body::STANDARD_BODY_FRICTION,
+    spawn::{RigidBodyBundle, RigidBodySummonable},
+};
+use api::{
+    converters::string_transform_to_transform,
+    data::NoData,
+    examinable::{Examinable, RichName},
+    health::Health,
+    tab_actions::{TabAction, TabActions},
+};
+
+use crate::{
+    actions::{recharger_action, RECHARGE_TAB_ACTION_ID},
+    recharger::Recharger,
+};
+
+pub const RECHARGER_ENTITY_NAME: &str = "recharger";
+
+pub fn get_default_transform() -> Transform {
+    Transform::identity()
+}
+
+pub struct RechargerSummoner;
+
+impl BaseEntitySummonable<NoData> for RechargerSummoner {
+    fn get_bundle(&self, spawn_data: &SpawnData, _entity_data: NoData) -> BaseEntityBundle {
+        let mut examine_map = BTreeMap::new();
+        examine_map.insert(
+            0,
+            "A recharger for power cells and laser weapons. Hold one in your hand to charge it."
+                .to_string(),
+        );
+
+        let tab_actions_option = Some(TabActions {
+            tab_actions: vec![TabAction {
+                id: RECHARGE_TAB_ACTION_ID.to_string(),
+                text: "Recharge".to_string(),
+                tab_list_priority: 100,
+                prerequisite_check: Arc::new(recharger_action),
+                belonging_entity: Some(spawn_data.entity),
+            }],
+        });
+
+        BaseEntityBundle {
+            default_transform: get_default_transform(),
+            examinable: Examinable {
+                assigned_texts: examine_map,
+                name: RichName {
+                    name: "recharger".to_string(),
+                    n: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            entity_name: RECHARGER_ENTITY_NAME.to_string(),
+            tab_actions_option,
+            health: Health {
+                is_combat_obstacle: true,
+                is_reach_obstacle: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl RigidBodySummonable<NoData> for RechargerSummoner {
+    fn get_bundle(&self, _spawn_data: &SpawnData, _entity_data: NoData) -> RigidBodyBundle {
+        let mut friction = Friction::coefficient(STANDARD_BODY_FRICTION);
+        friction.combine_rule = CoefficientCombineRule::Min;
+
+        RigidBodyBundle {
+            collider: Collider::cuboid(0.25, 0.5, 0.25),
+            collider_transform: Transform::from_translation(Vec3::new(0., 0.5, 0.)),
+            collider_friction: friction,
+            rigidbody_dynamic: false,
+            collision_events: true,
+        }
+    }
+}
+
+pub fn summon_recharger(
+    mut commands: Commands,
+    mut spawn_events: EventReader<SpawnEvent<RechargerSummoner>>,
+) {
+    for spawn_event in spawn_events.iter() {
+        commands
+            .entity(spawn_event.spawn_data.entity)
+            .insert(Recharger::default());
+    }
+}
+
+pub fn summon_raw_recharger(
+    mut spawn_events: EventReader<RawSpawnEvent>,
+    mut summon_recharger: EventWriter<SpawnEvent<RechargerSummoner>>,
+    mut commands: Commands,
+) {
+    for spawn_event in spawn_events.iter() {
+        if spawn_event.raw_entity.entity_type != RECHARGER_ENTITY_NAME {
+            continue;
+        }
+
+        let entity_transform = string_transform_to_transform(&spawn_event.raw_entity.transform);
+
+        summon_recharger.send(SpawnEvent {
+            spawn_data: SpawnData {
+                entity_transform: entity_transform,
+                default_map_spawn: true,
+                entity_name: spawn_event.raw_entity.entity_type.clone(),
+                entity: commands.spawn().id(),
+                raw_entity_option: Some(spawn_event.raw_entity.clone()),
+                ..Default::default()
+            },
+            summoner: RechargerSummoner,
+        });
+    }
+}
+
+pub fn default_summon_recharger(
+    mut default_spawner: EventReader<DefaultSpawnEvent>,
+    mut spawner: EventWriter<SpawnEvent<RechargerSummoner>>,
+) {
+    for spawn_event in default_spawner.iter() {
+        if spawn_event.spawn_data.entity_name != RECHARGER_ENTITY_NAME {
+            continue;
+        }
+        spawner.send(SpawnEvent {
+            spawn_data: spawn_event.spawn_data.clone(),
+            summoner: RechargerSummoner,
+        });
+    }
+}
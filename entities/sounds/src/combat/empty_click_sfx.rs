@@ -0,0 +1,21 @@
+use bevy::prelude::{Commands, Entity};
+use sfx::builder::{get_random_pitch_scale, Sfx};
+
+pub struct EmptyClickSfxBundle;
+
+pub const EMPTY_CLICK_PLAY_BACK_DURATION: f32 = 0.3 + 1.;
+
+impl EmptyClickSfxBundle {
+    pub fn new(commands: &mut Commands) -> Entity {
+        commands
+            .spawn_bundle((Sfx {
+                unit_db: 12.,
+                unit_size: 1.,
+                stream_id: "/content/audio/combat/empty_click.sample".to_string(),
+                play_back_duration: EMPTY_CLICK_PLAY_BACK_DURATION,
+                pitch_scale: get_random_pitch_scale(1.0),
+                ..Default::default()
+            },))
+            .id()
+    }
+}
@@ -1,6 +1,7 @@
 pub mod block1_sfx;
 pub mod block2_sfx;
 pub mod block3_sfx;
+pub mod empty_click_sfx;
 pub mod laser_light_block1_sfx;
 pub mod laser_light_block2_sfx;
 pub mod laser_light_block3_sfx;
@@ -0,0 +1,21 @@
+use bevy::prelude::{Commands, Entity};
+use sfx::builder::{get_random_pitch_scale, Sfx};
+
+pub struct Cry1SfxBundle;
+
+pub const CRY1_PLAY_BACK_DURATION: f32 = 2.5 + 1.;
+
+impl Cry1SfxBundle {
+    pub fn new(commands: &mut Commands) -> Entity {
+        commands
+            .spawn_bundle((Sfx {
+                unit_db: 12.,
+                unit_size: 1.,
+                stream_id: "/content/audio/emotes/cry1.sample".to_string(),
+                play_back_duration: CRY1_PLAY_BACK_DURATION,
+                pitch_scale: get_random_pitch_scale(1.0),
+                ..Default::default()
+            },))
+            .id()
+    }
+}
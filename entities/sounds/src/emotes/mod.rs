@@ -0,0 +1,2 @@
+pub mod cry1_sfx;
+pub mod salute1_sfx;
@@ -0,0 +1,21 @@
+use bevy::prelude::{Commands, Entity};
+use sfx::builder::{get_random_pitch_scale, Sfx};
+
+pub struct Salute1SfxBundle;
+
+pub const SALUTE1_PLAY_BACK_DURATION: f32 = 0.5 + 1.;
+
+impl Salute1SfxBundle {
+    pub fn new(commands: &mut Commands) -> Entity {
+        commands
+            .spawn_bundle((Sfx {
+                unit_db: 15.,
+                unit_size: 1.,
+                stream_id: "/content/audio/emotes/salute1.sample".to_string(),
+                play_back_duration: SALUTE1_PLAY_BACK_DURATION,
+                pitch_scale: get_random_pitch_scale(1.0),
+                ..Default::default()
+            },))
+            .id()
+    }
+}
@@ -8,6 +8,7 @@ pub mod ambience;
 pub mod combat;
 pub mod construction;
 pub mod counter_window;
+pub mod emotes;
 pub mod ui;
 
 pub struct SoundsPlugin;
@@ -60,6 +60,29 @@ impl CombatSoundSet {
         }
     }
 
+    // There are no dedicated gunshot samples yet, shots borrow the laser samples.
+    pub fn default_ballistic_projectiles() -> Self {
+        Self {
+            default: vec![
+                CombatSound::LaserLightShot1,
+                CombatSound::LaserLightShot2,
+                CombatSound::LaserLightShot3,
+                CombatSound::LaserLightShot4,
+            ],
+            hit_soft: vec![
+                CombatSound::FistsPunch1,
+                CombatSound::FistsPunch2,
+                CombatSound::FistsPunch3,
+                CombatSound::FistsPunch4,
+            ],
+            hit_blocked: vec![
+                CombatSound::FistsBlock1,
+                CombatSound::FistsBlock2,
+                CombatSound::FistsBlock3,
+            ],
+        }
+    }
+
     pub fn spawn_default_sfx(
         &self,
         commands: &mut Commands,